@@ -0,0 +1,93 @@
+//! Compares `humanize`, which returns a new `String` per call, with the
+//! allocation-free `write_humanized` and `Humanized` display adapter.
+//!
+//! Run with `cargo bench --bench humanize`. Each line reports the time per
+//! humanized row and the heap allocations made over all rows.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write;
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use chrono::{DateTime, Duration, Local, TimeZone, Utc};
+use period::{Clock, humanize_with, humanized_with, write_humanized_with};
+
+/// Counts every allocation made through the global allocator.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// A clock pinned to one instant, so every row sees the same "now".
+struct Fixed(DateTime<Utc>);
+
+impl Clock for Fixed {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+const ROWS: u32 = 200_000;
+
+fn measure(name: &str, rows: &[DateTime<Local>], mut humanize: impl FnMut(DateTime<Local>)) {
+    // Warm up caches such as the local timezone before counting.
+    rows.iter().take(1_000).for_each(|&row| humanize(row));
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for &row in rows {
+        humanize(row);
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+
+    println!(
+        "{name:<28} {:>10?}/row {allocations:>8} allocations in {ROWS} rows",
+        elapsed / ROWS,
+    );
+}
+
+fn main() {
+    let clock = Fixed(Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+    let now = clock.0.with_timezone(&Local);
+    // Offsets from a few seconds to a few years, covering every bucket.
+    let rows: Vec<_> = (0..ROWS)
+        .map(|i| now - Duration::seconds(i64::from(i).pow(3) % (5 * 365 * 86_400)))
+        .collect();
+
+    measure("humanize_with", &rows, |row| {
+        black_box(humanize_with(&clock, row));
+    });
+
+    let mut line = String::with_capacity(64);
+    measure("write_humanized_with", &rows, |row| {
+        line.clear();
+        write_humanized_with(&mut line, &clock, row).unwrap();
+        black_box(&line);
+    });
+
+    measure("write!(humanized_with(..))", &rows, |row| {
+        line.clear();
+        write!(line, "{}", humanized_with(&clock, row)).unwrap();
+        black_box(&line);
+    });
+}
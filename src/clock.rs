@@ -0,0 +1,278 @@
+use chrono::{DateTime, Utc};
+
+/// A source of the current instant.
+///
+/// Every function that is relative to "now" has a `_with` variant that reads
+/// the current instant from a [`Clock`] instead of the system clock, so the
+/// result can be pinned in tests or replayed from a recorded timestamp.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{DateTime, TimeZone, Utc};
+/// use period::Clock;
+///
+/// struct Fixed(DateTime<Utc>);
+///
+/// impl Clock for Fixed {
+///     fn now(&self) -> DateTime<Utc> {
+///         self.0
+///     }
+/// }
+///
+/// # fn main() -> Result<(), period::PeriodError> {
+/// let clock = Fixed(Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+/// let r = period::days_ago_with(&clock, 3)?;
+/// assert_eq!(r.as_datetime().to_utc(), clock.0 - chrono::Duration::days(3));
+/// # Ok(())
+/// # }
+/// ```
+pub trait Clock {
+    /// Returns the current instant.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The [`Clock`] backed by the operating system's wall clock.
+///
+/// This is the clock used by every function without a `_with` suffix.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A manually driven [`Clock`] for tests.
+///
+/// A `MockClock` starts frozen at the instant it was created with and only
+/// moves when told to, so every relative and humanize computation made
+/// through it is reproducible. Clones share the same timeline: advancing one
+/// handle advances them all, which lets a test keep a handle while the code
+/// under test owns another.
+///
+/// Available with the `test-util` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{Duration, TimeZone, Utc};
+/// use period::{MockClock, humanize_with};
+///
+/// # fn main() -> Result<(), period::PeriodError> {
+/// let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+/// let expires = period::days_from_now_with(&clock, 3)?.as_datetime();
+/// assert_eq!(humanize_with(&clock, expires), "in 3 days");
+///
+/// clock.advance(Duration::days(2));
+/// assert_eq!(humanize_with(&clock, expires), "tomorrow");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(any(test, feature = "test-util"))]
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: std::sync::Arc<std::sync::Mutex<DateTime<Utc>>>,
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl MockClock {
+    /// Creates a clock frozen at `instant`.
+    #[must_use]
+    pub fn new<Tz: chrono::TimeZone>(instant: &DateTime<Tz>) -> Self {
+        MockClock {
+            now: std::sync::Arc::new(std::sync::Mutex::new(instant.to_utc())),
+        }
+    }
+
+    /// Creates a clock frozen at the wall time `wall` in the UTC offset `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `wall` shifted by `offset` is outside chrono's supported range.
+    #[must_use]
+    pub fn at_wall_time(wall: chrono::NaiveDateTime, offset: chrono::FixedOffset) -> Self {
+        MockClock::new(&wall_time_instant(wall, offset))
+    }
+
+    /// Moves the clock to `instant`, forwards or backwards.
+    pub fn set<Tz: chrono::TimeZone>(&self, instant: &DateTime<Tz>) {
+        *self.lock() = instant.to_utc();
+    }
+
+    /// Moves the clock to the wall time `wall` in the UTC offset `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `wall` shifted by `offset` is outside chrono's supported range.
+    pub fn set_wall_time(&self, wall: chrono::NaiveDateTime, offset: chrono::FixedOffset) {
+        self.set(&wall_time_instant(wall, offset));
+    }
+
+    /// Moves the clock by `duration`. A negative duration moves it backwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting instant is outside chrono's supported range.
+    pub fn advance(&self, duration: chrono::Duration) {
+        let mut now = self.lock();
+        *now = now
+            .checked_add_signed(duration)
+            .expect("MockClock advanced out of range");
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, DateTime<Utc>> {
+        // The guarded value is a plain `Copy` instant, so a poisoned lock
+        // cannot hold a half-written state.
+        self.now
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.lock()
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+fn wall_time_instant(wall: chrono::NaiveDateTime, offset: chrono::FixedOffset) -> DateTime<Utc> {
+    use chrono::TimeZone;
+    offset
+        .from_local_datetime(&wall)
+        .single()
+        .expect("wall time out of range")
+        .to_utc()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, FixedOffset, NaiveDate, TimeZone};
+
+    #[test]
+    fn test_system_clock_returns_current_instant() {
+        let before = Utc::now();
+        let result = SystemClock.now();
+        let after = Utc::now();
+
+        assert!(result >= before);
+        assert!(result <= after);
+    }
+
+    fn noon() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_clock_is_object_safe() {
+        let clock: &dyn Clock = &MockClock::new(&noon());
+        assert_eq!(clock.now(), noon());
+    }
+
+    // -- MockClock ------------------------------------------------------------
+
+    #[test]
+    fn test_mock_clock_is_frozen() {
+        let clock = MockClock::new(&noon());
+        assert_eq!(clock.now(), noon());
+        assert_eq!(clock.now(), clock.now());
+    }
+
+    #[test]
+    fn test_mock_clock_new_normalizes_offset() {
+        let ist = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+        let clock = MockClock::new(&noon().with_timezone(&ist));
+        assert_eq!(clock.now(), noon());
+    }
+
+    #[test]
+    fn test_mock_clock_advance_forwards() {
+        let clock = MockClock::new(&noon());
+        clock.advance(Duration::days(3));
+        assert_eq!(clock.now(), noon() + Duration::days(3));
+    }
+
+    #[test]
+    fn test_mock_clock_advance_backwards() {
+        let clock = MockClock::new(&noon());
+        clock.advance(Duration::hours(-2));
+        assert_eq!(clock.now(), noon() - Duration::hours(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "MockClock advanced out of range")]
+    fn test_mock_clock_advance_out_of_range_panics() {
+        let clock = MockClock::new(&noon());
+        clock.advance(Duration::MAX);
+    }
+
+    #[test]
+    fn test_mock_clock_set() {
+        let clock = MockClock::new(&noon());
+        let later = noon() + Duration::weeks(10);
+        clock.set(&later);
+        assert_eq!(clock.now(), later);
+    }
+
+    #[test]
+    fn test_mock_clock_set_wall_time_in_offset() {
+        let clock = MockClock::new(&noon());
+        let cst = FixedOffset::west_opt(6 * 3600).unwrap();
+        let wall = NaiveDate::from_ymd_opt(2026, 3, 1)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+        clock.set_wall_time(wall, cst);
+        assert_eq!(
+            clock.now(),
+            Utc.with_ymd_and_hms(2026, 3, 1, 15, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_mock_clock_at_wall_time() {
+        let ist = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+        let wall = NaiveDate::from_ymd_opt(2026, 2, 22)
+            .unwrap()
+            .and_hms_opt(17, 30, 0)
+            .unwrap();
+        assert_eq!(MockClock::at_wall_time(wall, ist).now(), noon());
+    }
+
+    #[test]
+    fn test_mock_clock_clones_share_timeline() {
+        let clock = MockClock::new(&noon());
+        let handle = clock.clone();
+        handle.advance(Duration::minutes(5));
+        assert_eq!(clock.now(), noon() + Duration::minutes(5));
+    }
+
+    #[test]
+    fn test_mock_clock_drives_relative_functions() {
+        let clock = MockClock::new(&noon());
+        let expires = crate::days_from_now_with(&clock, 3).unwrap().as_datetime();
+        assert_eq!(crate::humanize_with(&clock, expires), "in 3 days");
+
+        clock.advance(Duration::days(2));
+        assert_eq!(crate::humanize_with(&clock, expires), "tomorrow");
+
+        clock.advance(Duration::days(1));
+        assert_eq!(crate::humanize_with(&clock, expires), "just now");
+
+        clock.advance(Duration::hours(5));
+        assert_eq!(crate::humanize_with(&clock, expires), "5 hours ago");
+    }
+
+    #[test]
+    fn test_mock_clock_drives_months_from_now() {
+        let clock = MockClock::new(&noon());
+        let a = crate::months_from_now_with(&clock, 1).unwrap();
+        let b = crate::months_from_now_with(&clock, 1).unwrap();
+        assert_eq!(a, b);
+    }
+}
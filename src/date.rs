@@ -93,8 +93,8 @@ mod tests {
         // 2026-02-23 is Monday; step through a full week
         let monday = NaiveDate::from_ymd_opt(2026, 2, 23).unwrap();
         let expected = [false, false, false, false, false, true, true]; // Mon–Sun
-        for (i, &expected_weekend) in expected.iter().enumerate() {
-            let date = monday + chrono::Duration::days(i as i64);
+        for (i, &expected_weekend) in (0..).zip(expected.iter()) {
+            let date = monday + chrono::Duration::days(i);
             assert_eq!(
                 is_weekend(date),
                 expected_weekend,
@@ -1,6 +1,6 @@
 use std::fmt;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
 pub enum PeriodError {
     NegativeValue {
@@ -12,6 +12,15 @@ pub enum PeriodError {
         unit: &'static str,
         value: i64,
     },
+    Parse {
+        format: &'static str,
+        position: usize,
+        reason: &'static str,
+    },
+    InvalidThreshold {
+        seconds: i64,
+        reason: &'static str,
+    },
 }
 
 impl std::error::Error for PeriodError {}
@@ -29,9 +38,22 @@ impl fmt::Display for PeriodError {
                     "{unit} must be positive. Did you mean {suggestion}({value})?"
                 )
             }
+            PeriodError::Overflow { unit, value } if *value < 0 => {
+                write!(f, "{unit} value {value} is too small")
+            }
             PeriodError::Overflow { unit, value } => {
                 write!(f, "{unit} value {value} is too large")
             }
+            PeriodError::Parse {
+                format,
+                position,
+                reason,
+            } => {
+                write!(f, "invalid {format} at position {position}: {reason}")
+            }
+            PeriodError::InvalidThreshold { seconds, reason } => {
+                write!(f, "invalid humanize threshold of {seconds}s: {reason}")
+            }
         }
     }
 }
@@ -0,0 +1,294 @@
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+
+use super::month_of;
+use crate::locale::{English, Locale, Style};
+
+/// Whether the day or the month comes first in a [`LongDate`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DateOrder {
+    /// `"February 22, 2026"`, as in the United States.
+    #[default]
+    MonthFirst,
+    /// `"22 February 2026"`, as in the United Kingdom.
+    DayFirst,
+}
+
+/// A configurable English long date: `"February 22, 2026"`,
+/// `"Sunday, February 22nd, 2026"`, `"Sun, 22nd February 2026"`.
+///
+/// Start from [`LongDate::new`] (the same output as
+/// [`to_long_date`](crate::to_long_date)) and add a weekday prefix, an
+/// ordinal day or the day-first order. Every setter is `const`, so a format
+/// can live in a `const`.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use period::{DateOrder, LongDate, Style};
+///
+/// const INVOICE: LongDate = LongDate::new()
+///     .weekday(Style::Short)
+///     .ordinal(true)
+///     .order(DateOrder::DayFirst);
+///
+/// let date = NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+/// assert_eq!(INVOICE.format(date), "Sun, 22nd February 2026");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct LongDate {
+    weekday: Option<Style>,
+    ordinal: bool,
+    order: DateOrder,
+}
+
+impl LongDate {
+    /// `"February 22, 2026"`: no weekday, cardinal day, month first.
+    #[inline]
+    pub const fn new() -> LongDate {
+        LongDate {
+            weekday: None,
+            ordinal: false,
+            order: DateOrder::MonthFirst,
+        }
+    }
+
+    /// Starts the date with the weekday and a comma: `"Sunday, "` for
+    /// [`Style::Long`], `"Sun, "` for [`Style::Short`] and `"S, "` for
+    /// [`Style::Narrow`].
+    #[inline]
+    pub const fn weekday(mut self, style: Style) -> LongDate {
+        self.weekday = Some(style);
+        self
+    }
+
+    /// Writes the day as an ordinal (`"22nd"`) instead of a number.
+    #[inline]
+    pub const fn ordinal(mut self, ordinal: bool) -> LongDate {
+        self.ordinal = ordinal;
+        self
+    }
+
+    /// Sets whether the day or the month comes first.
+    #[inline]
+    pub const fn order(mut self, order: DateOrder) -> LongDate {
+        self.order = order;
+        self
+    }
+
+    /// Formats `date`.
+    #[must_use]
+    pub fn format(&self, date: NaiveDate) -> String {
+        Formatted {
+            format: *self,
+            date,
+        }
+        .to_string()
+    }
+}
+
+/// Converts a [`NaiveDate`] to a long date with an ordinal day
+/// (e.g. `"February 22nd, 2026"`).
+///
+/// Output is always in English. See [`LongDate`] for the day-first order
+/// and weekday prefixes.
+#[must_use]
+#[inline]
+pub fn to_ordinal_date(date: NaiveDate) -> String {
+    LongDate::new().ordinal(true).format(date)
+}
+
+/// Converts a [`NaiveDate`] to a long date with the weekday and an ordinal
+/// day (e.g. `"Sunday, February 22nd, 2026"`).
+///
+/// Output is always in English. See [`LongDate`] for the day-first order
+/// and abbreviated weekdays.
+#[must_use]
+#[inline]
+pub fn to_full_date(date: NaiveDate) -> String {
+    LongDate::new()
+        .weekday(Style::Long)
+        .ordinal(true)
+        .format(date)
+}
+
+/// The English ordinal suffix for `n`: `"st"`, `"nd"`, `"rd"` or `"th"`.
+///
+/// Numbers ending in 11, 12 and 13 take `"th"` (`"11th"`, `"112th"`).
+///
+/// # Example
+///
+/// ```rust
+/// use period::ordinal_suffix;
+///
+/// assert_eq!(format!("{}{}", 22, ordinal_suffix(22)), "22nd");
+/// assert_eq!(ordinal_suffix(13), "th");
+/// ```
+#[must_use]
+pub const fn ordinal_suffix(n: u32) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+struct Formatted {
+    format: LongDate,
+    date: NaiveDate,
+}
+
+impl fmt::Display for Formatted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let LongDate {
+            weekday,
+            ordinal,
+            order,
+        } = self.format;
+        let date = self.date;
+        if let Some(style) = weekday {
+            write!(f, "{}, ", English.weekday_name(date.weekday(), style))?;
+        }
+        let month = English.month_name(month_of(date), Style::Long);
+        let day = date.day();
+        let suffix = if ordinal { ordinal_suffix(day) } else { "" };
+        // `%Y`, as in `to_long_date`: zero-padded to four digits.
+        let year = date.format("%Y");
+        match order {
+            DateOrder::MonthFirst => write!(f, "{month} {day}{suffix}, {year}"),
+            DateOrder::DayFirst => write!(f, "{day}{suffix} {month} {year}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 2, day).unwrap()
+    }
+
+    // -- ordinal_suffix -------------------------------------------------------
+
+    #[test]
+    fn test_ordinal_suffix_every_day_of_month() {
+        let days: Vec<String> = (1..=31)
+            .map(|day| format!("{day}{}", ordinal_suffix(day)))
+            .collect();
+        assert_eq!(
+            days,
+            [
+                "1st", "2nd", "3rd", "4th", "5th", "6th", "7th", "8th", "9th", "10th", "11th",
+                "12th", "13th", "14th", "15th", "16th", "17th", "18th", "19th", "20th", "21st",
+                "22nd", "23rd", "24th", "25th", "26th", "27th", "28th", "29th", "30th", "31st",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ordinal_suffix_beyond_a_month() {
+        assert_eq!(ordinal_suffix(0), "th");
+        assert_eq!(ordinal_suffix(101), "st");
+        assert_eq!(ordinal_suffix(111), "th");
+        assert_eq!(ordinal_suffix(112), "th");
+        assert_eq!(ordinal_suffix(113), "th");
+        assert_eq!(ordinal_suffix(122), "nd");
+        assert_eq!(ordinal_suffix(1_003), "rd");
+    }
+
+    #[test]
+    fn test_ordinal_date_every_day_of_month() {
+        let january: Vec<String> = (1..=31)
+            .map(|day| to_ordinal_date(NaiveDate::from_ymd_opt(2026, 1, day).unwrap()))
+            .collect();
+        assert_eq!(january[0], "January 1st, 2026");
+        assert_eq!(january[1], "January 2nd, 2026");
+        assert_eq!(january[2], "January 3rd, 2026");
+        assert_eq!(january[10], "January 11th, 2026");
+        assert_eq!(january[11], "January 12th, 2026");
+        assert_eq!(january[12], "January 13th, 2026");
+        assert_eq!(january[20], "January 21st, 2026");
+        assert_eq!(january[21], "January 22nd, 2026");
+        assert_eq!(january[22], "January 23rd, 2026");
+        assert_eq!(january[29], "January 30th, 2026");
+        assert_eq!(january[30], "January 31st, 2026");
+    }
+
+    // -- to_ordinal_date / to_full_date ---------------------------------------
+
+    #[test]
+    fn test_to_ordinal_date() {
+        assert_eq!(to_ordinal_date(date(22)), "February 22nd, 2026");
+    }
+
+    #[test]
+    fn test_to_full_date() {
+        assert_eq!(to_full_date(date(22)), "Sunday, February 22nd, 2026");
+        assert_eq!(to_full_date(date(23)), "Monday, February 23rd, 2026");
+    }
+
+    #[test]
+    fn test_to_full_date_leap_day() {
+        let date = NaiveDate::from_ymd_opt(2028, 2, 29).unwrap();
+        assert_eq!(to_full_date(date), "Tuesday, February 29th, 2028");
+    }
+
+    // -- LongDate -------------------------------------------------------------
+
+    #[test]
+    fn test_default_matches_to_long_date() {
+        for day in [1, 5, 22, 28] {
+            assert_eq!(
+                LongDate::new().format(date(day)),
+                crate::to_long_date(date(day))
+            );
+        }
+        let early = NaiveDate::from_ymd_opt(7, 2, 22).unwrap();
+        assert_eq!(LongDate::new().format(early), "February 22, 0007");
+        assert_eq!(LongDate::new().format(early), crate::to_long_date(early));
+        assert_eq!(LongDate::default(), LongDate::new());
+    }
+
+    #[test]
+    fn test_day_first() {
+        let format = LongDate::new().order(DateOrder::DayFirst);
+        assert_eq!(format.format(date(22)), "22 February 2026");
+        assert_eq!(format.ordinal(true).format(date(22)), "22nd February 2026");
+        assert_eq!(format.ordinal(true).format(date(1)), "1st February 2026");
+    }
+
+    #[test]
+    fn test_weekday_prefixes() {
+        let cases = [
+            (Style::Long, "Sunday, February 22, 2026"),
+            (Style::Short, "Sun, February 22, 2026"),
+            (Style::Narrow, "S, February 22, 2026"),
+        ];
+        for (style, expected) in cases {
+            assert_eq!(LongDate::new().weekday(style).format(date(22)), expected);
+        }
+    }
+
+    #[test]
+    fn test_every_combination() {
+        let format = LongDate::new()
+            .weekday(Style::Short)
+            .ordinal(true)
+            .order(DateOrder::DayFirst);
+        assert_eq!(format.format(date(3)), "Tue, 3rd February 2026");
+        assert_eq!(
+            format.ordinal(false).format(date(3)),
+            "Tue, 3 February 2026"
+        );
+        assert_eq!(
+            format.order(DateOrder::MonthFirst).format(date(11)),
+            "Wed, February 11th, 2026"
+        );
+    }
+}
@@ -1,4 +1,21 @@
-use chrono::{DateTime, Datelike, NaiveDate, TimeZone};
+mod long_date;
+mod parse;
+mod pattern;
+mod time;
+
+use chrono::{DateTime, Datelike, Month, NaiveDate, TimeZone};
+
+use crate::error::PeriodError;
+use crate::locale::Locale;
+use crate::period::Period;
+
+pub use long_date::{DateOrder, LongDate, ordinal_suffix, to_full_date, to_ordinal_date};
+pub use parse::{from_date_string, from_iso8601, from_long_date, from_rfc2822, from_short_date};
+pub use pattern::{DatePattern, Formattable, format};
+pub use time::{
+    TimeFormat, TimePrecision, to_12h_time, to_24h_time, to_long_datetime, to_short_datetime,
+    to_time_string,
+};
 
 /// Converts a [`NaiveDate`] to an ISO 8601 date string (`YYYY-MM-DD`).
 #[must_use]
@@ -10,7 +27,8 @@ pub fn to_date_string(date: NaiveDate) -> String {
 /// Converts a [`NaiveDate`] to a long-form date string (e.g. `"February 22, 2026"`).
 ///
 /// Single-digit days are not padded: `"February 5, 2026"`, not `"February  5, 2026"`.
-/// Output is always in English regardless of system locale.
+/// Output is always in English regardless of system locale; see
+/// [`to_long_date_with_locale`] for other languages.
 #[must_use]
 #[inline]
 pub fn to_long_date(date: NaiveDate) -> String {
@@ -25,7 +43,8 @@ pub fn to_long_date(date: NaiveDate) -> String {
 /// Converts a [`NaiveDate`] to a short-form date string (e.g. `"Feb 22, 2026"`).
 ///
 /// Single-digit days are not padded: `"Feb 5, 2026"`, not `"Feb  5, 2026"`.
-/// Output is always in English regardless of system locale.
+/// Output is always in English regardless of system locale; see
+/// [`to_short_date_with_locale`] for other languages.
 #[must_use]
 #[inline]
 pub fn to_short_date(date: NaiveDate) -> String {
@@ -37,6 +56,60 @@ pub fn to_short_date(date: NaiveDate) -> String {
     )
 }
 
+/// Like [`to_long_date`], but in the language of `locale`, using its
+/// [`long_date_pattern`](Locale::long_date_pattern): `"22. Februar 2026"`,
+/// `"22 février 2026"`, `"2026年2月22日"`.
+///
+/// The names and patterns are bundled with the crate; the system locale is
+/// never consulted.
+///
+/// # Errors
+/// Returns [`PeriodError::Parse`] if `locale`'s pattern is not a valid
+/// [`DatePattern`] for a date, such as one with a time field. The patterns
+/// of the built-in locales always are.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use period::{German, to_long_date_with_locale};
+///
+/// # fn main() -> Result<(), period::PeriodError> {
+/// let date = NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+/// assert_eq!(to_long_date_with_locale(date, &German)?, "22. Februar 2026");
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_long_date_with_locale(
+    date: NaiveDate,
+    locale: &(impl Locale + ?Sized),
+) -> Result<String, PeriodError> {
+    format_with_pattern(date, locale, locale.long_date_pattern())
+}
+
+/// Like [`to_short_date`], but in the language of `locale`, using its
+/// [`short_date_pattern`](Locale::short_date_pattern): `"22. Feb. 2026"`,
+/// `"22 févr. 2026"`, `"2026年2月22日"`.
+///
+/// # Errors
+/// Returns [`PeriodError::Parse`] if `locale`'s pattern is not a valid
+/// [`DatePattern`] for a date. The patterns of the built-in locales always
+/// are.
+pub fn to_short_date_with_locale(
+    date: NaiveDate,
+    locale: &(impl Locale + ?Sized),
+) -> Result<String, PeriodError> {
+    format_with_pattern(date, locale, locale.short_date_pattern())
+}
+
+fn format_with_pattern(
+    date: NaiveDate,
+    locale: &(impl Locale + ?Sized),
+    pattern: &str,
+) -> Result<String, PeriodError> {
+    DatePattern::compile(pattern)?.format_with_locale(&date, locale)
+}
+
 /// Converts a [`DateTime`] to an RFC 3339 / ISO 8601 string
 /// (e.g. `"2026-02-22T14:30:00+05:30"`).
 ///
@@ -57,6 +130,22 @@ pub fn to_rfc2822<Tz: TimeZone>(datetime: &DateTime<Tz>) -> String {
     datetime.to_rfc2822()
 }
 
+/// Converts a [`Period`] to an ISO 8601 duration string
+/// (e.g. `"P1Y2M3DT4H5M6S"`, `"P2W"`, `"-PT1.5S"`).
+///
+/// Same as `period.to_string()`; see [`Period::parse_iso8601`] for the
+/// reverse direction.
+#[must_use]
+#[inline]
+pub fn to_iso8601_duration(period: &Period) -> String {
+    period.to_string()
+}
+
+/// The month of `date`, for looking up its name.
+pub(crate) fn month_of(date: NaiveDate) -> Month {
+    Month::try_from(u8::try_from(date.month()).unwrap_or(1)).unwrap_or(Month::January)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,6 +241,22 @@ mod tests {
         assert_eq!(to_short_date(date), "Feb 29, 2028");
     }
 
+    // -- to_long_date_with_locale ---------------------------------------------
+
+    #[test]
+    fn test_locale_pattern_errors_are_returned() {
+        let date = NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+        assert_eq!(
+            format_with_pattern(date, &crate::English, "MMMM d, h:mm"),
+            Err(PeriodError::Parse {
+                format: "date pattern",
+                position: 8,
+                reason: "time field used with a date-only value",
+            })
+        );
+        assert!(format_with_pattern(date, &crate::English, "MMMM q").is_err());
+    }
+
     // -- to_iso8601 -----------------------------------------------------------
 
     #[test]
@@ -290,4 +395,17 @@ mod tests {
         let datetime = tz.with_ymd_and_hms(2028, 2, 29, 0, 0, 0).single().unwrap(); // Tuesday
         assert_eq!(to_rfc2822(&datetime), "Tue, 29 Feb 2028 00:00:00 +0000");
     }
+
+    // -- to_iso8601_duration --------------------------------------------------
+
+    #[test]
+    fn test_to_iso8601_duration() {
+        let period = Period::years(1).with_months(2).with_days(3).with_hours(4);
+        assert_eq!(to_iso8601_duration(&period), "P1Y2M3DT4H");
+    }
+
+    #[test]
+    fn test_to_iso8601_duration_weeks() {
+        assert_eq!(to_iso8601_duration(&Period::weeks(2)), "P2W");
+    }
 }
@@ -0,0 +1,753 @@
+use chrono::{DateTime, Datelike, FixedOffset, Month, NaiveDate, NaiveTime, Offset, Utc, Weekday};
+
+use crate::error::PeriodError;
+use crate::locale::{English, Locale, Style};
+
+/// Parses an ISO 8601 date string (`YYYY-MM-DD`), the format of
+/// [`to_date_string`](crate::to_date_string).
+///
+/// Surrounding whitespace and whitespace around the `-` separators are
+/// ignored, and the month and day may have one digit (`"2026-2-5"`).
+///
+/// # Errors
+/// Returns [`PeriodError::Parse`] with the byte offset of the first invalid
+/// character, or of a month or day that is out of range.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use period::from_date_string;
+///
+/// # fn main() -> Result<(), period::PeriodError> {
+/// assert_eq!(from_date_string(" 2026-02-22 ")?, NaiveDate::from_ymd_opt(2026, 2, 22).unwrap());
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_date_string(s: &str) -> Result<NaiveDate, PeriodError> {
+    let mut scanner = Scanner::new(s, "date string");
+    let date = scanner.iso_date()?;
+    scanner.end()?;
+    Ok(date)
+}
+
+/// Parses a long-form date (`"February 22, 2026"`), the format of
+/// [`to_long_date`](crate::to_long_date).
+///
+/// The month name is case-insensitive, any run of whitespace may separate
+/// the parts, and the comma is optional.
+///
+/// # Errors
+/// Returns [`PeriodError::Parse`] with the byte offset of the first invalid
+/// character, of an unknown month name, or of a day that is out of range.
+pub fn from_long_date(s: &str) -> Result<NaiveDate, PeriodError> {
+    let mut scanner = Scanner::new(s, "long date");
+    let date = scanner.month_day_year(Style::Long)?;
+    scanner.end()?;
+    Ok(date)
+}
+
+/// Parses a short-form date (`"Feb 22, 2026"`), the format of
+/// [`to_short_date`](crate::to_short_date).
+///
+/// The month abbreviation is case-insensitive and may end in a period
+/// (`"feb. 22 2026"`); any run of whitespace may separate the parts, and the
+/// comma is optional.
+///
+/// # Errors
+/// Returns [`PeriodError::Parse`] with the byte offset of the first invalid
+/// character, of an unknown month abbreviation, or of a day that is out of
+/// range.
+pub fn from_short_date(s: &str) -> Result<NaiveDate, PeriodError> {
+    let mut scanner = Scanner::new(s, "short date");
+    let date = scanner.month_day_year(Style::Short)?;
+    scanner.end()?;
+    Ok(date)
+}
+
+/// Parses an RFC 3339 / ISO 8601 date-time (`"2026-02-22T14:30:00+05:30"`),
+/// the format of [`to_iso8601`](crate::to_iso8601).
+///
+/// Accepts a lowercase `t` or whitespace between the date and the time, a
+/// fraction of a second with `.` or `,`, and a UTC offset written `Z`, `z`,
+/// `+05:30` or `+0530`. The offset is required. Surrounding whitespace is
+/// ignored.
+///
+/// # Errors
+/// Returns [`PeriodError::Parse`] with the byte offset of the first invalid
+/// character, or of a field that is out of range.
+///
+/// # Example
+///
+/// ```rust
+/// use period::{from_iso8601, to_iso8601};
+///
+/// # fn main() -> Result<(), period::PeriodError> {
+/// let dt = from_iso8601("2026-02-22 14:30:00+05:30")?;
+/// assert_eq!(to_iso8601(&dt), "2026-02-22T14:30:00+05:30");
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_iso8601(s: &str) -> Result<DateTime<FixedOffset>, PeriodError> {
+    let mut scanner = Scanner::new(s, "ISO 8601 date-time");
+    let date = scanner.iso_date()?;
+    let separator = scanner.pos;
+    if !scanner.eat_if(|b| b.eq_ignore_ascii_case(&b'T')) && !scanner.skip_whitespace() {
+        return Err(scanner.error_at(separator, "expected 'T' between the date and the time"));
+    }
+    let time = scanner.time(true)?;
+    scanner.skip_whitespace();
+    let offset = scanner.iso_offset()?;
+    scanner.end()?;
+    Ok(at_offset(date, time, offset))
+}
+
+/// Parses an RFC 2822 date-time (`"Sun, 22 Feb 2026 14:30:00 -0600"`), the
+/// format of [`to_rfc2822`](crate::to_rfc2822).
+///
+/// Day and month names are case-insensitive, any run of whitespace may
+/// separate the parts, and the weekday and the seconds are optional. The
+/// zone may be a numeric offset, `GMT`, `UT`, `UTC`, `Z` or one of the
+/// North American names RFC 2822 allows (`EST`, `PDT`, …).
+///
+/// # Errors
+/// Returns [`PeriodError::Parse`] with the byte offset of the first invalid
+/// character, of a field that is out of range, or of a weekday that does
+/// not match the date.
+pub fn from_rfc2822(s: &str) -> Result<DateTime<FixedOffset>, PeriodError> {
+    let mut scanner = Scanner::new(s, "RFC 2822 date-time");
+    scanner.skip_whitespace();
+    let weekday_at = scanner.pos;
+    let weekday = if scanner.peek().is_some_and(|b| b.is_ascii_alphabetic()) {
+        let name = scanner.word();
+        let weekday = weekday_from_abbreviation(name)
+            .ok_or_else(|| scanner.error_at(weekday_at, "expected a weekday abbreviation"))?;
+        scanner.skip_whitespace();
+        scanner.expect(b',', "expected ',' after the weekday")?;
+        Some(weekday)
+    } else {
+        None
+    };
+
+    scanner.skip_whitespace();
+    let day_at = scanner.pos;
+    let day = scanner.number(1, 2, "expected a day")?;
+    scanner.skip_whitespace();
+    let month = scanner.month(Style::Short)?;
+    scanner.skip_whitespace();
+    let year = scanner.year()?;
+    let date = NaiveDate::from_ymd_opt(year, month, day)
+        .ok_or_else(|| scanner.error_at(day_at, "day is out of range for the month"))?;
+    if let Some(weekday) = weekday
+        && date.weekday() != weekday
+    {
+        return Err(scanner.error_at(weekday_at, "weekday does not match the date"));
+    }
+
+    scanner.skip_whitespace();
+    let time = scanner.time(false)?;
+    scanner.skip_whitespace();
+    let offset = scanner.rfc2822_zone()?;
+    scanner.end()?;
+    Ok(at_offset(date, time, offset))
+}
+
+/// The instant at wall-clock `date` and `time` in `offset`. A fixed offset
+/// has exactly one instant for every wall-clock time.
+fn at_offset(date: NaiveDate, time: NaiveTime, offset: FixedOffset) -> DateTime<FixedOffset> {
+    let utc = date.and_time(time) - offset;
+    DateTime::from_naive_utc_and_offset(utc, offset)
+}
+
+/// A position in the input being parsed, and the name of its format for
+/// errors.
+struct Scanner<'a> {
+    input: &'a str,
+    pos: usize,
+    format: &'static str,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(input: &'a str, format: &'static str) -> Scanner<'a> {
+        Scanner {
+            input,
+            pos: 0,
+            format,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    /// Skips whitespace, returning whether there was any.
+    fn skip_whitespace(&mut self) -> bool {
+        let rest = &self.input[self.pos..];
+        let trimmed = rest.trim_start();
+        self.pos += rest.len() - trimmed.len();
+        rest.len() != trimmed.len()
+    }
+
+    /// Consumes the next byte if it matches `pred`.
+    fn eat_if(&mut self, pred: impl FnOnce(&u8) -> bool) -> bool {
+        let matched = self.peek().is_some_and(|b| pred(&b));
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn expect(&mut self, byte: u8, reason: &'static str) -> Result<(), PeriodError> {
+        if self.eat_if(|&b| b == byte) {
+            Ok(())
+        } else {
+            Err(self.error(reason))
+        }
+    }
+
+    /// Reads a run of ASCII letters.
+    fn word(&mut self) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(|b| b.is_ascii_alphabetic()) {
+            self.pos += 1;
+        }
+        &self.input[start..self.pos]
+    }
+
+    /// Reads between `min` and `max` ASCII digits.
+    fn number(&mut self, min: usize, max: usize, reason: &'static str) -> Result<u32, PeriodError> {
+        let start = self.pos;
+        let mut value = 0;
+        while self.pos - start < max
+            && let Some(digit) = self.peek().filter(u8::is_ascii_digit)
+        {
+            value = value * 10 + u32::from(digit - b'0');
+            self.pos += 1;
+        }
+        if self.pos - start < min {
+            return Err(self.error_at(start, reason));
+        }
+        Ok(value)
+    }
+
+    /// Reads a four-digit year.
+    fn year(&mut self) -> Result<i32, PeriodError> {
+        let year = self.number(4, 4, "expected a four-digit year")?;
+        Ok(i32::try_from(year).unwrap_or_default())
+    }
+
+    /// Reads `YYYY-MM-DD`.
+    fn iso_date(&mut self) -> Result<NaiveDate, PeriodError> {
+        self.skip_whitespace();
+        let year = self.year()?;
+        self.skip_whitespace();
+        self.expect(b'-', "expected '-'")?;
+        self.skip_whitespace();
+        let month_at = self.pos;
+        let month = self.number(1, 2, "expected a month")?;
+        if !(1..=12).contains(&month) {
+            return Err(self.error_at(month_at, "month is out of range"));
+        }
+        self.skip_whitespace();
+        self.expect(b'-', "expected '-'")?;
+        self.skip_whitespace();
+        let day_at = self.pos;
+        let day = self.number(1, 2, "expected a day")?;
+        NaiveDate::from_ymd_opt(year, month, day)
+            .ok_or_else(|| self.error_at(day_at, "day is out of range for the month"))
+    }
+
+    /// Reads `<month name> <day>[,] <year>`.
+    fn month_day_year(&mut self, style: Style) -> Result<NaiveDate, PeriodError> {
+        self.skip_whitespace();
+        let month = self.month(style)?;
+        self.skip_whitespace();
+        let day_at = self.pos;
+        let day = self.number(1, 2, "expected a day")?;
+        self.skip_whitespace();
+        self.eat_if(|&b| b == b',');
+        self.skip_whitespace();
+        let year = self.year()?;
+        NaiveDate::from_ymd_opt(year, month, day)
+            .ok_or_else(|| self.error_at(day_at, "day is out of range for the month"))
+    }
+
+    /// Reads an English month name of the given width, returning its number.
+    /// An abbreviation may end in a period.
+    fn month(&mut self, style: Style) -> Result<u32, PeriodError> {
+        let start = self.pos;
+        let name = self.word();
+        let month = month_from_name(name, style);
+        let Some(month) = month else {
+            let reason = if style == Style::Long {
+                "expected a month name"
+            } else {
+                "expected a month abbreviation"
+            };
+            return Err(self.error_at(start, reason));
+        };
+        if style == Style::Short {
+            self.eat_if(|&b| b == b'.');
+        }
+        Ok(month.number_from_month())
+    }
+
+    /// Reads `HH:MM[:SS[.fraction]]`. Seconds are required when
+    /// `require_seconds` is set.
+    fn time(&mut self, require_seconds: bool) -> Result<NaiveTime, PeriodError> {
+        let hour_at = self.pos;
+        let hour = self.number(2, 2, "expected a two-digit hour")?;
+        self.expect(b':', "expected ':'")?;
+        let minute_at = self.pos;
+        let minute = self.number(2, 2, "expected two-digit minutes")?;
+        let second_at = self.pos;
+        let (mut second, mut nano) = (0, 0);
+        if require_seconds || self.peek() == Some(b':') {
+            self.expect(b':', "expected ':'")?;
+            second = self.number(2, 2, "expected two-digit seconds")?;
+            if self.eat_if(|&b| b == b'.' || b == b',') {
+                nano = self.fraction()?;
+            }
+        }
+        if hour > 23 {
+            return Err(self.error_at(hour_at, "hour is out of range"));
+        }
+        if minute > 59 {
+            return Err(self.error_at(minute_at, "minute is out of range"));
+        }
+        // A leap second is stored as second 59 with nanoseconds past 1_000_000_000.
+        if second == 60 {
+            second = 59;
+            nano += 1_000_000_000;
+        }
+        NaiveTime::from_hms_nano_opt(hour, minute, second, nano)
+            .ok_or_else(|| self.error_at(second_at, "second is out of range"))
+    }
+
+    /// Reads up to nine digits of a fraction of a second, as nanoseconds.
+    /// Further digits are read and dropped.
+    fn fraction(&mut self) -> Result<u32, PeriodError> {
+        let start = self.pos;
+        let digits = self.number(1, 9, "expected digits after the decimal separator")?;
+        let width = u32::try_from(self.pos - start).unwrap_or(9);
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        Ok(digits * 10_u32.pow(9 - width))
+    }
+
+    /// Reads `Z`, `+HH:MM` or `+HHMM`.
+    fn iso_offset(&mut self) -> Result<FixedOffset, PeriodError> {
+        if self.eat_if(|b| b.eq_ignore_ascii_case(&b'Z')) {
+            return Ok(Utc.fix());
+        }
+        let at = self.pos;
+        let negative = match self.peek() {
+            Some(b'+') => false,
+            Some(b'-') => true,
+            _ => return Err(self.error("expected a UTC offset")),
+        };
+        self.pos += 1;
+        let hours = self.number(2, 2, "expected two-digit offset hours")?;
+        self.eat_if(|&b| b == b':');
+        let minutes = self.number(2, 2, "expected two-digit offset minutes")?;
+        self.offset(at, negative, hours, minutes)
+    }
+
+    /// Reads `+HHMM` or a zone name.
+    fn rfc2822_zone(&mut self) -> Result<FixedOffset, PeriodError> {
+        let at = self.pos;
+        let negative = match self.peek() {
+            Some(b'+') => false,
+            Some(b'-') => true,
+            Some(b) if b.is_ascii_alphabetic() => {
+                let hours = zone_hours(self.word())
+                    .ok_or_else(|| self.error_at(at, "unknown time zone"))?;
+                return self.offset(at, hours < 0, hours.unsigned_abs(), 0);
+            }
+            _ => return Err(self.error("expected a time zone")),
+        };
+        self.pos += 1;
+        let hours = self.number(2, 2, "expected two-digit offset hours")?;
+        let minutes = self.number(2, 2, "expected two-digit offset minutes")?;
+        self.offset(at, negative, hours, minutes)
+    }
+
+    fn offset(
+        &self,
+        at: usize,
+        negative: bool,
+        hours: u32,
+        minutes: u32,
+    ) -> Result<FixedOffset, PeriodError> {
+        if hours > 23 || minutes > 59 {
+            return Err(self.error_at(at, "offset is out of range"));
+        }
+        let seconds = i32::try_from(hours * 3600 + minutes * 60).unwrap_or_default();
+        let seconds = if negative { -seconds } else { seconds };
+        FixedOffset::east_opt(seconds).ok_or_else(|| self.error_at(at, "offset is out of range"))
+    }
+
+    /// Fails unless only whitespace is left.
+    fn end(&mut self) -> Result<(), PeriodError> {
+        self.skip_whitespace();
+        if self.pos == self.input.len() {
+            Ok(())
+        } else {
+            Err(self.error("unexpected trailing input"))
+        }
+    }
+
+    fn error(&self, reason: &'static str) -> PeriodError {
+        self.error_at(self.pos, reason)
+    }
+
+    fn error_at(&self, position: usize, reason: &'static str) -> PeriodError {
+        PeriodError::Parse {
+            format: self.format,
+            position,
+            reason,
+        }
+    }
+}
+
+/// The month whose English name in `style` is `name`, ignoring case.
+fn month_from_name(name: &str, style: Style) -> Option<Month> {
+    (1..=12)
+        .filter_map(|n| Month::try_from(n).ok())
+        .find(|&month| English.month_name(month, style).eq_ignore_ascii_case(name))
+}
+
+/// The weekday whose English abbreviation is `name`, ignoring case.
+fn weekday_from_abbreviation(name: &str) -> Option<Weekday> {
+    (0..7)
+        .filter_map(|n| Weekday::try_from(n).ok())
+        .find(|&weekday| {
+            English
+                .weekday_name(weekday, Style::Short)
+                .eq_ignore_ascii_case(name)
+        })
+}
+
+/// The offset in hours of an RFC 2822 zone name, ignoring case.
+fn zone_hours(name: &str) -> Option<i32> {
+    const ZONES: [(&str, i32); 12] = [
+        ("GMT", 0),
+        ("UT", 0),
+        ("UTC", 0),
+        ("Z", 0),
+        ("EST", -5),
+        ("EDT", -4),
+        ("CST", -6),
+        ("CDT", -5),
+        ("MST", -7),
+        ("MDT", -6),
+        ("PST", -8),
+        ("PDT", -7),
+    ];
+    ZONES
+        .iter()
+        .find(|(zone, _)| zone.eq_ignore_ascii_case(name))
+        .map(|&(_, hours)| hours)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{to_date_string, to_iso8601, to_long_date, to_rfc2822, to_short_date};
+    use chrono::{TimeZone, Timelike};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn parse_error(format: &'static str, position: usize, reason: &'static str) -> PeriodError {
+        PeriodError::Parse {
+            format,
+            position,
+            reason,
+        }
+    }
+
+    /// The first day of every month of 2026 and 2028, and the last day of
+    /// February in both.
+    fn sample_dates() -> Vec<NaiveDate> {
+        let mut dates: Vec<NaiveDate> = [2026, 2028]
+            .iter()
+            .flat_map(|&y| (1..=12).map(move |m| date(y, m, 1)))
+            .collect();
+        dates.extend([date(2026, 2, 28), date(2028, 2, 29), date(2026, 12, 31)]);
+        dates
+    }
+
+    // -- round trips ----------------------------------------------------------
+
+    #[test]
+    fn test_round_trip_dates() {
+        for date in sample_dates() {
+            assert_eq!(from_date_string(&to_date_string(date)), Ok(date));
+            assert_eq!(from_long_date(&to_long_date(date)), Ok(date));
+            assert_eq!(from_short_date(&to_short_date(date)), Ok(date));
+        }
+    }
+
+    #[test]
+    fn test_round_trip_datetimes() {
+        let offsets = [
+            0,
+            5 * 3600 + 30 * 60,
+            -6 * 3600,
+            14 * 3600,
+            -(9 * 3600 + 30 * 60),
+        ];
+        for date in sample_dates() {
+            for seconds in offsets {
+                let tz = FixedOffset::east_opt(seconds).unwrap();
+                let dt = tz
+                    .from_local_datetime(&date.and_hms_opt(14, 30, 5).unwrap())
+                    .unwrap();
+                let iso = to_iso8601(&dt);
+                assert_eq!(from_iso8601(&iso).map(|dt| to_iso8601(&dt)), Ok(iso));
+                let rfc = to_rfc2822(&dt);
+                assert_eq!(from_rfc2822(&rfc).map(|dt| to_rfc2822(&dt)), Ok(rfc));
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip_iso8601_fraction() {
+        let dt = Utc
+            .with_ymd_and_hms(2026, 2, 22, 14, 30, 5)
+            .unwrap()
+            .with_nanosecond(123_456_789)
+            .unwrap()
+            .fixed_offset();
+        assert_eq!(from_iso8601(&to_iso8601(&dt)), Ok(dt));
+    }
+
+    // -- from_date_string -----------------------------------------------------
+
+    #[test]
+    fn test_from_date_string_lenient() {
+        assert_eq!(from_date_string("  2026-02-22\n"), Ok(date(2026, 2, 22)));
+        assert_eq!(from_date_string("2026 - 2 - 5"), Ok(date(2026, 2, 5)));
+    }
+
+    #[test]
+    fn test_from_date_string_errors() {
+        const F: &str = "date string";
+        assert_eq!(
+            from_date_string("26-02-22"),
+            Err(parse_error(F, 0, "expected a four-digit year"))
+        );
+        assert_eq!(
+            from_date_string("2026/02/22"),
+            Err(parse_error(F, 4, "expected '-'"))
+        );
+        assert_eq!(
+            from_date_string("2026-13-01"),
+            Err(parse_error(F, 5, "month is out of range"))
+        );
+        assert_eq!(
+            from_date_string("2026-02-29"),
+            Err(parse_error(F, 8, "day is out of range for the month"))
+        );
+        assert_eq!(
+            from_date_string("2026-02-22x"),
+            Err(parse_error(F, 10, "unexpected trailing input"))
+        );
+        assert_eq!(
+            from_date_string(""),
+            Err(parse_error(F, 0, "expected a four-digit year"))
+        );
+    }
+
+    // -- from_long_date / from_short_date -------------------------------------
+
+    #[test]
+    fn test_from_long_date_lenient() {
+        let expected = Ok(date(2026, 2, 22));
+        assert_eq!(from_long_date("february 22, 2026"), expected);
+        assert_eq!(from_long_date("  FEBRUARY   22 ,  2026  "), expected);
+        assert_eq!(from_long_date("February 22 2026"), expected);
+        assert_eq!(from_long_date("February\t22,\n2026"), expected);
+    }
+
+    #[test]
+    fn test_from_short_date_lenient() {
+        let expected = Ok(date(2026, 2, 22));
+        assert_eq!(from_short_date("feb 22, 2026"), expected);
+        assert_eq!(from_short_date("FEB. 22 2026"), expected);
+        assert_eq!(from_short_date(" Feb  22,2026 "), expected);
+    }
+
+    #[test]
+    fn test_from_long_date_errors() {
+        const F: &str = "long date";
+        assert_eq!(
+            from_long_date("Feb 22, 2026"),
+            Err(parse_error(F, 0, "expected a month name"))
+        );
+        assert_eq!(
+            from_long_date("February, 2026"),
+            Err(parse_error(F, 8, "expected a day"))
+        );
+        assert_eq!(
+            from_long_date("February 30, 2026"),
+            Err(parse_error(F, 9, "day is out of range for the month"))
+        );
+        assert_eq!(
+            from_long_date("February 22, 26"),
+            Err(parse_error(F, 13, "expected a four-digit year"))
+        );
+    }
+
+    #[test]
+    fn test_from_short_date_errors() {
+        const F: &str = "short date";
+        assert_eq!(
+            from_short_date("February 22, 2026"),
+            Err(parse_error(F, 0, "expected a month abbreviation"))
+        );
+        assert_eq!(
+            from_short_date("Feb 22, 2026 EST"),
+            Err(parse_error(F, 13, "unexpected trailing input"))
+        );
+    }
+
+    // -- from_iso8601 ---------------------------------------------------------
+
+    #[test]
+    fn test_from_iso8601_lenient() {
+        let expected = FixedOffset::east_opt(5 * 3600 + 30 * 60)
+            .unwrap()
+            .with_ymd_and_hms(2026, 2, 22, 14, 30, 0)
+            .unwrap();
+        assert_eq!(from_iso8601("2026-02-22T14:30:00+05:30"), Ok(expected));
+        assert_eq!(from_iso8601(" 2026-02-22t14:30:00+0530 "), Ok(expected));
+        assert_eq!(from_iso8601("2026-02-22 14:30:00 +05:30"), Ok(expected));
+        assert_eq!(from_iso8601("2026-02-22T09:00:00z"), Ok(expected));
+    }
+
+    #[test]
+    fn test_from_iso8601_fraction() {
+        let dt = from_iso8601("2026-02-22T14:30:00,25Z").unwrap();
+        assert_eq!(dt.timestamp_subsec_millis(), 250);
+        let dt = from_iso8601("2026-02-22T14:30:00.1234567891Z").unwrap();
+        assert_eq!(dt.timestamp_subsec_nanos(), 123_456_789);
+    }
+
+    #[test]
+    fn test_from_iso8601_leap_second() {
+        let dt = from_iso8601("2016-12-31T23:59:60Z").unwrap();
+        assert_eq!(dt.second(), 59);
+        assert_eq!(dt.timestamp_subsec_nanos(), 1_000_000_000);
+    }
+
+    #[test]
+    fn test_from_iso8601_errors() {
+        const F: &str = "ISO 8601 date-time";
+        assert_eq!(
+            from_iso8601("2026-02-22"),
+            Err(parse_error(
+                F,
+                10,
+                "expected 'T' between the date and the time"
+            ))
+        );
+        assert_eq!(
+            from_iso8601("2026-02-22T14:30"),
+            Err(parse_error(F, 16, "expected ':'"))
+        );
+        assert_eq!(
+            from_iso8601("2026-02-22T14:30:00"),
+            Err(parse_error(F, 19, "expected a UTC offset"))
+        );
+        assert_eq!(
+            from_iso8601("2026-02-22T24:00:00Z"),
+            Err(parse_error(F, 11, "hour is out of range"))
+        );
+        assert_eq!(
+            from_iso8601("2026-02-22T14:60:00Z"),
+            Err(parse_error(F, 14, "minute is out of range"))
+        );
+        assert_eq!(
+            from_iso8601("2026-02-22T14:30:00+25:00"),
+            Err(parse_error(F, 19, "offset is out of range"))
+        );
+        assert_eq!(
+            from_iso8601("2026-02-22T14:30:00.Z"),
+            Err(parse_error(
+                F,
+                20,
+                "expected digits after the decimal separator"
+            ))
+        );
+    }
+
+    // -- from_rfc2822 ---------------------------------------------------------
+
+    #[test]
+    fn test_from_rfc2822_lenient() {
+        let expected = FixedOffset::west_opt(6 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2026, 2, 22, 14, 30, 0)
+            .unwrap();
+        assert_eq!(
+            from_rfc2822("Sun, 22 Feb 2026 14:30:00 -0600"),
+            Ok(expected)
+        );
+        assert_eq!(
+            from_rfc2822("  sun ,22   FEB 2026 14:30 -0600 "),
+            Ok(expected)
+        );
+        assert_eq!(from_rfc2822("22 Feb 2026 14:30:00 CST"), Ok(expected));
+        assert_eq!(from_rfc2822("22 Feb 2026 20:30:00 gmt"), Ok(expected));
+    }
+
+    #[test]
+    fn test_from_rfc2822_zone_names() {
+        for (zone, hours) in [("UT", 0), ("Z", 0), ("EDT", -4), ("PST", -8)] {
+            let dt = from_rfc2822(&format!("22 Feb 2026 14:30:00 {zone}")).unwrap();
+            assert_eq!(dt.offset().local_minus_utc(), hours * 3600, "{zone}");
+        }
+    }
+
+    #[test]
+    fn test_from_rfc2822_errors() {
+        const F: &str = "RFC 2822 date-time";
+        assert_eq!(
+            from_rfc2822("Mon, 22 Feb 2026 14:30:00 +0000"),
+            Err(parse_error(F, 0, "weekday does not match the date"))
+        );
+        assert_eq!(
+            from_rfc2822("Sunday, 22 Feb 2026 14:30:00 +0000"),
+            Err(parse_error(F, 0, "expected a weekday abbreviation"))
+        );
+        assert_eq!(
+            from_rfc2822("Sun 22 Feb 2026 14:30:00 +0000"),
+            Err(parse_error(F, 4, "expected ',' after the weekday"))
+        );
+        assert_eq!(
+            from_rfc2822("Sun, 22 Foo 2026 14:30:00 +0000"),
+            Err(parse_error(F, 8, "expected a month abbreviation"))
+        );
+        assert_eq!(
+            from_rfc2822("Sun, 22 Feb 2026 14:30:00"),
+            Err(parse_error(F, 25, "expected a time zone"))
+        );
+        assert_eq!(
+            from_rfc2822("Sun, 22 Feb 2026 14:30:00 XYZ"),
+            Err(parse_error(F, 26, "unknown time zone"))
+        );
+    }
+
+    #[test]
+    fn test_error_display_has_position() {
+        let err = from_long_date("Febuary 22, 2026").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid long date at position 0: expected a month name"
+        );
+    }
+}
@@ -0,0 +1,783 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use chrono::{Offset, Timelike};
+
+use super::month_of;
+use crate::error::PeriodError;
+use crate::locale::{English, Locale, Style};
+use crate::relative::Relative;
+
+const FORMAT: &str = "date pattern";
+
+/// A value that [`format`](fn@format) and [`DatePattern::format`] can render.
+///
+/// Implemented for:
+///
+/// - [`NaiveDate`] — date fields only. A pattern with time fields (`h`, `m`,
+///   …) or zone fields (`Z`) is rejected.
+/// - [`NaiveDateTime`] — date and time fields. A pattern with zone fields is
+///   rejected, since the value has no offset.
+/// - [`DateTime<Tz>`] for any timezone, and [`Relative<Tz>`] — every field,
+///   read in the value's own timezone.
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait Formattable: private::Sealed {}
+
+impl Formattable for NaiveDate {}
+impl Formattable for NaiveDateTime {}
+impl<Tz: TimeZone> Formattable for DateTime<Tz> {}
+impl<Tz: TimeZone> Formattable for Relative<Tz> {}
+
+pub(crate) mod private {
+    use chrono::{FixedOffset, NaiveDate, NaiveTime};
+
+    /// The parts of a value a pattern can refer to. `time` and `offset` are
+    /// `None` when the value does not carry them.
+    pub struct Fields {
+        pub date: NaiveDate,
+        pub time: Option<NaiveTime>,
+        pub offset: Option<FixedOffset>,
+    }
+
+    pub trait Sealed {
+        fn fields(&self) -> Fields;
+    }
+}
+
+use private::Fields;
+
+impl private::Sealed for NaiveDate {
+    fn fields(&self) -> Fields {
+        Fields {
+            date: *self,
+            time: None,
+            offset: None,
+        }
+    }
+}
+
+impl private::Sealed for NaiveDateTime {
+    fn fields(&self) -> Fields {
+        Fields {
+            date: self.date(),
+            time: Some(self.time()),
+            offset: None,
+        }
+    }
+}
+
+impl<Tz: TimeZone> private::Sealed for DateTime<Tz> {
+    fn fields(&self) -> Fields {
+        let local = self.naive_local();
+        Fields {
+            date: local.date(),
+            time: Some(local.time()),
+            offset: Some(self.offset().fix()),
+        }
+    }
+}
+
+impl<Tz: TimeZone> private::Sealed for Relative<Tz> {
+    fn fields(&self) -> Fields {
+        self.clone().as_datetime().fields()
+    }
+}
+
+/// A compiled [LDML date pattern](https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table)
+/// such as `"yyyy-MM-dd"` or `"EEEE, MMMM d 'at' h:mm a"`.
+///
+/// Compiling checks the pattern once; [`format`](DatePattern::format) then
+/// only walks the compiled fields, so a pattern used in a loop should be
+/// compiled outside it. [`period::format`](crate::format) compiles and
+/// formats in one call.
+///
+/// Supported fields, with English names; see
+/// [`format_with_locale`](DatePattern::format_with_locale) for other
+/// languages:
+///
+/// | Letter | Field | Examples |
+/// |---|---|---|
+/// | `y` | year | `y` → `2026`, `yy` → `26`, `yyyy` → `2026` |
+/// | `M` | month | `M` → `2`, `MM` → `02`, `MMM` → `Feb`, `MMMM` → `February`, `MMMMM` → `F` |
+/// | `d` | day of month | `d` → `5`, `dd` → `05` |
+/// | `D` | day of year | `D` → `53`, `DDD` → `053` |
+/// | `E` | weekday | `E`–`EEE` → `Sun`, `EEEE` → `Sunday`, `EEEEE` → `S`, `EEEEEE` → `Su` |
+/// | `a` | AM/PM | `a`–`aaaa` → `PM`, `aaaaa` → `p` |
+/// | `h` / `H` | hour 1–12 / 0–23 | `h` → `2`, `HH` → `14` |
+/// | `K` / `k` | hour 0–11 / 1–24 | `K` → `2`, `kk` → `14` |
+/// | `m` / `s` | minute / second | `mm` → `05` |
+/// | `S` | fraction of a second, truncated | `SSS` → `250` |
+/// | `Z` | offset | `Z`–`ZZZ` → `+0530`, `ZZZZ` → `GMT+05:30`, `ZZZZZ` → `+05:30` or `Z` |
+///
+/// Text in single quotes is copied as is (`'at'`), and `''` is a literal
+/// quote. Other ASCII letters are reserved and rejected; every other
+/// character is copied as is. Years before 1 CE are written with a minus
+/// sign, as in ISO 8601.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use period::DatePattern;
+///
+/// # fn main() -> Result<(), period::PeriodError> {
+/// let pattern = DatePattern::compile("EEE, MMM d")?;
+/// let date = NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+/// assert_eq!(pattern.format(&date)?, "Sun, Feb 22");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatePattern {
+    source: String,
+    items: Vec<Item>,
+    /// Byte offset of the first time field, if any.
+    time_at: Option<usize>,
+    /// Byte offset of the first zone field, if any.
+    zone_at: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    Literal(String),
+    Field(Field, usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Year,
+    Month,
+    Day,
+    DayOfYear,
+    Weekday,
+    AmPm,
+    Hour12,
+    Hour23,
+    Hour11,
+    Hour24,
+    Minute,
+    Second,
+    Fraction,
+    Zone,
+}
+
+impl Field {
+    /// The field for a pattern letter and the most letters it may repeat.
+    fn from_letter(letter: u8) -> Option<(Field, usize)> {
+        Some(match letter {
+            b'y' => (Field::Year, 9),
+            b'M' => (Field::Month, 5),
+            b'd' => (Field::Day, 2),
+            b'D' => (Field::DayOfYear, 3),
+            b'E' => (Field::Weekday, 6),
+            b'a' => (Field::AmPm, 5),
+            b'h' => (Field::Hour12, 2),
+            b'H' => (Field::Hour23, 2),
+            b'K' => (Field::Hour11, 2),
+            b'k' => (Field::Hour24, 2),
+            b'm' => (Field::Minute, 2),
+            b's' => (Field::Second, 2),
+            b'S' => (Field::Fraction, 9),
+            b'Z' => (Field::Zone, 5),
+            _ => return None,
+        })
+    }
+
+    fn needs_time(self) -> bool {
+        matches!(
+            self,
+            Field::AmPm
+                | Field::Hour12
+                | Field::Hour23
+                | Field::Hour11
+                | Field::Hour24
+                | Field::Minute
+                | Field::Second
+                | Field::Fraction
+        )
+    }
+}
+
+impl DatePattern {
+    /// Compiles an LDML pattern such as `"yyyy-MM-dd'T'HH:mm"`.
+    ///
+    /// # Errors
+    /// Returns [`PeriodError::Parse`] with the byte offset of an unknown
+    /// pattern letter, a field repeated more times than it supports
+    /// (`"ddd"`), or an unterminated quote.
+    pub fn compile(pattern: &str) -> Result<DatePattern, PeriodError> {
+        let bytes = pattern.as_bytes();
+        let mut items = Vec::new();
+        let mut literal = String::new();
+        let mut time_at = None;
+        let mut zone_at = None;
+        let mut pos = 0;
+
+        while pos < bytes.len() {
+            let byte = bytes[pos];
+            if byte == b'\'' {
+                pos = read_quoted(pattern, pos, &mut literal)?;
+            } else if byte.is_ascii_alphabetic() {
+                let (field, max) =
+                    Field::from_letter(byte).ok_or_else(|| error(pos, "unknown pattern letter"))?;
+                let count = bytes[pos..].iter().take_while(|&&b| b == byte).count();
+                if count > max {
+                    return Err(error(pos, "too many pattern letters for this field"));
+                }
+                if field.needs_time() {
+                    time_at.get_or_insert(pos);
+                }
+                if field == Field::Zone {
+                    zone_at.get_or_insert(pos);
+                }
+                if !literal.is_empty() {
+                    items.push(Item::Literal(std::mem::take(&mut literal)));
+                }
+                items.push(Item::Field(field, count));
+                pos += count;
+            } else {
+                let ch = pattern[pos..].chars().next().unwrap_or_default();
+                literal.push(ch);
+                pos += ch.len_utf8();
+            }
+        }
+        if !literal.is_empty() {
+            items.push(Item::Literal(literal));
+        }
+
+        Ok(DatePattern {
+            source: pattern.to_owned(),
+            items,
+            time_at,
+            zone_at,
+        })
+    }
+
+    /// The pattern this was compiled from.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Formats `value` with this pattern, with English names.
+    ///
+    /// # Errors
+    /// Returns [`PeriodError::Parse`] pointing at the first time field when
+    /// `value` is a [`NaiveDate`], or at the first zone field when `value`
+    /// has no offset. See [`Formattable`].
+    #[inline]
+    pub fn format<V: Formattable + ?Sized>(&self, value: &V) -> Result<String, PeriodError> {
+        self.format_with_locale(value, &English)
+    }
+
+    /// Like [`format`](DatePattern::format), but with the month, weekday and
+    /// AM/PM names and the digits of `locale`.
+    ///
+    /// # Errors
+    /// Same as [`format`](DatePattern::format).
+    pub fn format_with_locale<V: Formattable + ?Sized>(
+        &self,
+        value: &V,
+        locale: &(impl Locale + ?Sized),
+    ) -> Result<String, PeriodError> {
+        let fields = value.fields();
+        if fields.time.is_none()
+            && let Some(at) = self.time_at
+        {
+            return Err(error(at, "time field used with a date-only value"));
+        }
+        if fields.offset.is_none()
+            && let Some(at) = self.zone_at
+        {
+            return Err(error(at, "zone field used with a value without an offset"));
+        }
+        Ok(Formatted {
+            pattern: self,
+            locale,
+            fields,
+        }
+        .to_string())
+    }
+}
+
+impl FromStr for DatePattern {
+    type Err = PeriodError;
+
+    /// Same as [`DatePattern::compile`].
+    fn from_str(s: &str) -> Result<DatePattern, PeriodError> {
+        DatePattern::compile(s)
+    }
+}
+
+impl fmt::Display for DatePattern {
+    /// Writes the source pattern.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Formats a date or date-time with an LDML pattern such as `"yyyy-MM-dd"`,
+/// `"EEEE, MMMM d"` or `"h:mm a ZZZZZ"`.
+///
+/// Compiles the pattern on every call; compile a [`DatePattern`] once
+/// instead when formatting many values. See [`DatePattern`] for the
+/// supported fields.
+///
+/// # Errors
+/// Returns [`PeriodError::Parse`] if the pattern is invalid, or uses fields
+/// the value does not have (a time on a [`NaiveDate`], an offset on a naive
+/// value).
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{FixedOffset, TimeZone};
+///
+/// # fn main() -> Result<(), period::PeriodError> {
+/// let tz = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+/// let dt = tz.with_ymd_and_hms(2026, 2, 22, 14, 30, 0).unwrap();
+/// assert_eq!(period::format(&dt, "EEEE h:mm a ZZZZZ")?, "Sunday 2:30 PM +05:30");
+/// # Ok(())
+/// # }
+/// ```
+pub fn format<V: Formattable + ?Sized>(value: &V, pattern: &str) -> Result<String, PeriodError> {
+    DatePattern::compile(pattern)?.format(value)
+}
+
+/// Reads the quoted literal opening at `pos` into `literal`, returning the
+/// position after it. `''` is a literal quote, inside or outside quotes.
+fn read_quoted(pattern: &str, pos: usize, literal: &mut String) -> Result<usize, PeriodError> {
+    let rest = &pattern[pos + 1..];
+    if rest.starts_with('\'') {
+        literal.push('\'');
+        return Ok(pos + 2);
+    }
+    let mut chars = rest.char_indices().peekable();
+    while let Some((i, ch)) = chars.next() {
+        if ch != '\'' {
+            literal.push(ch);
+        } else if chars.next_if(|&(_, next)| next == '\'').is_some() {
+            literal.push('\'');
+        } else {
+            return Ok(pos + 1 + i + 1);
+        }
+    }
+    Err(error(pos, "unterminated quote"))
+}
+
+/// A pattern applied to the fields of one value. Time and zone fields have
+/// been checked against the pattern before this is built.
+struct Formatted<'a, L: ?Sized> {
+    pattern: &'a DatePattern,
+    locale: &'a L,
+    fields: Fields,
+}
+
+impl<L: Locale + ?Sized> fmt::Display for Formatted<'_, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let locale = self.locale;
+        let Fields { date, time, offset } = self.fields;
+        let time = time.unwrap_or(NaiveTime::MIN);
+        for item in &self.pattern.items {
+            let (field, count) = match item {
+                Item::Literal(text) => {
+                    f.write_str(text)?;
+                    continue;
+                }
+                Item::Field(field, count) => (*field, *count),
+            };
+            let number = match field {
+                Field::Year if count == 2 => i64::from(date.year().rem_euclid(100)),
+                Field::Year => i64::from(date.year()),
+                Field::Month if count <= 2 => i64::from(date.month()),
+                Field::Day => i64::from(date.day()),
+                Field::DayOfYear => i64::from(date.ordinal()),
+                Field::Hour12 => i64::from((time.hour() + 11) % 12 + 1),
+                Field::Hour11 => i64::from(time.hour() % 12),
+                Field::Hour24 if time.hour() == 0 => 24,
+                Field::Hour23 | Field::Hour24 => i64::from(time.hour()),
+                Field::Minute => i64::from(time.minute()),
+                Field::Second => i64::from(time.second()),
+                Field::Fraction => {
+                    // A leap second is stored as nanoseconds past 1_000_000_000.
+                    let nanos = time.nanosecond() % 1_000_000_000;
+                    i64::from(nanos / 10_u32.pow(9 - u32::try_from(count).unwrap_or(9)))
+                }
+                Field::Month => {
+                    f.write_str(locale.month_name(month_of(date), name_style(count)))?;
+                    continue;
+                }
+                Field::Weekday => {
+                    let weekday = date.weekday();
+                    f.write_str(match count {
+                        6 => locale.weekday_short(weekday),
+                        _ => locale.weekday_name(weekday, name_style(count)),
+                    })?;
+                    continue;
+                }
+                Field::AmPm => {
+                    f.write_str(locale.day_period(time.hour() >= 12, name_style(count)))?;
+                    continue;
+                }
+                Field::Zone => {
+                    write_offset(f, offset.map_or(0, |o| o.local_minus_utc()), count)?;
+                    continue;
+                }
+            };
+            write_number(locale, f, number, count)?;
+        }
+        Ok(())
+    }
+}
+
+/// The name width for a text field repeated `count` times: four letters give
+/// the full name, fewer the abbreviated one and more the narrow one.
+fn name_style(count: usize) -> Style {
+    match count.cmp(&4) {
+        Ordering::Less => Style::Short,
+        Ordering::Equal => Style::Long,
+        Ordering::Greater => Style::Narrow,
+    }
+}
+
+/// Writes `n` in the locale's digits, zero-padded to `width` digits, with a
+/// leading `-` for negative values.
+fn write_number(
+    locale: &(impl Locale + ?Sized),
+    f: &mut fmt::Formatter<'_>,
+    n: i64,
+    width: usize,
+) -> fmt::Result {
+    if n < 0 {
+        f.write_str("-")?;
+    }
+    let n = n.unsigned_abs();
+    let digits = n.checked_ilog10().map_or(1, |log| log as usize + 1);
+    for _ in digits..width {
+        locale.write_number(0, f)?;
+    }
+    locale.write_number(n, f)
+}
+
+/// Writes an offset in the style chosen by the number of `Z` letters:
+/// `+0530`, `GMT+05:30` or `+05:30` (`Z` for UTC).
+fn write_offset(f: &mut fmt::Formatter<'_>, seconds: i32, count: usize) -> fmt::Result {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let minutes = seconds.unsigned_abs() / 60;
+    let (hours, minutes) = (minutes / 60, minutes % 60);
+    match count {
+        1..=3 => write!(f, "{sign}{hours:02}{minutes:02}"),
+        4 if seconds == 0 => f.write_str("GMT"),
+        4 => write!(f, "GMT{sign}{hours:02}:{minutes:02}"),
+        _ if seconds == 0 => f.write_str("Z"),
+        _ => write!(f, "{sign}{hours:02}:{minutes:02}"),
+    }
+}
+
+fn error(position: usize, reason: &'static str) -> PeriodError {
+    PeriodError::Parse {
+        format: FORMAT,
+        position,
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 2, 22).unwrap()
+    }
+
+    fn datetime() -> NaiveDateTime {
+        date().and_hms_milli_opt(14, 30, 5, 250).unwrap()
+    }
+
+    fn ist() -> DateTime<FixedOffset> {
+        let tz = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+        tz.from_local_datetime(&datetime()).unwrap()
+    }
+
+    // -- date fields ----------------------------------------------------------
+
+    #[test]
+    fn test_iso_date() {
+        assert_eq!(format(&date(), "yyyy-MM-dd").unwrap(), "2026-02-22");
+    }
+
+    #[test]
+    fn test_year_widths() {
+        let early = NaiveDate::from_ymd_opt(7, 3, 4).unwrap();
+        assert_eq!(format(&early, "y").unwrap(), "7");
+        assert_eq!(format(&early, "yy").unwrap(), "07");
+        assert_eq!(format(&early, "yyyy").unwrap(), "0007");
+        assert_eq!(format(&date(), "yy").unwrap(), "26");
+        assert_eq!(format(&date(), "yyyyy").unwrap(), "02026");
+    }
+
+    #[test]
+    fn test_year_before_common_era() {
+        let bce = NaiveDate::from_ymd_opt(-44, 3, 15).unwrap();
+        assert_eq!(format(&bce, "yyyy-MM-dd").unwrap(), "-0044-03-15");
+        assert_eq!(format(&bce, "yy").unwrap(), "56");
+    }
+
+    #[test]
+    fn test_month_widths() {
+        let cases = [
+            ("M", "2"),
+            ("MM", "02"),
+            ("MMM", "Feb"),
+            ("MMMM", "February"),
+            ("MMMMM", "F"),
+        ];
+        for (pattern, expected) in cases {
+            assert_eq!(format(&date(), pattern).unwrap(), expected, "{pattern}");
+        }
+    }
+
+    #[test]
+    fn test_every_month_name() {
+        let names: Vec<String> = (1..=12)
+            .map(|m| {
+                let date = NaiveDate::from_ymd_opt(2026, m, 1).unwrap();
+                format(&date, "MMMM/MMM").unwrap()
+            })
+            .collect();
+        assert_eq!(names[0], "January/Jan");
+        assert_eq!(names[4], "May/May");
+        assert_eq!(names[8], "September/Sep");
+        assert_eq!(names[11], "December/Dec");
+    }
+
+    #[test]
+    fn test_day_widths() {
+        let fifth = NaiveDate::from_ymd_opt(2026, 2, 5).unwrap();
+        assert_eq!(format(&fifth, "d").unwrap(), "5");
+        assert_eq!(format(&fifth, "dd").unwrap(), "05");
+        assert_eq!(format(&fifth, "MMM d").unwrap(), "Feb 5");
+    }
+
+    #[test]
+    fn test_day_of_year() {
+        assert_eq!(format(&date(), "D").unwrap(), "53");
+        assert_eq!(format(&date(), "DDD").unwrap(), "053");
+        let last = NaiveDate::from_ymd_opt(2028, 12, 31).unwrap();
+        assert_eq!(format(&last, "D").unwrap(), "366");
+    }
+
+    #[test]
+    fn test_weekday_widths() {
+        let cases = [
+            ("E", "Sun"),
+            ("EEE", "Sun"),
+            ("EEEE", "Sunday"),
+            ("EEEEE", "S"),
+            ("EEEEEE", "Su"),
+        ];
+        for (pattern, expected) in cases {
+            assert_eq!(format(&date(), pattern).unwrap(), expected, "{pattern}");
+        }
+        let thursday = NaiveDate::from_ymd_opt(2026, 2, 26).unwrap();
+        assert_eq!(format(&thursday, "EEEE").unwrap(), "Thursday");
+    }
+
+    // -- time fields ----------------------------------------------------------
+
+    #[test]
+    fn test_twelve_hour_clock() {
+        assert_eq!(format(&datetime(), "h:mm a").unwrap(), "2:30 PM");
+        assert_eq!(format(&datetime(), "hh:mm aaaaa").unwrap(), "02:30 p");
+    }
+
+    #[test]
+    fn test_hour_fields_around_midnight_and_noon() {
+        let midnight = date().and_hms_opt(0, 5, 0).unwrap();
+        let noon = date().and_hms_opt(12, 5, 0).unwrap();
+        assert_eq!(format(&midnight, "h a|H|K|k").unwrap(), "12 AM|0|0|24");
+        assert_eq!(format(&noon, "h a|H|K|k").unwrap(), "12 PM|12|0|12");
+    }
+
+    #[test]
+    fn test_seconds_and_fraction() {
+        assert_eq!(format(&datetime(), "HH:mm:ss").unwrap(), "14:30:05");
+        assert_eq!(format(&datetime(), "s.S").unwrap(), "5.2");
+        assert_eq!(format(&datetime(), "ss.SSS").unwrap(), "05.250");
+        assert_eq!(format(&datetime(), "SSSSSSSSS").unwrap(), "250000000");
+    }
+
+    #[test]
+    fn test_fraction_of_leap_second() {
+        let leap = date().and_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+        assert_eq!(format(&leap, "ss.SSS").unwrap(), "59.500");
+    }
+
+    // -- zone fields ----------------------------------------------------------
+
+    #[test]
+    fn test_zone_widths() {
+        let cases = [
+            ("Z", "+0530"),
+            ("ZZZ", "+0530"),
+            ("ZZZZ", "GMT+05:30"),
+            ("ZZZZZ", "+05:30"),
+        ];
+        for (pattern, expected) in cases {
+            assert_eq!(format(&ist(), pattern).unwrap(), expected, "{pattern}");
+        }
+    }
+
+    #[test]
+    fn test_zone_utc_and_negative() {
+        let utc = Utc.from_utc_datetime(&datetime());
+        assert_eq!(format(&utc, "Z|ZZZZ|ZZZZZ").unwrap(), "+0000|GMT|Z");
+        let cst = FixedOffset::west_opt(6 * 3600).unwrap();
+        let dt = cst.from_local_datetime(&datetime()).unwrap();
+        assert_eq!(
+            format(&dt, "Z|ZZZZ|ZZZZZ").unwrap(),
+            "-0600|GMT-06:00|-06:00"
+        );
+    }
+
+    #[test]
+    fn test_datetime_fields_are_wall_clock() {
+        assert_eq!(
+            format(&ist(), "yyyy-MM-dd'T'HH:mm:ssZZZZZ").unwrap(),
+            "2026-02-22T14:30:05+05:30"
+        );
+    }
+
+    #[test]
+    fn test_relative() {
+        let relative = crate::days_after(ist(), 1).unwrap();
+        assert_eq!(
+            format(&relative, "EEE d MMM ZZZZZ").unwrap(),
+            "Mon 23 Feb +05:30"
+        );
+    }
+
+    // -- literals -------------------------------------------------------------
+
+    #[test]
+    fn test_quoted_literals() {
+        assert_eq!(
+            format(&datetime(), "EEEE 'at' h 'o''clock'").unwrap(),
+            "Sunday at 2 o'clock"
+        );
+        assert_eq!(format(&date(), "''yy").unwrap(), "'26");
+        assert_eq!(format(&date(), "'yyyy'").unwrap(), "yyyy");
+    }
+
+    #[test]
+    fn test_non_letters_are_literal() {
+        assert_eq!(format(&date(), "d.M.yy — ✓").unwrap(), "22.2.26 — ✓");
+        assert_eq!(format(&date(), "yyyy年M月d日").unwrap(), "2026年2月22日");
+        assert_eq!(format(&date(), "").unwrap(), "");
+    }
+
+    // -- errors ---------------------------------------------------------------
+
+    #[test]
+    fn test_unknown_letter() {
+        assert_eq!(
+            format(&date(), "yyyy-MM-dd G").unwrap_err(),
+            error(11, "unknown pattern letter")
+        );
+    }
+
+    #[test]
+    fn test_too_many_letters() {
+        assert_eq!(
+            DatePattern::compile("MMM ddd").unwrap_err(),
+            error(4, "too many pattern letters for this field")
+        );
+        assert!(DatePattern::compile("MMMMMM").is_err());
+        assert!(DatePattern::compile("EEEEEEE").is_err());
+        assert!(DatePattern::compile("ZZZZZZ").is_err());
+    }
+
+    #[test]
+    fn test_unterminated_quote() {
+        let err = DatePattern::compile("h 'o''clock").unwrap_err();
+        assert_eq!(err, error(2, "unterminated quote"));
+        assert_eq!(
+            err.to_string(),
+            "invalid date pattern at position 2: unterminated quote"
+        );
+    }
+
+    #[test]
+    fn test_time_field_on_date() {
+        assert_eq!(
+            format(&date(), "MMM d, h:mm").unwrap_err(),
+            error(7, "time field used with a date-only value")
+        );
+    }
+
+    #[test]
+    fn test_zone_field_on_naive_value() {
+        assert_eq!(
+            format(&datetime(), "HH:mm Z").unwrap_err(),
+            error(6, "zone field used with a value without an offset")
+        );
+        assert_eq!(
+            format(&date(), "yyyy Z").unwrap_err(),
+            error(5, "zone field used with a value without an offset")
+        );
+    }
+
+    // -- DatePattern ----------------------------------------------------------
+
+    #[test]
+    fn test_compiled_pattern_is_reusable() {
+        let pattern: DatePattern = "MMM d".parse().unwrap();
+        assert_eq!(pattern.format(&date()).unwrap(), "Feb 22");
+        assert_eq!(pattern.format(&datetime()).unwrap(), "Feb 22");
+        assert_eq!(pattern.format(&ist()).unwrap(), "Feb 22");
+        assert_eq!(pattern.as_str(), "MMM d");
+        assert_eq!(pattern.to_string(), "MMM d");
+    }
+
+    #[test]
+    fn test_format_with_locale() {
+        let pattern = DatePattern::compile("EEEE d MMMM, a h:mm").unwrap();
+        assert_eq!(
+            pattern
+                .format_with_locale(&datetime(), &crate::French)
+                .unwrap(),
+            "dimanche 22 février, PM 2:30"
+        );
+        assert_eq!(
+            pattern
+                .format_with_locale(&datetime(), &crate::Japanese)
+                .unwrap(),
+            "日曜日 22 2月, 午後 2:30"
+        );
+    }
+
+    #[test]
+    fn test_locale_digits_are_padded() {
+        let early = NaiveDate::from_ymd_opt(2026, 3, 4).unwrap();
+        let pattern = DatePattern::compile("dd/MM/yyyy").unwrap();
+        assert_eq!(
+            pattern.format_with_locale(&early, &crate::Arabic).unwrap(),
+            "٠٤/٠٣/٢٠٢٦"
+        );
+    }
+
+    #[test]
+    fn test_matches_strftime() {
+        let pattern = DatePattern::compile("EEE, dd MMM yyyy HH:mm:ss Z").unwrap();
+        let dt = ist();
+        assert_eq!(
+            pattern.format(&dt).unwrap(),
+            dt.format("%a, %d %b %Y %H:%M:%S %z").to_string()
+        );
+    }
+}
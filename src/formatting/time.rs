@@ -0,0 +1,373 @@
+use std::fmt;
+
+use chrono::{NaiveDateTime, NaiveTime, Timelike};
+
+use super::{to_long_date, to_short_date};
+
+/// The smallest unit a [`TimeFormat`] shows. Smaller units are truncated,
+/// not rounded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimePrecision {
+    /// `"2:30 PM"`.
+    #[default]
+    Minutes,
+    /// `"2:30:05 PM"`.
+    Seconds,
+    /// `"2:30:05.250 PM"`.
+    Millis,
+}
+
+/// A configurable English time of day: `"2:30 PM"`, `"14:30:05"`,
+/// `"2:30:05.250 pm"`, `"noon"`.
+///
+/// Start from [`TimeFormat::twelve_hour`] or
+/// [`TimeFormat::twenty_four_hour`] and add seconds or milliseconds, lowercase
+/// AM/PM or the words `"noon"` and `"midnight"`. Every setter is `const`, so
+/// a format can live in a `const`.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveTime;
+/// use period::TimeFormat;
+///
+/// const SCHEDULE: TimeFormat = TimeFormat::twelve_hour()
+///     .lowercase(true)
+///     .noon_midnight(true);
+///
+/// let time = NaiveTime::from_hms_opt(14, 30, 0).unwrap();
+/// assert_eq!(SCHEDULE.format(time), "2:30 pm");
+/// assert_eq!(SCHEDULE.format(NaiveTime::from_hms_opt(12, 0, 0).unwrap()), "noon");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct TimeFormat {
+    twelve_hour: bool,
+    precision: TimePrecision,
+    lowercase: bool,
+    noon_midnight: bool,
+}
+
+impl TimeFormat {
+    /// `"2:30 PM"`: hours 1–12 without padding, minutes, and an AM/PM marker.
+    #[inline]
+    pub const fn twelve_hour() -> TimeFormat {
+        TimeFormat {
+            twelve_hour: true,
+            precision: TimePrecision::Minutes,
+            lowercase: false,
+            noon_midnight: false,
+        }
+    }
+
+    /// `"14:30"`: hours 00–23 and minutes, both zero-padded.
+    #[inline]
+    pub const fn twenty_four_hour() -> TimeFormat {
+        TimeFormat {
+            twelve_hour: false,
+            ..TimeFormat::twelve_hour()
+        }
+    }
+
+    /// Sets the smallest unit shown: minutes (the default), seconds
+    /// (`"2:30:05 PM"`) or milliseconds (`"2:30:05.250 PM"`).
+    #[inline]
+    pub const fn precision(mut self, precision: TimePrecision) -> TimeFormat {
+        self.precision = precision;
+        self
+    }
+
+    /// Writes the 12-hour marker in lowercase (`"pm"` instead of `"PM"`).
+    #[inline]
+    pub const fn lowercase(mut self, lowercase: bool) -> TimeFormat {
+        self.lowercase = lowercase;
+        self
+    }
+
+    /// Writes `"noon"` and `"midnight"` instead of a time that shows as
+    /// exactly 12:00 or 00:00 at the chosen [precision](TimeFormat::precision):
+    /// 12:00:30 counts as noon when seconds are not shown.
+    #[inline]
+    pub const fn noon_midnight(mut self, noon_midnight: bool) -> TimeFormat {
+        self.noon_midnight = noon_midnight;
+        self
+    }
+
+    /// Formats `time`.
+    #[must_use]
+    pub fn format(&self, time: NaiveTime) -> String {
+        Formatted {
+            format: *self,
+            time,
+        }
+        .to_string()
+    }
+}
+
+/// Converts a [`NaiveTime`] to a 12-hour time (e.g. `"2:30 PM"`).
+///
+/// The hour is not padded and seconds are dropped. See [`TimeFormat`] for
+/// seconds, lowercase markers and `"noon"`/`"midnight"`.
+#[must_use]
+#[inline]
+pub fn to_12h_time(time: NaiveTime) -> String {
+    TimeFormat::twelve_hour().format(time)
+}
+
+/// Converts a [`NaiveTime`] to a 24-hour time (e.g. `"14:30"`).
+///
+/// See [`TimeFormat`] for seconds and milliseconds.
+#[must_use]
+#[inline]
+pub fn to_24h_time(time: NaiveTime) -> String {
+    TimeFormat::twenty_four_hour().format(time)
+}
+
+/// Converts a [`NaiveTime`] to an ISO 8601 time string (`HH:MM:SS`), the
+/// time-of-day counterpart of [`to_date_string`](crate::to_date_string).
+///
+/// Fractions of a second are dropped; use [`TimeFormat::twenty_four_hour`]
+/// with [`TimePrecision::Millis`] to keep milliseconds.
+#[must_use]
+#[inline]
+pub fn to_time_string(time: NaiveTime) -> String {
+    TimeFormat::twenty_four_hour()
+        .precision(TimePrecision::Seconds)
+        .format(time)
+}
+
+/// Converts a [`NaiveDateTime`] to a short date and a 12-hour time
+/// (e.g. `"Feb 22, 2026, 2:30 PM"`).
+///
+/// For a [`DateTime`](chrono::DateTime), pass its
+/// [`naive_local`](chrono::DateTime::naive_local) wall-clock time.
+#[must_use]
+pub fn to_short_datetime(datetime: NaiveDateTime) -> String {
+    format!(
+        "{}, {}",
+        to_short_date(datetime.date()),
+        to_12h_time(datetime.time())
+    )
+}
+
+/// Converts a [`NaiveDateTime`] to a long date and a 12-hour time
+/// (e.g. `"February 22, 2026 at 2:30 PM"`).
+///
+/// For a [`DateTime`](chrono::DateTime), pass its
+/// [`naive_local`](chrono::DateTime::naive_local) wall-clock time.
+#[must_use]
+pub fn to_long_datetime(datetime: NaiveDateTime) -> String {
+    format!(
+        "{} at {}",
+        to_long_date(datetime.date()),
+        to_12h_time(datetime.time())
+    )
+}
+
+struct Formatted {
+    format: TimeFormat,
+    time: NaiveTime,
+}
+
+impl fmt::Display for Formatted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let TimeFormat {
+            twelve_hour,
+            precision,
+            lowercase,
+            noon_midnight,
+        } = self.format;
+        let time = self.time;
+        let seconds = precision >= TimePrecision::Seconds;
+        let millis = precision == TimePrecision::Millis;
+        // A leap second is stored as nanoseconds past 1_000_000_000.
+        let milli = time.nanosecond() % 1_000_000_000 / 1_000_000;
+        let (hour, minute, second) = (time.hour(), time.minute(), time.second());
+
+        let on_the_hour = minute == 0 && (!seconds || second == 0) && (!millis || milli == 0);
+        if noon_midnight && on_the_hour && hour % 12 == 0 {
+            return f.write_str(if hour == 12 { "noon" } else { "midnight" });
+        }
+
+        if twelve_hour {
+            write!(f, "{}:{minute:02}", (hour + 11) % 12 + 1)?;
+        } else {
+            write!(f, "{hour:02}:{minute:02}")?;
+        }
+        if seconds {
+            write!(f, ":{second:02}")?;
+        }
+        if millis {
+            write!(f, ".{milli:03}")?;
+        }
+        if twelve_hour {
+            f.write_str(match (hour >= 12, lowercase) {
+                (false, false) => " AM",
+                (true, false) => " PM",
+                (false, true) => " am",
+                (true, true) => " pm",
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn time(hour: u32, minute: u32, second: u32, milli: u32) -> NaiveTime {
+        NaiveTime::from_hms_milli_opt(hour, minute, second, milli).unwrap()
+    }
+
+    // -- to_12h_time ----------------------------------------------------------
+
+    #[test]
+    fn test_to_12h_time() {
+        assert_eq!(to_12h_time(time(14, 30, 0, 0)), "2:30 PM");
+        assert_eq!(to_12h_time(time(9, 5, 59, 999)), "9:05 AM");
+    }
+
+    #[test]
+    fn test_to_12h_time_around_midnight_and_noon() {
+        assert_eq!(to_12h_time(time(0, 0, 0, 0)), "12:00 AM");
+        assert_eq!(to_12h_time(time(0, 59, 0, 0)), "12:59 AM");
+        assert_eq!(to_12h_time(time(1, 0, 0, 0)), "1:00 AM");
+        assert_eq!(to_12h_time(time(11, 59, 0, 0)), "11:59 AM");
+        assert_eq!(to_12h_time(time(12, 0, 0, 0)), "12:00 PM");
+        assert_eq!(to_12h_time(time(13, 0, 0, 0)), "1:00 PM");
+        assert_eq!(to_12h_time(time(23, 59, 0, 0)), "11:59 PM");
+    }
+
+    // -- to_24h_time / to_time_string -----------------------------------------
+
+    #[test]
+    fn test_to_24h_time() {
+        assert_eq!(to_24h_time(time(14, 30, 0, 0)), "14:30");
+        assert_eq!(to_24h_time(time(0, 5, 0, 0)), "00:05");
+        assert_eq!(to_24h_time(time(23, 59, 59, 999)), "23:59");
+    }
+
+    #[test]
+    fn test_to_time_string() {
+        assert_eq!(to_time_string(time(14, 30, 5, 250)), "14:30:05");
+        assert_eq!(to_time_string(time(0, 0, 0, 0)), "00:00:00");
+    }
+
+    #[test]
+    fn test_to_time_string_matches_chrono() {
+        let t = time(7, 8, 9, 0);
+        assert_eq!(to_time_string(t), t.format("%H:%M:%S").to_string());
+    }
+
+    // -- TimeFormat -----------------------------------------------------------
+
+    #[test]
+    fn test_seconds_and_millis() {
+        let t = time(14, 30, 5, 250);
+        assert_eq!(
+            TimeFormat::twelve_hour()
+                .precision(TimePrecision::Seconds)
+                .format(t),
+            "2:30:05 PM"
+        );
+        assert_eq!(
+            TimeFormat::twelve_hour()
+                .precision(TimePrecision::Millis)
+                .format(t),
+            "2:30:05.250 PM"
+        );
+        assert_eq!(
+            TimeFormat::twenty_four_hour()
+                .precision(TimePrecision::Millis)
+                .format(t),
+            "14:30:05.250"
+        );
+        assert_eq!(
+            TimeFormat::twenty_four_hour()
+                .precision(TimePrecision::Millis)
+                .format(time(1, 2, 3, 4)),
+            "01:02:03.004"
+        );
+    }
+
+    #[test]
+    fn test_millis_of_leap_second() {
+        let leap = NaiveTime::from_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+        assert_eq!(
+            TimeFormat::twenty_four_hour()
+                .precision(TimePrecision::Millis)
+                .format(leap),
+            "23:59:59.500"
+        );
+    }
+
+    #[test]
+    fn test_lowercase() {
+        let format = TimeFormat::twelve_hour().lowercase(true);
+        assert_eq!(format.format(time(14, 30, 0, 0)), "2:30 pm");
+        assert_eq!(format.format(time(2, 30, 0, 0)), "2:30 am");
+        // The 24-hour clock has no marker to lowercase.
+        assert_eq!(
+            TimeFormat::twenty_four_hour()
+                .lowercase(true)
+                .format(time(14, 30, 0, 0)),
+            "14:30"
+        );
+    }
+
+    #[test]
+    fn test_noon_and_midnight() {
+        for format in [TimeFormat::twelve_hour(), TimeFormat::twenty_four_hour()] {
+            let format = format.noon_midnight(true);
+            assert_eq!(format.format(time(12, 0, 0, 0)), "noon");
+            assert_eq!(format.format(time(0, 0, 0, 0)), "midnight");
+        }
+        let format = TimeFormat::twelve_hour().noon_midnight(true);
+        assert_eq!(format.format(time(12, 1, 0, 0)), "12:01 PM");
+        assert_eq!(format.format(time(0, 1, 0, 0)), "12:01 AM");
+    }
+
+    #[test]
+    fn test_noon_follows_shown_precision() {
+        let format = TimeFormat::twelve_hour().noon_midnight(true);
+        assert_eq!(format.format(time(12, 0, 30, 0)), "noon");
+        assert_eq!(
+            format
+                .precision(TimePrecision::Seconds)
+                .format(time(12, 0, 30, 0)),
+            "12:00:30 PM"
+        );
+        assert_eq!(
+            format
+                .precision(TimePrecision::Seconds)
+                .format(time(12, 0, 0, 500)),
+            "noon"
+        );
+        assert_eq!(
+            format
+                .precision(TimePrecision::Millis)
+                .format(time(12, 0, 0, 500)),
+            "12:00:00.500 PM"
+        );
+    }
+
+    // -- combined date and time -----------------------------------------------
+
+    #[test]
+    fn test_to_short_datetime() {
+        let datetime = NaiveDate::from_ymd_opt(2026, 2, 22)
+            .unwrap()
+            .and_time(time(14, 30, 0, 0));
+        assert_eq!(to_short_datetime(datetime), "Feb 22, 2026, 2:30 PM");
+    }
+
+    #[test]
+    fn test_to_long_datetime() {
+        let datetime = NaiveDate::from_ymd_opt(2026, 2, 5)
+            .unwrap()
+            .and_time(time(0, 15, 0, 0));
+        assert_eq!(to_long_datetime(datetime), "February 5, 2026 at 12:15 AM");
+    }
+}
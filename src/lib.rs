@@ -1,15 +1,48 @@
+mod clock;
 mod date;
 mod error;
 mod formatting;
+mod locale;
 mod now;
+mod period;
 mod relative;
 
+#[cfg(feature = "test-util")]
+pub use clock::MockClock;
+pub use clock::{Clock, SystemClock};
 pub use date::{day_of_year, days_in_month, is_weekday, is_weekend, week_of_year};
 pub use error::PeriodError;
-pub use formatting::{to_date_string, to_iso8601, to_long_date, to_rfc2822, to_short_date};
-pub use now::{now, today};
+pub use formatting::{
+    DateOrder, DatePattern, Formattable, LongDate, TimeFormat, TimePrecision, format,
+    from_date_string, from_iso8601, from_long_date, from_rfc2822, from_short_date, ordinal_suffix,
+    to_12h_time, to_24h_time, to_date_string, to_full_date, to_iso8601, to_iso8601_duration,
+    to_long_date, to_long_date_with_locale, to_long_datetime, to_ordinal_date, to_rfc2822,
+    to_short_date, to_short_date_with_locale, to_short_datetime, to_time_string,
+};
+pub use locale::{
+    Arabic, English, French, German, Japanese, Locale, PluralCategory, Polish, Russian, Style,
+    Tense, TimeUnit,
+};
+pub use now::{now, now_in, now_with, today, today_in, today_with};
+pub use period::Period;
 pub use relative::{
-    Relative, days_ago, days_from_now, hours_ago, hours_from_now, humanize, minutes_ago,
-    minutes_from_now, months_ago, months_from_now, seconds_ago, seconds_from_now, tomorrow,
-    weeks_ago, weeks_from_now, years_ago, years_from_now, yesterday,
+    Anchor, Conjunction, Humanize, Humanized, HumanizedRange, Humanizer, HumanizerBuilder,
+    LiveLabel, Precise, Relative, RelativeBuilder, Rounding, Rung, ago, days_after, days_ago,
+    days_ago_in, days_ago_with, days_before, days_from_now, days_from_now_in, days_from_now_with,
+    from_now, hours_after, hours_ago, hours_ago_in, hours_ago_with, hours_before, hours_from_now,
+    hours_from_now_in, hours_from_now_with, humanize, humanize_calendar, humanize_calendar_with,
+    humanize_duration, humanize_live, humanize_live_with, humanize_precise, humanize_precise_with,
+    humanize_styled, humanize_styled_with, humanize_timestamp, humanize_timestamp_with,
+    humanize_with, humanize_with_locale, humanize_with_locale_with, humanized, humanized_with,
+    minutes_after, minutes_ago, minutes_ago_in, minutes_ago_with, minutes_before, minutes_from_now,
+    minutes_from_now_in, minutes_from_now_with, months_after, months_ago, months_ago_in,
+    months_ago_with, months_before, months_from_now, months_from_now_in, months_from_now_with,
+    parse, parse_humanized, parse_humanized_with, parse_in, parse_with, seconds_after, seconds_ago,
+    seconds_ago_in, seconds_ago_with, seconds_before, seconds_from_now, seconds_from_now_in,
+    seconds_from_now_with, tomorrow, tomorrow_in, tomorrow_with, weeks_after, weeks_ago,
+    weeks_ago_in, weeks_ago_with, weeks_before, weeks_from_now, weeks_from_now_in,
+    weeks_from_now_with, write_humanized, write_humanized_with, write_humanized_with_locale,
+    write_humanized_with_locale_with, years_after, years_ago, years_ago_in, years_ago_with,
+    years_before, years_from_now, years_from_now_in, years_from_now_with, yesterday, yesterday_in,
+    yesterday_with,
 };
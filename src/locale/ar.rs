@@ -0,0 +1,666 @@
+use std::fmt;
+
+use chrono::{Month, Weekday};
+
+use super::{Locale, PluralCategory, Style, Tense, TimeUnit};
+
+/// Arabic (`ar`), written with Arabic-Indic digits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Arabic;
+
+impl Locale for Arabic {
+    fn plural_category(&self, n: u64) -> PluralCategory {
+        match (n, n % 100) {
+            (0, _) => PluralCategory::Zero,
+            (1, _) => PluralCategory::One,
+            (2, _) => PluralCategory::Two,
+            (_, 3..=10) => PluralCategory::Few,
+            (_, 11..=99) => PluralCategory::Many,
+            _ => PluralCategory::Other,
+        }
+    }
+
+    fn just_now(&self) -> &'static str {
+        "الآن"
+    }
+
+    fn yesterday(&self) -> &'static str {
+        "أمس"
+    }
+
+    fn tomorrow(&self) -> &'static str {
+        "غدًا"
+    }
+
+    fn today(&self) -> &'static str {
+        "اليوم"
+    }
+
+    fn single_unit(&self, unit: TimeUnit, tense: Tense) -> &'static str {
+        let [_, one, ..] = match tense {
+            Tense::Past => past(unit),
+            Tense::Future => future(unit),
+        };
+        one
+    }
+
+    fn weekday(&self, weekday: Weekday, tense: Tense) -> &'static str {
+        match (tense, weekday) {
+            (Tense::Past, Weekday::Mon) => "الاثنين الماضي",
+            (Tense::Past, Weekday::Tue) => "الثلاثاء الماضي",
+            (Tense::Past, Weekday::Wed) => "الأربعاء الماضي",
+            (Tense::Past, Weekday::Thu) => "الخميس الماضي",
+            (Tense::Past, Weekday::Fri) => "الجمعة الماضية",
+            (Tense::Past, Weekday::Sat) => "السبت الماضي",
+            (Tense::Past, Weekday::Sun) => "الأحد الماضي",
+            (Tense::Future, Weekday::Mon) => "الاثنين القادم",
+            (Tense::Future, Weekday::Tue) => "الثلاثاء القادم",
+            (Tense::Future, Weekday::Wed) => "الأربعاء القادم",
+            (Tense::Future, Weekday::Thu) => "الخميس القادم",
+            (Tense::Future, Weekday::Fri) => "الجمعة القادمة",
+            (Tense::Future, Weekday::Sat) => "السبت القادم",
+            (Tense::Future, Weekday::Sun) => "الأحد القادم",
+        }
+    }
+
+    fn adjacent(&self, unit: TimeUnit, tense: Tense) -> &str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Week) => "الأسبوع الماضي",
+            (Tense::Past, TimeUnit::Month) => "الشهر الماضي",
+            (Tense::Past, TimeUnit::Year) => "السنة الماضية",
+            (Tense::Future, TimeUnit::Week) => "الأسبوع القادم",
+            (Tense::Future, TimeUnit::Month) => "الشهر القادم",
+            (Tense::Future, TimeUnit::Year) => "السنة القادمة",
+            _ => self.single_unit(unit, tense),
+        }
+    }
+
+    fn units(&self, unit: TimeUnit, tense: Tense, category: PluralCategory) -> &'static str {
+        let [zero, one, two, few, many, other] = match tense {
+            Tense::Past => past(unit),
+            Tense::Future => future(unit),
+        };
+        match category {
+            PluralCategory::Zero => zero,
+            PluralCategory::One => one,
+            PluralCategory::Two => two,
+            PluralCategory::Few => few,
+            PluralCategory::Many => many,
+            PluralCategory::Other => other,
+        }
+    }
+
+    fn write_number(&self, n: u64, out: &mut dyn fmt::Write) -> fmt::Result {
+        const DIGITS: [char; 10] = ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'];
+        // u64::MAX has 20 digits; fill from the right to avoid allocating.
+        let mut digits = [0; 20];
+        let mut start = digits.len();
+        let mut rest = n;
+        loop {
+            start -= 1;
+            digits[start] = (rest % 10) as usize;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+        for &digit in &digits[start..] {
+            out.write_char(DIGITS[digit])?;
+        }
+        Ok(())
+    }
+
+    fn relative(&self, tense: Tense) -> &'static str {
+        match tense {
+            Tense::Past => "قبل {0}",
+            Tense::Future => "خلال {0}",
+        }
+    }
+
+    fn list_separators(&self) -> [&'static str; 2] {
+        ["، ", " و"]
+    }
+
+    fn compact(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "{0} ث",
+            TimeUnit::Minute => "{0} د",
+            TimeUnit::Hour => "{0} س",
+            TimeUnit::Day => "{0} ي",
+            TimeUnit::Week => "{0} أ",
+            TimeUnit::Month => "{0} ش",
+            TimeUnit::Year => "{0} سنة",
+        }
+    }
+
+    fn few_seconds(&self) -> &'static str {
+        "بضع ثوانٍ"
+    }
+
+    fn about_one(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "حوالي ثانية",
+            TimeUnit::Minute => "حوالي دقيقة",
+            TimeUnit::Hour => "حوالي ساعة",
+            TimeUnit::Day => "حوالي يوم",
+            TimeUnit::Week => "حوالي أسبوع",
+            TimeUnit::Month => "حوالي شهر",
+            TimeUnit::Year => "حوالي سنة",
+        }
+    }
+
+    fn duration_units(&self, unit: TimeUnit, category: PluralCategory) -> &'static str {
+        let [zero, one, two, few, many, other] = match unit {
+            TimeUnit::Second => [
+                "{0} ثانية",
+                "ثانية واحدة",
+                "ثانيتان",
+                "{0} ثوانٍ",
+                "{0} ثانية",
+                "{0} ثانية",
+            ],
+            TimeUnit::Minute => [
+                "{0} دقيقة",
+                "دقيقة واحدة",
+                "دقيقتان",
+                "{0} دقائق",
+                "{0} دقيقة",
+                "{0} دقيقة",
+            ],
+            TimeUnit::Hour => [
+                "{0} ساعة",
+                "ساعة واحدة",
+                "ساعتان",
+                "{0} ساعات",
+                "{0} ساعة",
+                "{0} ساعة",
+            ],
+            TimeUnit::Day => [
+                "{0} يوم",
+                "يوم واحد",
+                "يومان",
+                "{0} أيام",
+                "{0} يومًا",
+                "{0} يوم",
+            ],
+            TimeUnit::Week => [
+                "{0} أسبوع",
+                "أسبوع واحد",
+                "أسبوعان",
+                "{0} أسابيع",
+                "{0} أسبوعًا",
+                "{0} أسبوع",
+            ],
+            TimeUnit::Month => [
+                "{0} شهر",
+                "شهر واحد",
+                "شهران",
+                "{0} أشهر",
+                "{0} شهرًا",
+                "{0} شهر",
+            ],
+            TimeUnit::Year => [
+                "{0} سنة",
+                "سنة واحدة",
+                "سنتان",
+                "{0} سنوات",
+                "{0} سنة",
+                "{0} سنة",
+            ],
+        };
+        match category {
+            PluralCategory::Zero => zero,
+            PluralCategory::One => one,
+            PluralCategory::Two => two,
+            PluralCategory::Few => few,
+            PluralCategory::Many => many,
+            PluralCategory::Other => other,
+        }
+    }
+
+    fn month_name(&self, month: Month, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long | Style::Short => [
+                "يناير",
+                "فبراير",
+                "مارس",
+                "أبريل",
+                "مايو",
+                "يونيو",
+                "يوليو",
+                "أغسطس",
+                "سبتمبر",
+                "أكتوبر",
+                "نوفمبر",
+                "ديسمبر",
+            ],
+            Style::Narrow => ["ي", "ف", "م", "أ", "و", "ن", "ل", "غ", "س", "ك", "ب", "د"],
+        };
+        names[month.number_from_month() as usize - 1]
+    }
+
+    fn weekday_name(&self, weekday: Weekday, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long | Style::Short => [
+                "الأحد",
+                "الاثنين",
+                "الثلاثاء",
+                "الأربعاء",
+                "الخميس",
+                "الجمعة",
+                "السبت",
+            ],
+            Style::Narrow => ["ح", "ن", "ث", "ر", "خ", "ج", "س"],
+        };
+        names[weekday.num_days_from_sunday() as usize]
+    }
+
+    fn weekday_short(&self, weekday: Weekday) -> &'static str {
+        ["أحد", "إثنين", "ثلاثاء", "أربعاء", "خميس", "جمعة", "سبت"]
+            [weekday.num_days_from_sunday() as usize]
+    }
+
+    fn day_period(&self, pm: bool, _: Style) -> &'static str {
+        if pm { "م" } else { "ص" }
+    }
+
+    fn long_date_pattern(&self) -> &'static str {
+        "d MMMM y"
+    }
+
+    fn short_date_pattern(&self) -> &'static str {
+        "d MMM y"
+    }
+}
+
+/// Past-tense patterns by plural category, from `zero` to `other`.
+fn past(unit: TimeUnit) -> [&'static str; 6] {
+    match unit {
+        TimeUnit::Second => [
+            "قبل {0} ثانية",
+            "قبل ثانية واحدة",
+            "قبل ثانيتين",
+            "قبل {0} ثوانٍ",
+            "قبل {0} ثانية",
+            "قبل {0} ثانية",
+        ],
+        TimeUnit::Minute => [
+            "قبل {0} دقيقة",
+            "قبل دقيقة واحدة",
+            "قبل دقيقتين",
+            "قبل {0} دقائق",
+            "قبل {0} دقيقة",
+            "قبل {0} دقيقة",
+        ],
+        TimeUnit::Hour => [
+            "قبل {0} ساعة",
+            "قبل ساعة واحدة",
+            "قبل ساعتين",
+            "قبل {0} ساعات",
+            "قبل {0} ساعة",
+            "قبل {0} ساعة",
+        ],
+        TimeUnit::Day => [
+            "قبل {0} يوم",
+            "قبل يوم واحد",
+            "قبل يومين",
+            "قبل {0} أيام",
+            "قبل {0} يومًا",
+            "قبل {0} يوم",
+        ],
+        TimeUnit::Week => [
+            "قبل {0} أسبوع",
+            "قبل أسبوع واحد",
+            "قبل أسبوعين",
+            "قبل {0} أسابيع",
+            "قبل {0} أسبوعًا",
+            "قبل {0} أسبوع",
+        ],
+        TimeUnit::Month => [
+            "قبل {0} شهر",
+            "قبل شهر واحد",
+            "قبل شهرين",
+            "قبل {0} أشهر",
+            "قبل {0} شهرًا",
+            "قبل {0} شهر",
+        ],
+        TimeUnit::Year => [
+            "قبل {0} سنة",
+            "قبل سنة واحدة",
+            "قبل سنتين",
+            "قبل {0} سنوات",
+            "قبل {0} سنة",
+            "قبل {0} سنة",
+        ],
+    }
+}
+
+/// Future-tense patterns by plural category, from `zero` to `other`.
+fn future(unit: TimeUnit) -> [&'static str; 6] {
+    match unit {
+        TimeUnit::Second => [
+            "خلال {0} ثانية",
+            "خلال ثانية واحدة",
+            "خلال ثانيتين",
+            "خلال {0} ثوانٍ",
+            "خلال {0} ثانية",
+            "خلال {0} ثانية",
+        ],
+        TimeUnit::Minute => [
+            "خلال {0} دقيقة",
+            "خلال دقيقة واحدة",
+            "خلال دقيقتين",
+            "خلال {0} دقائق",
+            "خلال {0} دقيقة",
+            "خلال {0} دقيقة",
+        ],
+        TimeUnit::Hour => [
+            "خلال {0} ساعة",
+            "خلال ساعة واحدة",
+            "خلال ساعتين",
+            "خلال {0} ساعات",
+            "خلال {0} ساعة",
+            "خلال {0} ساعة",
+        ],
+        TimeUnit::Day => [
+            "خلال {0} يوم",
+            "خلال يوم واحد",
+            "خلال يومين",
+            "خلال {0} أيام",
+            "خلال {0} يومًا",
+            "خلال {0} يوم",
+        ],
+        TimeUnit::Week => [
+            "خلال {0} أسبوع",
+            "خلال أسبوع واحد",
+            "خلال أسبوعين",
+            "خلال {0} أسابيع",
+            "خلال {0} أسبوعًا",
+            "خلال {0} أسبوع",
+        ],
+        TimeUnit::Month => [
+            "خلال {0} شهر",
+            "خلال شهر واحد",
+            "خلال شهرين",
+            "خلال {0} أشهر",
+            "خلال {0} شهرًا",
+            "خلال {0} شهر",
+        ],
+        TimeUnit::Year => [
+            "خلال {0} سنة",
+            "خلال سنة واحدة",
+            "خلال سنتين",
+            "خلال {0} سنوات",
+            "خلال {0} سنة",
+            "خلال {0} سنة",
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::tests::{
+        humanize_buckets, humanize_duration_buckets, month_dates, weekday_names,
+    };
+
+    #[test]
+    fn test_arabic_indic_digits() {
+        let write = |n| {
+            let mut out = String::new();
+            Arabic.write_number(n, &mut out).unwrap();
+            out
+        };
+        assert_eq!(write(0), "٠");
+        assert_eq!(write(105), "١٠٥");
+        assert_eq!(write(u64::MAX), "١٨٤٤٦٧٤٤٠٧٣٧٠٩٥٥١٦١٥");
+    }
+
+    #[test]
+    fn test_plural_categories() {
+        assert_eq!(Arabic.plural_category(0), PluralCategory::Zero);
+        assert_eq!(Arabic.plural_category(1), PluralCategory::One);
+        assert_eq!(Arabic.plural_category(2), PluralCategory::Two);
+        assert_eq!(Arabic.plural_category(3), PluralCategory::Few);
+        assert_eq!(Arabic.plural_category(10), PluralCategory::Few);
+        assert_eq!(Arabic.plural_category(11), PluralCategory::Many);
+        assert_eq!(Arabic.plural_category(99), PluralCategory::Many);
+        assert_eq!(Arabic.plural_category(100), PluralCategory::Other);
+        assert_eq!(Arabic.plural_category(102), PluralCategory::Other);
+        assert_eq!(Arabic.plural_category(103), PluralCategory::Few);
+    }
+
+    #[test]
+    fn test_every_bucket() {
+        let (past, future) = humanize_buckets(&Arabic);
+        assert_eq!(
+            past,
+            [
+                "الآن",
+                "قبل دقيقة واحدة",
+                "قبل دقيقة واحدة",
+                "قبل دقيقتين",
+                "قبل ٣ دقائق",
+                "قبل ٥ دقائق",
+                "قبل ١١ دقيقة",
+                "قبل ٢١ دقيقة",
+                "قبل ٢٢ دقيقة",
+                "قبل ساعة واحدة",
+                "قبل ساعة واحدة",
+                "قبل ساعتين",
+                "قبل ٣ ساعات",
+                "قبل ٥ ساعات",
+                "قبل ٢١ ساعة",
+                "أمس",
+                "قبل يوم واحد",
+                "قبل يومين",
+                "قبل ٣ أيام",
+                "قبل ٥ أيام",
+                "قبل ١١ يومًا",
+                "قبل ٢٢ يومًا",
+                "قبل شهر واحد",
+                "قبل شهر واحد",
+                "قبل شهرين",
+                "قبل ٥ أشهر",
+                "قبل سنة واحدة",
+                "قبل سنة واحدة",
+                "قبل سنتين",
+                "قبل ٣ سنوات",
+                "قبل ٥ سنوات",
+                "قبل ١١ سنة",
+                "قبل ٢١ سنة",
+                "قبل ٢٢ سنة",
+                "قبل ١٠٠ سنة",
+                "قبل ١٠٢ سنة",
+            ]
+        );
+        assert_eq!(
+            future,
+            [
+                "الآن",
+                "خلال دقيقة واحدة",
+                "خلال دقيقة واحدة",
+                "خلال دقيقتين",
+                "خلال ٣ دقائق",
+                "خلال ٥ دقائق",
+                "خلال ١١ دقيقة",
+                "خلال ٢١ دقيقة",
+                "خلال ٢٢ دقيقة",
+                "خلال ساعة واحدة",
+                "خلال ساعة واحدة",
+                "خلال ساعتين",
+                "خلال ٣ ساعات",
+                "خلال ٥ ساعات",
+                "خلال ٢١ ساعة",
+                "غدًا",
+                "خلال يوم واحد",
+                "خلال يومين",
+                "خلال ٣ أيام",
+                "خلال ٥ أيام",
+                "خلال ١١ يومًا",
+                "خلال ٢٢ يومًا",
+                "خلال شهر واحد",
+                "خلال شهر واحد",
+                "خلال شهرين",
+                "خلال ٥ أشهر",
+                "خلال سنة واحدة",
+                "خلال سنة واحدة",
+                "خلال سنتين",
+                "خلال ٣ سنوات",
+                "خلال ٥ سنوات",
+                "خلال ١١ سنة",
+                "خلال ٢١ سنة",
+                "خلال ٢٢ سنة",
+                "خلال ١٠٠ سنة",
+                "خلال ١٠٢ سنة",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_duration_bucket() {
+        assert_eq!(
+            humanize_duration_buckets(&Arabic),
+            [
+                "بضع ثوانٍ",
+                "حوالي دقيقة",
+                "دقيقة واحدة",
+                "دقيقتان",
+                "٣ دقائق",
+                "٥ دقائق",
+                "١١ دقيقة",
+                "٢١ دقيقة",
+                "٢٢ دقيقة",
+                "حوالي ساعة",
+                "ساعة واحدة",
+                "ساعتان",
+                "٣ ساعات",
+                "٥ ساعات",
+                "٢١ ساعة",
+                "حوالي يوم",
+                "يوم واحد",
+                "يومان",
+                "٣ أيام",
+                "٥ أيام",
+                "١١ يومًا",
+                "٢٢ يومًا",
+                "حوالي شهر",
+                "شهر واحد",
+                "شهران",
+                "٥ أشهر",
+                "حوالي سنة",
+                "سنة واحدة",
+                "سنتان",
+                "٣ سنوات",
+                "٥ سنوات",
+                "١١ سنة",
+                "٢١ سنة",
+                "٢٢ سنة",
+                "١٠٠ سنة",
+                "١٠٢ سنة",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calendar_phrases() {
+        assert_eq!(Arabic.today(), "اليوم");
+        let days = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        assert_eq!(
+            days.map(|day| Arabic.weekday(day, Tense::Past)),
+            [
+                "الاثنين الماضي",
+                "الثلاثاء الماضي",
+                "الأربعاء الماضي",
+                "الخميس الماضي",
+                "الجمعة الماضية",
+                "السبت الماضي",
+                "الأحد الماضي",
+            ]
+        );
+        assert_eq!(
+            days.map(|day| Arabic.weekday(day, Tense::Future)),
+            [
+                "الاثنين القادم",
+                "الثلاثاء القادم",
+                "الأربعاء القادم",
+                "الخميس القادم",
+                "الجمعة القادمة",
+                "السبت القادم",
+                "الأحد القادم",
+            ]
+        );
+        let units = [TimeUnit::Week, TimeUnit::Month, TimeUnit::Year];
+        assert_eq!(
+            units.map(|unit| Arabic.adjacent(unit, Tense::Past)),
+            ["الأسبوع الماضي", "الشهر الماضي", "السنة الماضية"]
+        );
+        assert_eq!(
+            units.map(|unit| Arabic.adjacent(unit, Tense::Future)),
+            ["الأسبوع القادم", "الشهر القادم", "السنة القادمة"]
+        );
+    }
+
+    #[test]
+    fn test_every_month_date() {
+        let (long, short) = month_dates(&Arabic);
+        assert_eq!(
+            long,
+            [
+                "٢٢ يناير ٢٠٢٦",
+                "٢٢ فبراير ٢٠٢٦",
+                "٢٢ مارس ٢٠٢٦",
+                "٢٢ أبريل ٢٠٢٦",
+                "٢٢ مايو ٢٠٢٦",
+                "٢٢ يونيو ٢٠٢٦",
+                "٢٢ يوليو ٢٠٢٦",
+                "٢٢ أغسطس ٢٠٢٦",
+                "٢٢ سبتمبر ٢٠٢٦",
+                "٢٢ أكتوبر ٢٠٢٦",
+                "٢٢ نوفمبر ٢٠٢٦",
+                "٢٢ ديسمبر ٢٠٢٦",
+            ]
+        );
+        assert_eq!(
+            short,
+            [
+                "٢٢ يناير ٢٠٢٦",
+                "٢٢ فبراير ٢٠٢٦",
+                "٢٢ مارس ٢٠٢٦",
+                "٢٢ أبريل ٢٠٢٦",
+                "٢٢ مايو ٢٠٢٦",
+                "٢٢ يونيو ٢٠٢٦",
+                "٢٢ يوليو ٢٠٢٦",
+                "٢٢ أغسطس ٢٠٢٦",
+                "٢٢ سبتمبر ٢٠٢٦",
+                "٢٢ أكتوبر ٢٠٢٦",
+                "٢٢ نوفمبر ٢٠٢٦",
+                "٢٢ ديسمبر ٢٠٢٦",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekday_names() {
+        assert_eq!(
+            weekday_names(&Arabic),
+            [
+                "الأحد|الأحد|ح|أحد",
+                "الاثنين|الاثنين|ن|إثنين",
+                "الثلاثاء|الثلاثاء|ث|ثلاثاء",
+                "الأربعاء|الأربعاء|ر|أربعاء",
+                "الخميس|الخميس|خ|خميس",
+                "الجمعة|الجمعة|ج|جمعة",
+                "السبت|السبت|س|سبت",
+            ]
+        );
+    }
+}
@@ -0,0 +1,465 @@
+use chrono::{Month, Weekday};
+
+use super::{Locale, PluralCategory, Style, Tense, TimeUnit};
+
+/// German (`de`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct German;
+
+impl Locale for German {
+    fn plural_category(&self, n: u64) -> PluralCategory {
+        if n == 1 {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    fn just_now(&self) -> &'static str {
+        "gerade eben"
+    }
+
+    fn yesterday(&self) -> &'static str {
+        "gestern"
+    }
+
+    fn tomorrow(&self) -> &'static str {
+        "morgen"
+    }
+
+    fn today(&self) -> &'static str {
+        "heute"
+    }
+
+    fn single_unit(&self, unit: TimeUnit, tense: Tense) -> &'static str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Second) => "vor einer Sekunde",
+            (Tense::Past, TimeUnit::Minute) => "vor einer Minute",
+            (Tense::Past, TimeUnit::Hour) => "vor einer Stunde",
+            (Tense::Past, TimeUnit::Day) => "vor einem Tag",
+            (Tense::Past, TimeUnit::Week) => "vor einer Woche",
+            (Tense::Past, TimeUnit::Month) => "vor einem Monat",
+            (Tense::Past, TimeUnit::Year) => "vor einem Jahr",
+            (Tense::Future, TimeUnit::Second) => "in einer Sekunde",
+            (Tense::Future, TimeUnit::Minute) => "in einer Minute",
+            (Tense::Future, TimeUnit::Hour) => "in einer Stunde",
+            (Tense::Future, TimeUnit::Day) => "in einem Tag",
+            (Tense::Future, TimeUnit::Week) => "in einer Woche",
+            (Tense::Future, TimeUnit::Month) => "in einem Monat",
+            (Tense::Future, TimeUnit::Year) => "in einem Jahr",
+        }
+    }
+
+    fn weekday(&self, weekday: Weekday, tense: Tense) -> &'static str {
+        match (tense, weekday) {
+            (Tense::Past, Weekday::Mon) => "letzten Montag",
+            (Tense::Past, Weekday::Tue) => "letzten Dienstag",
+            (Tense::Past, Weekday::Wed) => "letzten Mittwoch",
+            (Tense::Past, Weekday::Thu) => "letzten Donnerstag",
+            (Tense::Past, Weekday::Fri) => "letzten Freitag",
+            (Tense::Past, Weekday::Sat) => "letzten Samstag",
+            (Tense::Past, Weekday::Sun) => "letzten Sonntag",
+            (Tense::Future, Weekday::Mon) => "nächsten Montag",
+            (Tense::Future, Weekday::Tue) => "nächsten Dienstag",
+            (Tense::Future, Weekday::Wed) => "nächsten Mittwoch",
+            (Tense::Future, Weekday::Thu) => "nächsten Donnerstag",
+            (Tense::Future, Weekday::Fri) => "nächsten Freitag",
+            (Tense::Future, Weekday::Sat) => "nächsten Samstag",
+            (Tense::Future, Weekday::Sun) => "nächsten Sonntag",
+        }
+    }
+
+    fn adjacent(&self, unit: TimeUnit, tense: Tense) -> &str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Week) => "letzte Woche",
+            (Tense::Past, TimeUnit::Month) => "letzten Monat",
+            (Tense::Past, TimeUnit::Year) => "letztes Jahr",
+            (Tense::Future, TimeUnit::Week) => "nächste Woche",
+            (Tense::Future, TimeUnit::Month) => "nächsten Monat",
+            (Tense::Future, TimeUnit::Year) => "nächstes Jahr",
+            _ => self.single_unit(unit, tense),
+        }
+    }
+
+    fn units(&self, unit: TimeUnit, tense: Tense, category: PluralCategory) -> &'static str {
+        let [one, other] = match (tense, unit) {
+            (Tense::Past, TimeUnit::Second) => ["vor {0} Sekunde", "vor {0} Sekunden"],
+            (Tense::Past, TimeUnit::Minute) => ["vor {0} Minute", "vor {0} Minuten"],
+            (Tense::Past, TimeUnit::Hour) => ["vor {0} Stunde", "vor {0} Stunden"],
+            (Tense::Past, TimeUnit::Day) => ["vor {0} Tag", "vor {0} Tagen"],
+            (Tense::Past, TimeUnit::Week) => ["vor {0} Woche", "vor {0} Wochen"],
+            (Tense::Past, TimeUnit::Month) => ["vor {0} Monat", "vor {0} Monaten"],
+            (Tense::Past, TimeUnit::Year) => ["vor {0} Jahr", "vor {0} Jahren"],
+            (Tense::Future, TimeUnit::Second) => ["in {0} Sekunde", "in {0} Sekunden"],
+            (Tense::Future, TimeUnit::Minute) => ["in {0} Minute", "in {0} Minuten"],
+            (Tense::Future, TimeUnit::Hour) => ["in {0} Stunde", "in {0} Stunden"],
+            (Tense::Future, TimeUnit::Day) => ["in {0} Tag", "in {0} Tagen"],
+            (Tense::Future, TimeUnit::Week) => ["in {0} Woche", "in {0} Wochen"],
+            (Tense::Future, TimeUnit::Month) => ["in {0} Monat", "in {0} Monaten"],
+            (Tense::Future, TimeUnit::Year) => ["in {0} Jahr", "in {0} Jahren"],
+        };
+        match category {
+            PluralCategory::One => one,
+            _ => other,
+        }
+    }
+
+    fn relative(&self, tense: Tense) -> &'static str {
+        match tense {
+            Tense::Past => "vor {0}",
+            Tense::Future => "in {0}",
+        }
+    }
+
+    fn list_separators(&self) -> [&'static str; 2] {
+        [", ", " und "]
+    }
+
+    fn compact(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "{0} Sek.",
+            TimeUnit::Minute => "{0} Min.",
+            TimeUnit::Hour => "{0} Std.",
+            TimeUnit::Day => "{0} T.",
+            TimeUnit::Week => "{0} W.",
+            TimeUnit::Month => "{0} M.",
+            TimeUnit::Year => "{0} J.",
+        }
+    }
+
+    fn few_seconds(&self) -> &'static str {
+        "ein paar Sekunden"
+    }
+
+    fn about_one(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "etwa eine Sekunde",
+            TimeUnit::Minute => "etwa eine Minute",
+            TimeUnit::Hour => "etwa eine Stunde",
+            TimeUnit::Day => "etwa ein Tag",
+            TimeUnit::Week => "etwa eine Woche",
+            TimeUnit::Month => "etwa ein Monat",
+            TimeUnit::Year => "etwa ein Jahr",
+        }
+    }
+
+    fn duration_units(&self, unit: TimeUnit, category: PluralCategory) -> &'static str {
+        let [one, other] = match unit {
+            TimeUnit::Second => ["{0} Sekunde", "{0} Sekunden"],
+            TimeUnit::Minute => ["{0} Minute", "{0} Minuten"],
+            TimeUnit::Hour => ["{0} Stunde", "{0} Stunden"],
+            TimeUnit::Day => ["{0} Tag", "{0} Tage"],
+            TimeUnit::Week => ["{0} Woche", "{0} Wochen"],
+            TimeUnit::Month => ["{0} Monat", "{0} Monate"],
+            TimeUnit::Year => ["{0} Jahr", "{0} Jahre"],
+        };
+        match category {
+            PluralCategory::One => one,
+            _ => other,
+        }
+    }
+
+    fn month_name(&self, month: Month, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long => [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            Style::Short => [
+                "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.",
+                "Nov.", "Dez.",
+            ],
+            Style::Narrow => ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+        };
+        names[month.number_from_month() as usize - 1]
+    }
+
+    fn weekday_name(&self, weekday: Weekday, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long => [
+                "Sonntag",
+                "Montag",
+                "Dienstag",
+                "Mittwoch",
+                "Donnerstag",
+                "Freitag",
+                "Samstag",
+            ],
+            Style::Short => ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+            Style::Narrow => ["S", "M", "D", "M", "D", "F", "S"],
+        };
+        names[weekday.num_days_from_sunday() as usize]
+    }
+
+    fn day_period(&self, pm: bool, _: Style) -> &'static str {
+        if pm { "PM" } else { "AM" }
+    }
+
+    fn long_date_pattern(&self) -> &'static str {
+        "d. MMMM y"
+    }
+
+    fn short_date_pattern(&self) -> &'static str {
+        "d. MMM y"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::tests::{
+        humanize_buckets, humanize_duration_buckets, month_dates, weekday_names,
+    };
+
+    #[test]
+    fn test_plural_categories() {
+        assert_eq!(German.plural_category(0), PluralCategory::Other);
+        assert_eq!(German.plural_category(1), PluralCategory::One);
+        assert_eq!(German.plural_category(2), PluralCategory::Other);
+        assert_eq!(German.plural_category(21), PluralCategory::Other);
+    }
+
+    #[test]
+    fn test_every_bucket() {
+        let (past, future) = humanize_buckets(&German);
+        assert_eq!(
+            past,
+            [
+                "gerade eben",
+                "vor einer Minute",
+                "vor 1 Minute",
+                "vor 2 Minuten",
+                "vor 3 Minuten",
+                "vor 5 Minuten",
+                "vor 11 Minuten",
+                "vor 21 Minuten",
+                "vor 22 Minuten",
+                "vor einer Stunde",
+                "vor 1 Stunde",
+                "vor 2 Stunden",
+                "vor 3 Stunden",
+                "vor 5 Stunden",
+                "vor 21 Stunden",
+                "gestern",
+                "vor 1 Tag",
+                "vor 2 Tagen",
+                "vor 3 Tagen",
+                "vor 5 Tagen",
+                "vor 11 Tagen",
+                "vor 22 Tagen",
+                "vor einem Monat",
+                "vor 1 Monat",
+                "vor 2 Monaten",
+                "vor 5 Monaten",
+                "vor einem Jahr",
+                "vor 1 Jahr",
+                "vor 2 Jahren",
+                "vor 3 Jahren",
+                "vor 5 Jahren",
+                "vor 11 Jahren",
+                "vor 21 Jahren",
+                "vor 22 Jahren",
+                "vor 100 Jahren",
+                "vor 102 Jahren",
+            ]
+        );
+        assert_eq!(
+            future,
+            [
+                "gerade eben",
+                "in einer Minute",
+                "in 1 Minute",
+                "in 2 Minuten",
+                "in 3 Minuten",
+                "in 5 Minuten",
+                "in 11 Minuten",
+                "in 21 Minuten",
+                "in 22 Minuten",
+                "in einer Stunde",
+                "in 1 Stunde",
+                "in 2 Stunden",
+                "in 3 Stunden",
+                "in 5 Stunden",
+                "in 21 Stunden",
+                "morgen",
+                "in 1 Tag",
+                "in 2 Tagen",
+                "in 3 Tagen",
+                "in 5 Tagen",
+                "in 11 Tagen",
+                "in 22 Tagen",
+                "in einem Monat",
+                "in 1 Monat",
+                "in 2 Monaten",
+                "in 5 Monaten",
+                "in einem Jahr",
+                "in 1 Jahr",
+                "in 2 Jahren",
+                "in 3 Jahren",
+                "in 5 Jahren",
+                "in 11 Jahren",
+                "in 21 Jahren",
+                "in 22 Jahren",
+                "in 100 Jahren",
+                "in 102 Jahren",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_duration_bucket() {
+        assert_eq!(
+            humanize_duration_buckets(&German),
+            [
+                "ein paar Sekunden",
+                "etwa eine Minute",
+                "1 Minute",
+                "2 Minuten",
+                "3 Minuten",
+                "5 Minuten",
+                "11 Minuten",
+                "21 Minuten",
+                "22 Minuten",
+                "etwa eine Stunde",
+                "1 Stunde",
+                "2 Stunden",
+                "3 Stunden",
+                "5 Stunden",
+                "21 Stunden",
+                "etwa ein Tag",
+                "1 Tag",
+                "2 Tage",
+                "3 Tage",
+                "5 Tage",
+                "11 Tage",
+                "22 Tage",
+                "etwa ein Monat",
+                "1 Monat",
+                "2 Monate",
+                "5 Monate",
+                "etwa ein Jahr",
+                "1 Jahr",
+                "2 Jahre",
+                "3 Jahre",
+                "5 Jahre",
+                "11 Jahre",
+                "21 Jahre",
+                "22 Jahre",
+                "100 Jahre",
+                "102 Jahre",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calendar_phrases() {
+        assert_eq!(German.today(), "heute");
+        let days = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        assert_eq!(
+            days.map(|day| German.weekday(day, Tense::Past)),
+            [
+                "letzten Montag",
+                "letzten Dienstag",
+                "letzten Mittwoch",
+                "letzten Donnerstag",
+                "letzten Freitag",
+                "letzten Samstag",
+                "letzten Sonntag",
+            ]
+        );
+        assert_eq!(
+            days.map(|day| German.weekday(day, Tense::Future)),
+            [
+                "nächsten Montag",
+                "nächsten Dienstag",
+                "nächsten Mittwoch",
+                "nächsten Donnerstag",
+                "nächsten Freitag",
+                "nächsten Samstag",
+                "nächsten Sonntag",
+            ]
+        );
+        let units = [TimeUnit::Week, TimeUnit::Month, TimeUnit::Year];
+        assert_eq!(
+            units.map(|unit| German.adjacent(unit, Tense::Past)),
+            ["letzte Woche", "letzten Monat", "letztes Jahr"]
+        );
+        assert_eq!(
+            units.map(|unit| German.adjacent(unit, Tense::Future)),
+            ["nächste Woche", "nächsten Monat", "nächstes Jahr"]
+        );
+    }
+
+    #[test]
+    fn test_every_month_date() {
+        let (long, short) = month_dates(&German);
+        assert_eq!(
+            long,
+            [
+                "22. Januar 2026",
+                "22. Februar 2026",
+                "22. März 2026",
+                "22. April 2026",
+                "22. Mai 2026",
+                "22. Juni 2026",
+                "22. Juli 2026",
+                "22. August 2026",
+                "22. September 2026",
+                "22. Oktober 2026",
+                "22. November 2026",
+                "22. Dezember 2026",
+            ]
+        );
+        assert_eq!(
+            short,
+            [
+                "22. Jan. 2026",
+                "22. Feb. 2026",
+                "22. März 2026",
+                "22. Apr. 2026",
+                "22. Mai 2026",
+                "22. Juni 2026",
+                "22. Juli 2026",
+                "22. Aug. 2026",
+                "22. Sept. 2026",
+                "22. Okt. 2026",
+                "22. Nov. 2026",
+                "22. Dez. 2026",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekday_names() {
+        assert_eq!(
+            weekday_names(&German),
+            [
+                "Sonntag|So.|S|So.",
+                "Montag|Mo.|M|Mo.",
+                "Dienstag|Di.|D|Di.",
+                "Mittwoch|Mi.|M|Mi.",
+                "Donnerstag|Do.|D|Do.",
+                "Freitag|Fr.|F|Fr.",
+                "Samstag|Sa.|S|Sa.",
+            ]
+        );
+    }
+}
@@ -0,0 +1,733 @@
+use chrono::{Month, Weekday};
+
+use super::{Locale, PluralCategory, Style, Tense, TimeUnit};
+
+/// English, the language of [`humanize`](fn@crate::humanize).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct English;
+
+impl Locale for English {
+    fn plural_category(&self, n: u64) -> PluralCategory {
+        if n == 1 {
+            PluralCategory::One
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    fn just_now(&self) -> &'static str {
+        "just now"
+    }
+
+    fn yesterday(&self) -> &'static str {
+        "yesterday"
+    }
+
+    fn tomorrow(&self) -> &'static str {
+        "tomorrow"
+    }
+
+    fn today(&self) -> &'static str {
+        "today"
+    }
+
+    fn single_unit(&self, unit: TimeUnit, tense: Tense) -> &'static str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Second) => "a second ago",
+            (Tense::Past, TimeUnit::Minute) => "a minute ago",
+            (Tense::Past, TimeUnit::Hour) => "an hour ago",
+            (Tense::Past, TimeUnit::Day) => "a day ago",
+            (Tense::Past, TimeUnit::Week) => "a week ago",
+            (Tense::Past, TimeUnit::Month) => "a month ago",
+            (Tense::Past, TimeUnit::Year) => "a year ago",
+            (Tense::Future, TimeUnit::Second) => "in a second",
+            (Tense::Future, TimeUnit::Minute) => "in a minute",
+            (Tense::Future, TimeUnit::Hour) => "in an hour",
+            (Tense::Future, TimeUnit::Day) => "in a day",
+            (Tense::Future, TimeUnit::Week) => "in a week",
+            (Tense::Future, TimeUnit::Month) => "in a month",
+            (Tense::Future, TimeUnit::Year) => "in a year",
+        }
+    }
+
+    fn weekday(&self, weekday: Weekday, tense: Tense) -> &'static str {
+        match (tense, weekday) {
+            (Tense::Past, Weekday::Mon) => "last Monday",
+            (Tense::Past, Weekday::Tue) => "last Tuesday",
+            (Tense::Past, Weekday::Wed) => "last Wednesday",
+            (Tense::Past, Weekday::Thu) => "last Thursday",
+            (Tense::Past, Weekday::Fri) => "last Friday",
+            (Tense::Past, Weekday::Sat) => "last Saturday",
+            (Tense::Past, Weekday::Sun) => "last Sunday",
+            (Tense::Future, Weekday::Mon) => "next Monday",
+            (Tense::Future, Weekday::Tue) => "next Tuesday",
+            (Tense::Future, Weekday::Wed) => "next Wednesday",
+            (Tense::Future, Weekday::Thu) => "next Thursday",
+            (Tense::Future, Weekday::Fri) => "next Friday",
+            (Tense::Future, Weekday::Sat) => "next Saturday",
+            (Tense::Future, Weekday::Sun) => "next Sunday",
+        }
+    }
+
+    fn adjacent(&self, unit: TimeUnit, tense: Tense) -> &str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Week) => "last week",
+            (Tense::Past, TimeUnit::Month) => "last month",
+            (Tense::Past, TimeUnit::Year) => "last year",
+            (Tense::Future, TimeUnit::Week) => "next week",
+            (Tense::Future, TimeUnit::Month) => "next month",
+            (Tense::Future, TimeUnit::Year) => "next year",
+            _ => self.single_unit(unit, tense),
+        }
+    }
+
+    fn units(&self, unit: TimeUnit, tense: Tense, category: PluralCategory) -> &'static str {
+        let [one, other] = match (tense, unit) {
+            (Tense::Past, TimeUnit::Second) => ["{0} second ago", "{0} seconds ago"],
+            (Tense::Past, TimeUnit::Minute) => ["{0} minute ago", "{0} minutes ago"],
+            (Tense::Past, TimeUnit::Hour) => ["{0} hour ago", "{0} hours ago"],
+            (Tense::Past, TimeUnit::Day) => ["{0} day ago", "{0} days ago"],
+            (Tense::Past, TimeUnit::Week) => ["{0} week ago", "{0} weeks ago"],
+            (Tense::Past, TimeUnit::Month) => ["{0} month ago", "{0} months ago"],
+            (Tense::Past, TimeUnit::Year) => ["{0} year ago", "{0} years ago"],
+            (Tense::Future, TimeUnit::Second) => ["in {0} second", "in {0} seconds"],
+            (Tense::Future, TimeUnit::Minute) => ["in {0} minute", "in {0} minutes"],
+            (Tense::Future, TimeUnit::Hour) => ["in {0} hour", "in {0} hours"],
+            (Tense::Future, TimeUnit::Day) => ["in {0} day", "in {0} days"],
+            (Tense::Future, TimeUnit::Week) => ["in {0} week", "in {0} weeks"],
+            (Tense::Future, TimeUnit::Month) => ["in {0} month", "in {0} months"],
+            (Tense::Future, TimeUnit::Year) => ["in {0} year", "in {0} years"],
+        };
+        match category {
+            PluralCategory::One => one,
+            _ => other,
+        }
+    }
+
+    fn relative(&self, tense: Tense) -> &'static str {
+        match tense {
+            Tense::Past => "{0} ago",
+            Tense::Future => "in {0}",
+        }
+    }
+
+    fn list_separators(&self) -> [&'static str; 2] {
+        [", ", " and "]
+    }
+
+    fn compact(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "{0}s",
+            TimeUnit::Minute => "{0}m",
+            TimeUnit::Hour => "{0}h",
+            TimeUnit::Day => "{0}d",
+            TimeUnit::Week => "{0}w",
+            TimeUnit::Month => "{0}mo",
+            TimeUnit::Year => "{0}y",
+        }
+    }
+
+    fn short(&self, unit: TimeUnit, category: PluralCategory) -> &'static str {
+        match (unit, category) {
+            (TimeUnit::Second, _) => "{0} sec.",
+            (TimeUnit::Minute, _) => "{0} min.",
+            (TimeUnit::Hour, _) => "{0} hr.",
+            (TimeUnit::Day, PluralCategory::One) => "{0} day",
+            (TimeUnit::Day, _) => "{0} days",
+            (TimeUnit::Week, _) => "{0} wk.",
+            (TimeUnit::Month, _) => "{0} mo.",
+            (TimeUnit::Year, _) => "{0} yr.",
+        }
+    }
+
+    fn few_seconds(&self) -> &'static str {
+        "a few seconds"
+    }
+
+    fn about_one(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "about a second",
+            TimeUnit::Minute => "about a minute",
+            TimeUnit::Hour => "about an hour",
+            TimeUnit::Day => "about a day",
+            TimeUnit::Week => "about a week",
+            TimeUnit::Month => "about a month",
+            TimeUnit::Year => "about a year",
+        }
+    }
+
+    fn duration_units(&self, unit: TimeUnit, category: PluralCategory) -> &'static str {
+        let [one, other] = match unit {
+            TimeUnit::Second => ["{0} second", "{0} seconds"],
+            TimeUnit::Minute => ["{0} minute", "{0} minutes"],
+            TimeUnit::Hour => ["{0} hour", "{0} hours"],
+            TimeUnit::Day => ["{0} day", "{0} days"],
+            TimeUnit::Week => ["{0} week", "{0} weeks"],
+            TimeUnit::Month => ["{0} month", "{0} months"],
+            TimeUnit::Year => ["{0} year", "{0} years"],
+        };
+        match category {
+            PluralCategory::One => one,
+            _ => other,
+        }
+    }
+
+    fn month_name(&self, month: Month, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long => [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            Style::Short => [
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ],
+            Style::Narrow => ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+        };
+        names[month.number_from_month() as usize - 1]
+    }
+
+    fn weekday_name(&self, weekday: Weekday, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long => [
+                "Sunday",
+                "Monday",
+                "Tuesday",
+                "Wednesday",
+                "Thursday",
+                "Friday",
+                "Saturday",
+            ],
+            Style::Short => ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+            Style::Narrow => ["S", "M", "T", "W", "T", "F", "S"],
+        };
+        names[weekday.num_days_from_sunday() as usize]
+    }
+
+    fn weekday_short(&self, weekday: Weekday) -> &'static str {
+        ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"][weekday.num_days_from_sunday() as usize]
+    }
+
+    fn day_period(&self, pm: bool, style: Style) -> &'static str {
+        match (style, pm) {
+            (Style::Narrow, false) => "a",
+            (Style::Narrow, true) => "p",
+            (_, false) => "AM",
+            (_, true) => "PM",
+        }
+    }
+
+    fn long_date_pattern(&self) -> &'static str {
+        "MMMM d, y"
+    }
+
+    fn short_date_pattern(&self) -> &'static str {
+        "MMM d, y"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::Style;
+    use crate::locale::tests::{
+        humanize_buckets, humanize_duration_buckets, humanize_styled_buckets,
+        humanize_styled_duration_buckets, month_dates, weekday_names,
+    };
+
+    #[test]
+    fn test_plural_categories() {
+        assert_eq!(English.plural_category(0), PluralCategory::Other);
+        assert_eq!(English.plural_category(1), PluralCategory::One);
+        assert_eq!(English.plural_category(2), PluralCategory::Other);
+        assert_eq!(English.plural_category(21), PluralCategory::Other);
+    }
+
+    #[test]
+    fn test_every_bucket() {
+        let (past, future) = humanize_buckets(&English);
+        assert_eq!(
+            past,
+            [
+                "just now",
+                "a minute ago",
+                "1 minute ago",
+                "2 minutes ago",
+                "3 minutes ago",
+                "5 minutes ago",
+                "11 minutes ago",
+                "21 minutes ago",
+                "22 minutes ago",
+                "an hour ago",
+                "1 hour ago",
+                "2 hours ago",
+                "3 hours ago",
+                "5 hours ago",
+                "21 hours ago",
+                "yesterday",
+                "1 day ago",
+                "2 days ago",
+                "3 days ago",
+                "5 days ago",
+                "11 days ago",
+                "22 days ago",
+                "a month ago",
+                "1 month ago",
+                "2 months ago",
+                "5 months ago",
+                "a year ago",
+                "1 year ago",
+                "2 years ago",
+                "3 years ago",
+                "5 years ago",
+                "11 years ago",
+                "21 years ago",
+                "22 years ago",
+                "100 years ago",
+                "102 years ago",
+            ]
+        );
+        assert_eq!(
+            future,
+            [
+                "just now",
+                "in a minute",
+                "in 1 minute",
+                "in 2 minutes",
+                "in 3 minutes",
+                "in 5 minutes",
+                "in 11 minutes",
+                "in 21 minutes",
+                "in 22 minutes",
+                "in an hour",
+                "in 1 hour",
+                "in 2 hours",
+                "in 3 hours",
+                "in 5 hours",
+                "in 21 hours",
+                "tomorrow",
+                "in 1 day",
+                "in 2 days",
+                "in 3 days",
+                "in 5 days",
+                "in 11 days",
+                "in 22 days",
+                "in a month",
+                "in 1 month",
+                "in 2 months",
+                "in 5 months",
+                "in a year",
+                "in 1 year",
+                "in 2 years",
+                "in 3 years",
+                "in 5 years",
+                "in 11 years",
+                "in 21 years",
+                "in 22 years",
+                "in 100 years",
+                "in 102 years",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_duration_bucket() {
+        assert_eq!(
+            humanize_duration_buckets(&English),
+            [
+                "a few seconds",
+                "about a minute",
+                "1 minute",
+                "2 minutes",
+                "3 minutes",
+                "5 minutes",
+                "11 minutes",
+                "21 minutes",
+                "22 minutes",
+                "about an hour",
+                "1 hour",
+                "2 hours",
+                "3 hours",
+                "5 hours",
+                "21 hours",
+                "about a day",
+                "1 day",
+                "2 days",
+                "3 days",
+                "5 days",
+                "11 days",
+                "22 days",
+                "about a month",
+                "1 month",
+                "2 months",
+                "5 months",
+                "about a year",
+                "1 year",
+                "2 years",
+                "3 years",
+                "5 years",
+                "11 years",
+                "21 years",
+                "22 years",
+                "100 years",
+                "102 years",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_bucket_short() {
+        let (past, future) = humanize_styled_buckets(&English, Style::Short);
+        assert_eq!(
+            past,
+            [
+                "just now",
+                "1 min. ago",
+                "1 min. ago",
+                "2 min. ago",
+                "3 min. ago",
+                "5 min. ago",
+                "11 min. ago",
+                "21 min. ago",
+                "22 min. ago",
+                "1 hr. ago",
+                "1 hr. ago",
+                "2 hr. ago",
+                "3 hr. ago",
+                "5 hr. ago",
+                "21 hr. ago",
+                "yesterday",
+                "1 day ago",
+                "2 days ago",
+                "3 days ago",
+                "5 days ago",
+                "11 days ago",
+                "22 days ago",
+                "1 mo. ago",
+                "1 mo. ago",
+                "2 mo. ago",
+                "5 mo. ago",
+                "1 yr. ago",
+                "1 yr. ago",
+                "2 yr. ago",
+                "3 yr. ago",
+                "5 yr. ago",
+                "11 yr. ago",
+                "21 yr. ago",
+                "22 yr. ago",
+                "100 yr. ago",
+                "102 yr. ago",
+            ]
+        );
+        assert_eq!(
+            future,
+            [
+                "just now",
+                "in 1 min.",
+                "in 1 min.",
+                "in 2 min.",
+                "in 3 min.",
+                "in 5 min.",
+                "in 11 min.",
+                "in 21 min.",
+                "in 22 min.",
+                "in 1 hr.",
+                "in 1 hr.",
+                "in 2 hr.",
+                "in 3 hr.",
+                "in 5 hr.",
+                "in 21 hr.",
+                "tomorrow",
+                "in 1 day",
+                "in 2 days",
+                "in 3 days",
+                "in 5 days",
+                "in 11 days",
+                "in 22 days",
+                "in 1 mo.",
+                "in 1 mo.",
+                "in 2 mo.",
+                "in 5 mo.",
+                "in 1 yr.",
+                "in 1 yr.",
+                "in 2 yr.",
+                "in 3 yr.",
+                "in 5 yr.",
+                "in 11 yr.",
+                "in 21 yr.",
+                "in 22 yr.",
+                "in 100 yr.",
+                "in 102 yr.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_duration_bucket_short() {
+        assert_eq!(
+            humanize_styled_duration_buckets(&English, Style::Short),
+            [
+                "a few seconds",
+                "1 min.",
+                "1 min.",
+                "2 min.",
+                "3 min.",
+                "5 min.",
+                "11 min.",
+                "21 min.",
+                "22 min.",
+                "1 hr.",
+                "1 hr.",
+                "2 hr.",
+                "3 hr.",
+                "5 hr.",
+                "21 hr.",
+                "1 day",
+                "1 day",
+                "2 days",
+                "3 days",
+                "5 days",
+                "11 days",
+                "22 days",
+                "1 mo.",
+                "1 mo.",
+                "2 mo.",
+                "5 mo.",
+                "1 yr.",
+                "1 yr.",
+                "2 yr.",
+                "3 yr.",
+                "5 yr.",
+                "11 yr.",
+                "21 yr.",
+                "22 yr.",
+                "100 yr.",
+                "102 yr.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_bucket_narrow() {
+        let (past, future) = humanize_styled_buckets(&English, Style::Narrow);
+        assert_eq!(
+            past,
+            [
+                "just now",
+                "1m ago",
+                "1m ago",
+                "2m ago",
+                "3m ago",
+                "5m ago",
+                "11m ago",
+                "21m ago",
+                "22m ago",
+                "1h ago",
+                "1h ago",
+                "2h ago",
+                "3h ago",
+                "5h ago",
+                "21h ago",
+                "yesterday",
+                "1d ago",
+                "2d ago",
+                "3d ago",
+                "5d ago",
+                "11d ago",
+                "22d ago",
+                "1mo ago",
+                "1mo ago",
+                "2mo ago",
+                "5mo ago",
+                "1y ago",
+                "1y ago",
+                "2y ago",
+                "3y ago",
+                "5y ago",
+                "11y ago",
+                "21y ago",
+                "22y ago",
+                "100y ago",
+                "102y ago",
+            ]
+        );
+        assert_eq!(
+            future,
+            [
+                "just now", "in 1m", "in 1m", "in 2m", "in 3m", "in 5m", "in 11m", "in 21m",
+                "in 22m", "in 1h", "in 1h", "in 2h", "in 3h", "in 5h", "in 21h", "tomorrow",
+                "in 1d", "in 2d", "in 3d", "in 5d", "in 11d", "in 22d", "in 1mo", "in 1mo",
+                "in 2mo", "in 5mo", "in 1y", "in 1y", "in 2y", "in 3y", "in 5y", "in 11y",
+                "in 21y", "in 22y", "in 100y", "in 102y",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_duration_bucket_narrow() {
+        assert_eq!(
+            humanize_styled_duration_buckets(&English, Style::Narrow),
+            [
+                "a few seconds",
+                "1m",
+                "1m",
+                "2m",
+                "3m",
+                "5m",
+                "11m",
+                "21m",
+                "22m",
+                "1h",
+                "1h",
+                "2h",
+                "3h",
+                "5h",
+                "21h",
+                "1d",
+                "1d",
+                "2d",
+                "3d",
+                "5d",
+                "11d",
+                "22d",
+                "1mo",
+                "1mo",
+                "2mo",
+                "5mo",
+                "1y",
+                "1y",
+                "2y",
+                "3y",
+                "5y",
+                "11y",
+                "21y",
+                "22y",
+                "100y",
+                "102y",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unused_units() {
+        assert_eq!(
+            English.single_unit(TimeUnit::Week, Tense::Past),
+            "a week ago"
+        );
+        assert_eq!(
+            English.units(TimeUnit::Second, Tense::Future, PluralCategory::Other),
+            "in {0} seconds"
+        );
+    }
+
+    #[test]
+    fn test_calendar_phrases() {
+        assert_eq!(English.today(), "today");
+        let days = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        assert_eq!(
+            days.map(|day| English.weekday(day, Tense::Past)),
+            [
+                "last Monday",
+                "last Tuesday",
+                "last Wednesday",
+                "last Thursday",
+                "last Friday",
+                "last Saturday",
+                "last Sunday",
+            ]
+        );
+        assert_eq!(
+            days.map(|day| English.weekday(day, Tense::Future)),
+            [
+                "next Monday",
+                "next Tuesday",
+                "next Wednesday",
+                "next Thursday",
+                "next Friday",
+                "next Saturday",
+                "next Sunday",
+            ]
+        );
+        let units = [TimeUnit::Week, TimeUnit::Month, TimeUnit::Year];
+        assert_eq!(
+            units.map(|unit| English.adjacent(unit, Tense::Past)),
+            ["last week", "last month", "last year"]
+        );
+        assert_eq!(
+            units.map(|unit| English.adjacent(unit, Tense::Future)),
+            ["next week", "next month", "next year"]
+        );
+    }
+
+    #[test]
+    fn test_every_month_date() {
+        let (long, short) = month_dates(&English);
+        assert_eq!(
+            long,
+            [
+                "January 22, 2026",
+                "February 22, 2026",
+                "March 22, 2026",
+                "April 22, 2026",
+                "May 22, 2026",
+                "June 22, 2026",
+                "July 22, 2026",
+                "August 22, 2026",
+                "September 22, 2026",
+                "October 22, 2026",
+                "November 22, 2026",
+                "December 22, 2026",
+            ]
+        );
+        assert_eq!(
+            short,
+            [
+                "Jan 22, 2026",
+                "Feb 22, 2026",
+                "Mar 22, 2026",
+                "Apr 22, 2026",
+                "May 22, 2026",
+                "Jun 22, 2026",
+                "Jul 22, 2026",
+                "Aug 22, 2026",
+                "Sep 22, 2026",
+                "Oct 22, 2026",
+                "Nov 22, 2026",
+                "Dec 22, 2026",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekday_names() {
+        assert_eq!(
+            weekday_names(&English),
+            [
+                "Sunday|Sun|S|Su",
+                "Monday|Mon|M|Mo",
+                "Tuesday|Tue|T|Tu",
+                "Wednesday|Wed|W|We",
+                "Thursday|Thu|T|Th",
+                "Friday|Fri|F|Fr",
+                "Saturday|Sat|S|Sa",
+            ]
+        );
+    }
+}
@@ -0,0 +1,472 @@
+use chrono::{Month, Weekday};
+
+use super::{Locale, PluralCategory, Style, Tense, TimeUnit};
+
+/// French (`fr`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct French;
+
+impl Locale for French {
+    fn plural_category(&self, n: u64) -> PluralCategory {
+        if n <= 1 {
+            PluralCategory::One
+        } else if n.is_multiple_of(1_000_000) {
+            PluralCategory::Many
+        } else {
+            PluralCategory::Other
+        }
+    }
+
+    fn just_now(&self) -> &'static str {
+        "à l’instant"
+    }
+
+    fn yesterday(&self) -> &'static str {
+        "hier"
+    }
+
+    fn tomorrow(&self) -> &'static str {
+        "demain"
+    }
+
+    fn today(&self) -> &'static str {
+        "aujourd’hui"
+    }
+
+    fn single_unit(&self, unit: TimeUnit, tense: Tense) -> &'static str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Second) => "il y a une seconde",
+            (Tense::Past, TimeUnit::Minute) => "il y a une minute",
+            (Tense::Past, TimeUnit::Hour) => "il y a une heure",
+            (Tense::Past, TimeUnit::Day) => "il y a un jour",
+            (Tense::Past, TimeUnit::Week) => "il y a une semaine",
+            (Tense::Past, TimeUnit::Month) => "il y a un mois",
+            (Tense::Past, TimeUnit::Year) => "il y a un an",
+            (Tense::Future, TimeUnit::Second) => "dans une seconde",
+            (Tense::Future, TimeUnit::Minute) => "dans une minute",
+            (Tense::Future, TimeUnit::Hour) => "dans une heure",
+            (Tense::Future, TimeUnit::Day) => "dans un jour",
+            (Tense::Future, TimeUnit::Week) => "dans une semaine",
+            (Tense::Future, TimeUnit::Month) => "dans un mois",
+            (Tense::Future, TimeUnit::Year) => "dans un an",
+        }
+    }
+
+    fn weekday(&self, weekday: Weekday, tense: Tense) -> &'static str {
+        match (tense, weekday) {
+            (Tense::Past, Weekday::Mon) => "lundi dernier",
+            (Tense::Past, Weekday::Tue) => "mardi dernier",
+            (Tense::Past, Weekday::Wed) => "mercredi dernier",
+            (Tense::Past, Weekday::Thu) => "jeudi dernier",
+            (Tense::Past, Weekday::Fri) => "vendredi dernier",
+            (Tense::Past, Weekday::Sat) => "samedi dernier",
+            (Tense::Past, Weekday::Sun) => "dimanche dernier",
+            (Tense::Future, Weekday::Mon) => "lundi prochain",
+            (Tense::Future, Weekday::Tue) => "mardi prochain",
+            (Tense::Future, Weekday::Wed) => "mercredi prochain",
+            (Tense::Future, Weekday::Thu) => "jeudi prochain",
+            (Tense::Future, Weekday::Fri) => "vendredi prochain",
+            (Tense::Future, Weekday::Sat) => "samedi prochain",
+            (Tense::Future, Weekday::Sun) => "dimanche prochain",
+        }
+    }
+
+    fn adjacent(&self, unit: TimeUnit, tense: Tense) -> &str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Week) => "la semaine dernière",
+            (Tense::Past, TimeUnit::Month) => "le mois dernier",
+            (Tense::Past, TimeUnit::Year) => "l’année dernière",
+            (Tense::Future, TimeUnit::Week) => "la semaine prochaine",
+            (Tense::Future, TimeUnit::Month) => "le mois prochain",
+            (Tense::Future, TimeUnit::Year) => "l’année prochaine",
+            _ => self.single_unit(unit, tense),
+        }
+    }
+
+    fn units(&self, unit: TimeUnit, tense: Tense, category: PluralCategory) -> &'static str {
+        // `many` (exact millions) takes the same form as `other`.
+        let [one, other] = match (tense, unit) {
+            (Tense::Past, TimeUnit::Second) => ["il y a {0} seconde", "il y a {0} secondes"],
+            (Tense::Past, TimeUnit::Minute) => ["il y a {0} minute", "il y a {0} minutes"],
+            (Tense::Past, TimeUnit::Hour) => ["il y a {0} heure", "il y a {0} heures"],
+            (Tense::Past, TimeUnit::Day) => ["il y a {0} jour", "il y a {0} jours"],
+            (Tense::Past, TimeUnit::Week) => ["il y a {0} semaine", "il y a {0} semaines"],
+            (Tense::Past, TimeUnit::Month) => ["il y a {0} mois", "il y a {0} mois"],
+            (Tense::Past, TimeUnit::Year) => ["il y a {0} an", "il y a {0} ans"],
+            (Tense::Future, TimeUnit::Second) => ["dans {0} seconde", "dans {0} secondes"],
+            (Tense::Future, TimeUnit::Minute) => ["dans {0} minute", "dans {0} minutes"],
+            (Tense::Future, TimeUnit::Hour) => ["dans {0} heure", "dans {0} heures"],
+            (Tense::Future, TimeUnit::Day) => ["dans {0} jour", "dans {0} jours"],
+            (Tense::Future, TimeUnit::Week) => ["dans {0} semaine", "dans {0} semaines"],
+            (Tense::Future, TimeUnit::Month) => ["dans {0} mois", "dans {0} mois"],
+            (Tense::Future, TimeUnit::Year) => ["dans {0} an", "dans {0} ans"],
+        };
+        match category {
+            PluralCategory::One => one,
+            _ => other,
+        }
+    }
+
+    fn relative(&self, tense: Tense) -> &'static str {
+        match tense {
+            Tense::Past => "il y a {0}",
+            Tense::Future => "dans {0}",
+        }
+    }
+
+    fn list_separators(&self) -> [&'static str; 2] {
+        [", ", " et "]
+    }
+
+    fn compact(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "{0} s",
+            TimeUnit::Minute => "{0} min",
+            TimeUnit::Hour => "{0} h",
+            TimeUnit::Day => "{0} j",
+            TimeUnit::Week => "{0} sem.",
+            TimeUnit::Month => "{0} m.",
+            TimeUnit::Year => "{0} a",
+        }
+    }
+
+    fn few_seconds(&self) -> &'static str {
+        "quelques secondes"
+    }
+
+    fn about_one(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "environ une seconde",
+            TimeUnit::Minute => "environ une minute",
+            TimeUnit::Hour => "environ une heure",
+            TimeUnit::Day => "environ un jour",
+            TimeUnit::Week => "environ une semaine",
+            TimeUnit::Month => "environ un mois",
+            TimeUnit::Year => "environ un an",
+        }
+    }
+
+    fn duration_units(&self, unit: TimeUnit, category: PluralCategory) -> &'static str {
+        let [one, other] = match unit {
+            TimeUnit::Second => ["{0} seconde", "{0} secondes"],
+            TimeUnit::Minute => ["{0} minute", "{0} minutes"],
+            TimeUnit::Hour => ["{0} heure", "{0} heures"],
+            TimeUnit::Day => ["{0} jour", "{0} jours"],
+            TimeUnit::Week => ["{0} semaine", "{0} semaines"],
+            TimeUnit::Month => ["{0} mois", "{0} mois"],
+            TimeUnit::Year => ["{0} an", "{0} ans"],
+        };
+        match category {
+            PluralCategory::One => one,
+            _ => other,
+        }
+    }
+
+    fn month_name(&self, month: Month, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long => [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            Style::Short => [
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ],
+            Style::Narrow => ["J", "F", "M", "A", "M", "J", "J", "A", "S", "O", "N", "D"],
+        };
+        names[month.number_from_month() as usize - 1]
+    }
+
+    fn weekday_name(&self, weekday: Weekday, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long => [
+                "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+            ],
+            Style::Short => ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+            Style::Narrow => ["D", "L", "M", "M", "J", "V", "S"],
+        };
+        names[weekday.num_days_from_sunday() as usize]
+    }
+
+    fn weekday_short(&self, weekday: Weekday) -> &'static str {
+        ["di", "lu", "ma", "me", "je", "ve", "sa"][weekday.num_days_from_sunday() as usize]
+    }
+
+    fn day_period(&self, pm: bool, _: Style) -> &'static str {
+        if pm { "PM" } else { "AM" }
+    }
+
+    fn long_date_pattern(&self) -> &'static str {
+        "d MMMM y"
+    }
+
+    fn short_date_pattern(&self) -> &'static str {
+        "d MMM y"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::tests::{
+        humanize_buckets, humanize_duration_buckets, month_dates, weekday_names,
+    };
+
+    #[test]
+    fn test_plural_categories() {
+        assert_eq!(French.plural_category(0), PluralCategory::One);
+        assert_eq!(French.plural_category(1), PluralCategory::One);
+        assert_eq!(French.plural_category(2), PluralCategory::Other);
+        assert_eq!(French.plural_category(1_000_000), PluralCategory::Many);
+        assert_eq!(French.plural_category(2_000_000), PluralCategory::Many);
+        assert_eq!(French.plural_category(1_000_001), PluralCategory::Other);
+    }
+
+    #[test]
+    fn test_every_bucket() {
+        let (past, future) = humanize_buckets(&French);
+        assert_eq!(
+            past,
+            [
+                "à l’instant",
+                "il y a une minute",
+                "il y a 1 minute",
+                "il y a 2 minutes",
+                "il y a 3 minutes",
+                "il y a 5 minutes",
+                "il y a 11 minutes",
+                "il y a 21 minutes",
+                "il y a 22 minutes",
+                "il y a une heure",
+                "il y a 1 heure",
+                "il y a 2 heures",
+                "il y a 3 heures",
+                "il y a 5 heures",
+                "il y a 21 heures",
+                "hier",
+                "il y a 1 jour",
+                "il y a 2 jours",
+                "il y a 3 jours",
+                "il y a 5 jours",
+                "il y a 11 jours",
+                "il y a 22 jours",
+                "il y a un mois",
+                "il y a 1 mois",
+                "il y a 2 mois",
+                "il y a 5 mois",
+                "il y a un an",
+                "il y a 1 an",
+                "il y a 2 ans",
+                "il y a 3 ans",
+                "il y a 5 ans",
+                "il y a 11 ans",
+                "il y a 21 ans",
+                "il y a 22 ans",
+                "il y a 100 ans",
+                "il y a 102 ans",
+            ]
+        );
+        assert_eq!(
+            future,
+            [
+                "à l’instant",
+                "dans une minute",
+                "dans 1 minute",
+                "dans 2 minutes",
+                "dans 3 minutes",
+                "dans 5 minutes",
+                "dans 11 minutes",
+                "dans 21 minutes",
+                "dans 22 minutes",
+                "dans une heure",
+                "dans 1 heure",
+                "dans 2 heures",
+                "dans 3 heures",
+                "dans 5 heures",
+                "dans 21 heures",
+                "demain",
+                "dans 1 jour",
+                "dans 2 jours",
+                "dans 3 jours",
+                "dans 5 jours",
+                "dans 11 jours",
+                "dans 22 jours",
+                "dans un mois",
+                "dans 1 mois",
+                "dans 2 mois",
+                "dans 5 mois",
+                "dans un an",
+                "dans 1 an",
+                "dans 2 ans",
+                "dans 3 ans",
+                "dans 5 ans",
+                "dans 11 ans",
+                "dans 21 ans",
+                "dans 22 ans",
+                "dans 100 ans",
+                "dans 102 ans",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_duration_bucket() {
+        assert_eq!(
+            humanize_duration_buckets(&French),
+            [
+                "quelques secondes",
+                "environ une minute",
+                "1 minute",
+                "2 minutes",
+                "3 minutes",
+                "5 minutes",
+                "11 minutes",
+                "21 minutes",
+                "22 minutes",
+                "environ une heure",
+                "1 heure",
+                "2 heures",
+                "3 heures",
+                "5 heures",
+                "21 heures",
+                "environ un jour",
+                "1 jour",
+                "2 jours",
+                "3 jours",
+                "5 jours",
+                "11 jours",
+                "22 jours",
+                "environ un mois",
+                "1 mois",
+                "2 mois",
+                "5 mois",
+                "environ un an",
+                "1 an",
+                "2 ans",
+                "3 ans",
+                "5 ans",
+                "11 ans",
+                "21 ans",
+                "22 ans",
+                "100 ans",
+                "102 ans",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calendar_phrases() {
+        assert_eq!(French.today(), "aujourd’hui");
+        let days = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        assert_eq!(
+            days.map(|day| French.weekday(day, Tense::Past)),
+            [
+                "lundi dernier",
+                "mardi dernier",
+                "mercredi dernier",
+                "jeudi dernier",
+                "vendredi dernier",
+                "samedi dernier",
+                "dimanche dernier",
+            ]
+        );
+        assert_eq!(
+            days.map(|day| French.weekday(day, Tense::Future)),
+            [
+                "lundi prochain",
+                "mardi prochain",
+                "mercredi prochain",
+                "jeudi prochain",
+                "vendredi prochain",
+                "samedi prochain",
+                "dimanche prochain",
+            ]
+        );
+        let units = [TimeUnit::Week, TimeUnit::Month, TimeUnit::Year];
+        assert_eq!(
+            units.map(|unit| French.adjacent(unit, Tense::Past)),
+            ["la semaine dernière", "le mois dernier", "l’année dernière"]
+        );
+        assert_eq!(
+            units.map(|unit| French.adjacent(unit, Tense::Future)),
+            [
+                "la semaine prochaine",
+                "le mois prochain",
+                "l’année prochaine"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_month_date() {
+        let (long, short) = month_dates(&French);
+        assert_eq!(
+            long,
+            [
+                "22 janvier 2026",
+                "22 février 2026",
+                "22 mars 2026",
+                "22 avril 2026",
+                "22 mai 2026",
+                "22 juin 2026",
+                "22 juillet 2026",
+                "22 août 2026",
+                "22 septembre 2026",
+                "22 octobre 2026",
+                "22 novembre 2026",
+                "22 décembre 2026",
+            ]
+        );
+        assert_eq!(
+            short,
+            [
+                "22 janv. 2026",
+                "22 févr. 2026",
+                "22 mars 2026",
+                "22 avr. 2026",
+                "22 mai 2026",
+                "22 juin 2026",
+                "22 juil. 2026",
+                "22 août 2026",
+                "22 sept. 2026",
+                "22 oct. 2026",
+                "22 nov. 2026",
+                "22 déc. 2026",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekday_names() {
+        assert_eq!(
+            weekday_names(&French),
+            [
+                "dimanche|dim.|D|di",
+                "lundi|lun.|L|lu",
+                "mardi|mar.|M|ma",
+                "mercredi|mer.|M|me",
+                "jeudi|jeu.|J|je",
+                "vendredi|ven.|V|ve",
+                "samedi|sam.|S|sa",
+            ]
+        );
+    }
+}
@@ -0,0 +1,440 @@
+use chrono::{Month, Weekday};
+
+use super::{Locale, PluralCategory, Style, Tense, TimeUnit};
+
+/// Japanese (`ja`). Japanese does not inflect for number, so every count is
+/// [`PluralCategory::Other`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Japanese;
+
+impl Locale for Japanese {
+    fn plural_category(&self, _: u64) -> PluralCategory {
+        PluralCategory::Other
+    }
+
+    fn just_now(&self) -> &'static str {
+        "たった今"
+    }
+
+    fn yesterday(&self) -> &'static str {
+        "昨日"
+    }
+
+    fn tomorrow(&self) -> &'static str {
+        "明日"
+    }
+
+    fn today(&self) -> &'static str {
+        "今日"
+    }
+
+    fn single_unit(&self, unit: TimeUnit, tense: Tense) -> &'static str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Second) => "1 秒前",
+            (Tense::Past, TimeUnit::Minute) => "1 分前",
+            (Tense::Past, TimeUnit::Hour) => "1 時間前",
+            (Tense::Past, TimeUnit::Day) => "1 日前",
+            (Tense::Past, TimeUnit::Week) => "1 週間前",
+            (Tense::Past, TimeUnit::Month) => "1 か月前",
+            (Tense::Past, TimeUnit::Year) => "1 年前",
+            (Tense::Future, TimeUnit::Second) => "1 秒後",
+            (Tense::Future, TimeUnit::Minute) => "1 分後",
+            (Tense::Future, TimeUnit::Hour) => "1 時間後",
+            (Tense::Future, TimeUnit::Day) => "1 日後",
+            (Tense::Future, TimeUnit::Week) => "1 週間後",
+            (Tense::Future, TimeUnit::Month) => "1 か月後",
+            (Tense::Future, TimeUnit::Year) => "1 年後",
+        }
+    }
+
+    fn weekday(&self, weekday: Weekday, tense: Tense) -> &'static str {
+        match (tense, weekday) {
+            (Tense::Past, Weekday::Mon) => "この前の月曜日",
+            (Tense::Past, Weekday::Tue) => "この前の火曜日",
+            (Tense::Past, Weekday::Wed) => "この前の水曜日",
+            (Tense::Past, Weekday::Thu) => "この前の木曜日",
+            (Tense::Past, Weekday::Fri) => "この前の金曜日",
+            (Tense::Past, Weekday::Sat) => "この前の土曜日",
+            (Tense::Past, Weekday::Sun) => "この前の日曜日",
+            (Tense::Future, Weekday::Mon) => "今度の月曜日",
+            (Tense::Future, Weekday::Tue) => "今度の火曜日",
+            (Tense::Future, Weekday::Wed) => "今度の水曜日",
+            (Tense::Future, Weekday::Thu) => "今度の木曜日",
+            (Tense::Future, Weekday::Fri) => "今度の金曜日",
+            (Tense::Future, Weekday::Sat) => "今度の土曜日",
+            (Tense::Future, Weekday::Sun) => "今度の日曜日",
+        }
+    }
+
+    fn adjacent(&self, unit: TimeUnit, tense: Tense) -> &str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Week) => "先週",
+            (Tense::Past, TimeUnit::Month) => "先月",
+            (Tense::Past, TimeUnit::Year) => "昨年",
+            (Tense::Future, TimeUnit::Week) => "来週",
+            (Tense::Future, TimeUnit::Month) => "来月",
+            (Tense::Future, TimeUnit::Year) => "来年",
+            _ => self.single_unit(unit, tense),
+        }
+    }
+
+    fn units(&self, unit: TimeUnit, tense: Tense, _: PluralCategory) -> &'static str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Second) => "{0} 秒前",
+            (Tense::Past, TimeUnit::Minute) => "{0} 分前",
+            (Tense::Past, TimeUnit::Hour) => "{0} 時間前",
+            (Tense::Past, TimeUnit::Day) => "{0} 日前",
+            (Tense::Past, TimeUnit::Week) => "{0} 週間前",
+            (Tense::Past, TimeUnit::Month) => "{0} か月前",
+            (Tense::Past, TimeUnit::Year) => "{0} 年前",
+            (Tense::Future, TimeUnit::Second) => "{0} 秒後",
+            (Tense::Future, TimeUnit::Minute) => "{0} 分後",
+            (Tense::Future, TimeUnit::Hour) => "{0} 時間後",
+            (Tense::Future, TimeUnit::Day) => "{0} 日後",
+            (Tense::Future, TimeUnit::Week) => "{0} 週間後",
+            (Tense::Future, TimeUnit::Month) => "{0} か月後",
+            (Tense::Future, TimeUnit::Year) => "{0} 年後",
+        }
+    }
+
+    fn relative(&self, tense: Tense) -> &'static str {
+        match tense {
+            Tense::Past => "{0}前",
+            Tense::Future => "{0}後",
+        }
+    }
+
+    fn list_separators(&self) -> [&'static str; 2] {
+        [" ", " "]
+    }
+
+    fn compact(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "{0}秒",
+            TimeUnit::Minute => "{0}分",
+            TimeUnit::Hour => "{0}時間",
+            TimeUnit::Day => "{0}日",
+            TimeUnit::Week => "{0}週間",
+            TimeUnit::Month => "{0}か月",
+            TimeUnit::Year => "{0}年",
+        }
+    }
+
+    fn few_seconds(&self) -> &'static str {
+        "数秒"
+    }
+
+    fn about_one(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "約1秒",
+            TimeUnit::Minute => "約1分",
+            TimeUnit::Hour => "約1時間",
+            TimeUnit::Day => "約1日",
+            TimeUnit::Week => "約1週間",
+            TimeUnit::Month => "約1か月",
+            TimeUnit::Year => "約1年",
+        }
+    }
+
+    fn duration_units(&self, unit: TimeUnit, _: PluralCategory) -> &'static str {
+        match unit {
+            TimeUnit::Second => "{0} 秒",
+            TimeUnit::Minute => "{0} 分",
+            TimeUnit::Hour => "{0} 時間",
+            TimeUnit::Day => "{0} 日",
+            TimeUnit::Week => "{0} 週間",
+            TimeUnit::Month => "{0} か月",
+            TimeUnit::Year => "{0} 年",
+        }
+    }
+
+    fn month_name(&self, month: Month, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long | Style::Short => [
+                "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月",
+                "12月",
+            ],
+            Style::Narrow => [
+                "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12",
+            ],
+        };
+        names[month.number_from_month() as usize - 1]
+    }
+
+    fn weekday_name(&self, weekday: Weekday, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long => [
+                "日曜日",
+                "月曜日",
+                "火曜日",
+                "水曜日",
+                "木曜日",
+                "金曜日",
+                "土曜日",
+            ],
+            Style::Short | Style::Narrow => ["日", "月", "火", "水", "木", "金", "土"],
+        };
+        names[weekday.num_days_from_sunday() as usize]
+    }
+
+    fn day_period(&self, pm: bool, _: Style) -> &'static str {
+        if pm { "午後" } else { "午前" }
+    }
+
+    fn long_date_pattern(&self) -> &'static str {
+        "y年M月d日"
+    }
+
+    fn short_date_pattern(&self) -> &'static str {
+        "y年M月d日"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::tests::{
+        humanize_buckets, humanize_duration_buckets, month_dates, weekday_names,
+    };
+
+    #[test]
+    fn test_plural_categories() {
+        assert_eq!(Japanese.plural_category(0), PluralCategory::Other);
+        assert_eq!(Japanese.plural_category(1), PluralCategory::Other);
+        assert_eq!(Japanese.plural_category(2), PluralCategory::Other);
+    }
+
+    #[test]
+    fn test_every_bucket() {
+        let (past, future) = humanize_buckets(&Japanese);
+        assert_eq!(
+            past,
+            [
+                "たった今",
+                "1 分前",
+                "1 分前",
+                "2 分前",
+                "3 分前",
+                "5 分前",
+                "11 分前",
+                "21 分前",
+                "22 分前",
+                "1 時間前",
+                "1 時間前",
+                "2 時間前",
+                "3 時間前",
+                "5 時間前",
+                "21 時間前",
+                "昨日",
+                "1 日前",
+                "2 日前",
+                "3 日前",
+                "5 日前",
+                "11 日前",
+                "22 日前",
+                "1 か月前",
+                "1 か月前",
+                "2 か月前",
+                "5 か月前",
+                "1 年前",
+                "1 年前",
+                "2 年前",
+                "3 年前",
+                "5 年前",
+                "11 年前",
+                "21 年前",
+                "22 年前",
+                "100 年前",
+                "102 年前",
+            ]
+        );
+        assert_eq!(
+            future,
+            [
+                "たった今",
+                "1 分後",
+                "1 分後",
+                "2 分後",
+                "3 分後",
+                "5 分後",
+                "11 分後",
+                "21 分後",
+                "22 分後",
+                "1 時間後",
+                "1 時間後",
+                "2 時間後",
+                "3 時間後",
+                "5 時間後",
+                "21 時間後",
+                "明日",
+                "1 日後",
+                "2 日後",
+                "3 日後",
+                "5 日後",
+                "11 日後",
+                "22 日後",
+                "1 か月後",
+                "1 か月後",
+                "2 か月後",
+                "5 か月後",
+                "1 年後",
+                "1 年後",
+                "2 年後",
+                "3 年後",
+                "5 年後",
+                "11 年後",
+                "21 年後",
+                "22 年後",
+                "100 年後",
+                "102 年後",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_duration_bucket() {
+        assert_eq!(
+            humanize_duration_buckets(&Japanese),
+            [
+                "数秒",
+                "約1分",
+                "1 分",
+                "2 分",
+                "3 分",
+                "5 分",
+                "11 分",
+                "21 分",
+                "22 分",
+                "約1時間",
+                "1 時間",
+                "2 時間",
+                "3 時間",
+                "5 時間",
+                "21 時間",
+                "約1日",
+                "1 日",
+                "2 日",
+                "3 日",
+                "5 日",
+                "11 日",
+                "22 日",
+                "約1か月",
+                "1 か月",
+                "2 か月",
+                "5 か月",
+                "約1年",
+                "1 年",
+                "2 年",
+                "3 年",
+                "5 年",
+                "11 年",
+                "21 年",
+                "22 年",
+                "100 年",
+                "102 年",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calendar_phrases() {
+        assert_eq!(Japanese.today(), "今日");
+        let days = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        assert_eq!(
+            days.map(|day| Japanese.weekday(day, Tense::Past)),
+            [
+                "この前の月曜日",
+                "この前の火曜日",
+                "この前の水曜日",
+                "この前の木曜日",
+                "この前の金曜日",
+                "この前の土曜日",
+                "この前の日曜日",
+            ]
+        );
+        assert_eq!(
+            days.map(|day| Japanese.weekday(day, Tense::Future)),
+            [
+                "今度の月曜日",
+                "今度の火曜日",
+                "今度の水曜日",
+                "今度の木曜日",
+                "今度の金曜日",
+                "今度の土曜日",
+                "今度の日曜日",
+            ]
+        );
+        let units = [TimeUnit::Week, TimeUnit::Month, TimeUnit::Year];
+        assert_eq!(
+            units.map(|unit| Japanese.adjacent(unit, Tense::Past)),
+            ["先週", "先月", "昨年"]
+        );
+        assert_eq!(
+            units.map(|unit| Japanese.adjacent(unit, Tense::Future)),
+            ["来週", "来月", "来年"]
+        );
+    }
+
+    #[test]
+    fn test_every_month_date() {
+        let (long, short) = month_dates(&Japanese);
+        assert_eq!(
+            long,
+            [
+                "2026年1月22日",
+                "2026年2月22日",
+                "2026年3月22日",
+                "2026年4月22日",
+                "2026年5月22日",
+                "2026年6月22日",
+                "2026年7月22日",
+                "2026年8月22日",
+                "2026年9月22日",
+                "2026年10月22日",
+                "2026年11月22日",
+                "2026年12月22日",
+            ]
+        );
+        assert_eq!(
+            short,
+            [
+                "2026年1月22日",
+                "2026年2月22日",
+                "2026年3月22日",
+                "2026年4月22日",
+                "2026年5月22日",
+                "2026年6月22日",
+                "2026年7月22日",
+                "2026年8月22日",
+                "2026年9月22日",
+                "2026年10月22日",
+                "2026年11月22日",
+                "2026年12月22日",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekday_names() {
+        assert_eq!(
+            weekday_names(&Japanese),
+            [
+                "日曜日|日|日|日",
+                "月曜日|月|月|月",
+                "火曜日|火|火|火",
+                "水曜日|水|水|水",
+                "木曜日|木|木|木",
+                "金曜日|金|金|金",
+                "土曜日|土|土|土",
+            ]
+        );
+    }
+}
@@ -0,0 +1,654 @@
+mod ar;
+mod de;
+mod en;
+mod fr;
+mod ja;
+mod pl;
+mod ru;
+
+use std::fmt;
+
+use chrono::{Month, Weekday};
+
+pub use ar::Arabic;
+pub use de::German;
+pub use en::English;
+pub use fr::French;
+pub use ja::Japanese;
+pub use pl::Polish;
+pub use ru::Russian;
+
+/// A CLDR plural category.
+///
+/// Each language maps integers onto a subset of these: English only uses
+/// `One` and `Other`, Russian and Polish add `Few` and `Many`, Arabic uses all
+/// six and Japanese only `Other`. See the [CLDR plural rules] for the full
+/// tables.
+///
+/// [CLDR plural rules]: https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// A unit of time a phrase can be expressed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl TimeUnit {
+    /// The length of one unit in seconds, with months as 30 days and years
+    /// as 365 days.
+    pub(crate) const fn seconds(self) -> i64 {
+        match self {
+            TimeUnit::Second => 1,
+            TimeUnit::Minute => 60,
+            TimeUnit::Hour => 3_600,
+            TimeUnit::Day => 86_400,
+            TimeUnit::Week => 7 * 86_400,
+            TimeUnit::Month => 30 * 86_400,
+            TimeUnit::Year => 365 * 86_400,
+        }
+    }
+}
+
+/// Whether a phrase points into the past (`"3 days ago"`) or the future
+/// (`"in 3 days"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tense {
+    Past,
+    Future,
+}
+
+/// How long the words of a phrase are, mirroring the styles of
+/// `Intl.RelativeTimeFormat`.
+///
+/// Phrases without a number, such as `"just now"` and `"yesterday"`, read
+/// the same in every style. In dates, the style picks the width of month and
+/// weekday names (`"February"`, `"Feb"`, `"F"`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// Whole words: `"3 minutes ago"`, `"about a minute"`.
+    #[default]
+    Long,
+    /// Abbreviated units: `"3 min. ago"`, `"3 min."`.
+    Short,
+    /// The narrowest units: `"3m ago"`, `"3m"`.
+    Narrow,
+}
+
+/// The words [`humanize_with_locale`](crate::humanize_with_locale) and
+/// [`to_long_date_with_locale`](crate::to_long_date_with_locale) use for one
+/// language.
+///
+/// Patterns contain `{0}` where the number goes, following CLDR's
+/// relative-time data. A pattern may leave `{0}` out when the language
+/// spells the number as part of the word, as Arabic does for one and two.
+///
+/// Built-in locales: [`English`], [`German`], [`French`], [`Polish`],
+/// [`Russian`], [`Arabic`] and [`Japanese`]. Implement this trait to add
+/// another. Methods added after the first release have a default, usually
+/// the [`English`] words, so an existing implementation keeps compiling; a
+/// complete locale overrides them.
+pub trait Locale {
+    /// The plural category of the non-negative integer `n`.
+    fn plural_category(&self, n: u64) -> PluralCategory;
+
+    /// The phrase for a moment within a few seconds of now (`"just now"`).
+    fn just_now(&self) -> &str;
+
+    /// The phrase for roughly one day ago (`"yesterday"`).
+    fn yesterday(&self) -> &str;
+
+    /// The phrase for roughly one day from now (`"tomorrow"`).
+    fn tomorrow(&self) -> &str;
+
+    /// The phrase for the current date, used when humanizing a date without
+    /// a time (`"today"`).
+    ///
+    /// Defaults to the [`English`] phrase.
+    fn today(&self) -> &str {
+        English.today()
+    }
+
+    /// The phrase for a single `unit` without a number, used at the start of
+    /// each bucket (`"a minute ago"`, `"in an hour"`).
+    fn single_unit(&self, unit: TimeUnit, tense: Tense) -> &str;
+
+    /// The phrase for `weekday` within the past or coming six days
+    /// (`"last Tuesday"`, `"next Tuesday"`), used by calendar mode.
+    ///
+    /// Defaults to the [`English`] phrase.
+    fn weekday(&self, weekday: Weekday, tense: Tense) -> &str {
+        English.weekday(weekday, tense)
+    }
+
+    /// The phrase for the previous or next calendar week, month or year
+    /// (`"last week"`, `"next year"`), used by calendar mode. Other units are
+    /// never asked for.
+    ///
+    /// Defaults to the [`English`] phrase.
+    fn adjacent(&self, unit: TimeUnit, tense: Tense) -> &str {
+        English.adjacent(unit, tense)
+    }
+
+    /// The pattern for a counted number of `unit`s in the given plural
+    /// `category` (`"{0} minutes ago"`, `"in {0} minutes"`).
+    fn units(&self, unit: TimeUnit, tense: Tense, category: PluralCategory) -> &str;
+
+    /// The pattern that frames a list of counts as past or future
+    /// (`"{0} ago"`, `"in {0}"`), used by precise humanization.
+    ///
+    /// Defaults to the [`English`] pattern.
+    fn relative(&self, tense: Tense) -> &str {
+        English.relative(tense)
+    }
+
+    /// The pattern for a counted number of `unit`s inside
+    /// [`relative`](Locale::relative) (`"{0} minutes"`).
+    ///
+    /// Defaults to the [`units`](Locale::units) pattern with the `relative`
+    /// framing removed, which gives the right grammatical case for every
+    /// built-in locale. Override it where that does not hold.
+    fn counted(&self, unit: TimeUnit, tense: Tense, category: PluralCategory) -> &str {
+        let pattern = self.units(unit, tense, category);
+        let (prefix, suffix) = self.relative(tense).split_once("{0}").unwrap_or(("", ""));
+        pattern
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_suffix(suffix))
+            .unwrap_or(pattern)
+    }
+
+    /// The separators of a list of counts: between items, and before the
+    /// last one (`[", ", " and "]`).
+    ///
+    /// Defaults to the [`English`] separators.
+    fn list_separators(&self) -> [&str; 2] {
+        English.list_separators()
+    }
+
+    /// The compact pattern for `unit` (`"{0}h"`), also used by
+    /// [`Style::Narrow`].
+    ///
+    /// Defaults to the [`English`] pattern.
+    fn compact(&self, unit: TimeUnit) -> &str {
+        English.compact(unit)
+    }
+
+    /// The abbreviated pattern for a counted number of `unit`s
+    /// (`"{0} min."`), used by [`Style::Short`].
+    ///
+    /// Defaults to [`compact`](Locale::compact), which for most languages is
+    /// already the abbreviated form.
+    fn short(&self, unit: TimeUnit, category: PluralCategory) -> &str {
+        let _ = category;
+        self.compact(unit)
+    }
+
+    /// The phrase for a duration of a few seconds (`"a few seconds"`), used
+    /// by [`humanize_duration`](crate::humanize_duration).
+    ///
+    /// Defaults to the [`English`] phrase.
+    fn few_seconds(&self) -> &str {
+        English.few_seconds()
+    }
+
+    /// The phrase for a duration of roughly one `unit` (`"about a minute"`).
+    ///
+    /// Defaults to the [`English`] phrase.
+    fn about_one(&self, unit: TimeUnit) -> &str {
+        English.about_one(unit)
+    }
+
+    /// The pattern for a duration of a counted number of `unit`s, without
+    /// past or future framing (`"{0} days"`).
+    ///
+    /// Defaults to the [`English`] pattern for `category`.
+    fn duration_units(&self, unit: TimeUnit, category: PluralCategory) -> &str {
+        English.duration_units(unit, category)
+    }
+
+    /// The name of `month` as it appears next to a day number (`"February"`,
+    /// `"Feb"`, `"F"`). Languages that inflect month names use the form a
+    /// date takes, such as the Russian genitive `"февраля"`.
+    ///
+    /// Defaults to the [`English`] name.
+    fn month_name(&self, month: Month, style: Style) -> &str {
+        English.month_name(month, style)
+    }
+
+    /// The name of `weekday` (`"Sunday"`, `"Sun"`, `"S"`).
+    ///
+    /// Defaults to the [`English`] name.
+    fn weekday_name(&self, weekday: Weekday, style: Style) -> &str {
+        English.weekday_name(weekday, style)
+    }
+
+    /// The shortest weekday name that still tells every day apart (`"Su"`),
+    /// used by the `EEEEEE` pattern field.
+    ///
+    /// Defaults to the [`Style::Short`] name from
+    /// [`weekday_name`](Locale::weekday_name).
+    fn weekday_short(&self, weekday: Weekday) -> &str {
+        self.weekday_name(weekday, Style::Short)
+    }
+
+    /// The marker for times before or after noon (`"AM"`, `"PM"`).
+    ///
+    /// Defaults to the [`English`] marker.
+    fn day_period(&self, pm: bool, style: Style) -> &str {
+        English.day_period(pm, style)
+    }
+
+    /// The [`DatePattern`](crate::DatePattern) for a date with the full month
+    /// name (`"MMMM d, y"`). It may only use date fields; a pattern that does
+    /// not compile or has time or zone fields makes
+    /// [`to_long_date_with_locale`](crate::to_long_date_with_locale) return
+    /// an error.
+    ///
+    /// Defaults to the [`English`] pattern.
+    fn long_date_pattern(&self) -> &str {
+        English.long_date_pattern()
+    }
+
+    /// The [`DatePattern`](crate::DatePattern) for a date with the
+    /// abbreviated month name (`"MMM d, y"`), with the same restrictions as
+    /// [`long_date_pattern`](Locale::long_date_pattern).
+    ///
+    /// Defaults to the [`English`] pattern.
+    fn short_date_pattern(&self) -> &str {
+        English.short_date_pattern()
+    }
+
+    /// Writes `n` in this locale's digits. Defaults to ASCII digits.
+    ///
+    /// # Errors
+    /// Returns an error if writing to `out` fails.
+    fn write_number(&self, n: u64, out: &mut dyn fmt::Write) -> fmt::Result {
+        write!(out, "{n}")
+    }
+}
+
+/// Writes the phrase for `n` `unit`s in `style`, filling the locale's
+/// pattern. The short and narrow patterns are framed by
+/// [`Locale::relative`].
+pub(crate) fn write_units(
+    locale: &(impl Locale + ?Sized),
+    n: u64,
+    unit: TimeUnit,
+    tense: Tense,
+    style: Style,
+    out: &mut dyn fmt::Write,
+) -> fmt::Result {
+    let category = locale.plural_category(n);
+    let pattern = match style {
+        Style::Long => return write_pattern(locale, locale.units(unit, tense, category), n, out),
+        Style::Short => locale.short(unit, category),
+        Style::Narrow => locale.compact(unit),
+    };
+    let (prefix, suffix) = locale.relative(tense).split_once("{0}").unwrap_or(("", ""));
+    out.write_str(prefix)?;
+    write_pattern(locale, pattern, n, out)?;
+    out.write_str(suffix)
+}
+
+/// Writes `pattern` with `{0}` replaced by `n` in the locale's digits.
+pub(crate) fn write_pattern(
+    locale: &(impl Locale + ?Sized),
+    pattern: &str,
+    n: u64,
+    out: &mut dyn fmt::Write,
+) -> fmt::Result {
+    match pattern.split_once("{0}") {
+        Some((before, after)) => {
+            out.write_str(before)?;
+            locale.write_number(n, out)?;
+            out.write_str(after)
+        }
+        None => out.write_str(pattern),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Offsets in seconds covering every bucket of the humanize table, with
+    /// counts chosen to hit each plural category of the built-in locales.
+    pub(crate) const BUCKET_OFFSETS: [i64; 36] = [
+        10,                     // just now
+        60,                     // single minute
+        95,                     // 1 minute
+        2 * 60,                 // 2 minutes
+        3 * 60,                 // 3 minutes
+        5 * 60,                 // 5 minutes
+        11 * 60,                // 11 minutes
+        21 * 60,                // 21 minutes
+        22 * 60,                // 22 minutes
+        60 * 60,                // single hour
+        5_401,                  // 1 hour
+        2 * 3_600,              // 2 hours
+        3 * 3_600,              // 3 hours
+        5 * 3_600,              // 5 hours
+        21 * 3_600,             // 21 hours
+        24 * 3_600,             // yesterday / tomorrow
+        37 * 3_600,             // 1 day
+        2 * 86_400,             // 2 days
+        3 * 86_400,             // 3 days
+        5 * 86_400,             // 5 days
+        11 * 86_400,            // 11 days
+        22 * 86_400,            // 22 days
+        30 * 86_400,            // single month
+        46 * 86_400,            // 1 month
+        60 * 86_400,            // 2 months
+        150 * 86_400,           // 5 months
+        13 * 30 * 86_400,       // single year
+        19 * 30 * 86_400,       // 1 year
+        2 * 365 * 86_400,       // 2 years
+        3 * 365 * 86_400,       // 3 years
+        5 * 365 * 86_400,       // 5 years
+        11 * 365 * 86_400,      // 11 years
+        21 * 365 * 86_400,      // 21 years
+        22 * 365 * 86_400,      // 22 years
+        100 * 365 * 86_400 + 1, // 100 years
+        102 * 365 * 86_400 + 1, // 102 years
+    ];
+
+    /// Humanizes each of [`BUCKET_OFFSETS`] in the past and in the future.
+    pub(crate) fn humanize_buckets(locale: &dyn Locale) -> (Vec<String>, Vec<String>) {
+        humanize_styled_buckets(locale, Style::Long)
+    }
+
+    /// Like [`humanize_buckets`], in `style`.
+    pub(crate) fn humanize_styled_buckets(
+        locale: &dyn Locale,
+        style: Style,
+    ) -> (Vec<String>, Vec<String>) {
+        use chrono::{Duration, Local, TimeZone, Utc};
+
+        let noon = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let clock = crate::clock::MockClock::new(&noon);
+        let now = noon.with_timezone(&Local);
+        let render = |offset| {
+            crate::Humanizer::styled(style).humanize_with_locale_with(&clock, locale, now + offset)
+        };
+        let past = BUCKET_OFFSETS
+            .iter()
+            .map(|&secs| render(Duration::seconds(-secs)))
+            .collect();
+        let future = BUCKET_OFFSETS
+            .iter()
+            .map(|&secs| render(Duration::seconds(secs)))
+            .collect();
+        (past, future)
+    }
+
+    /// Humanizes each of [`BUCKET_OFFSETS`] as a bare duration.
+    pub(crate) fn humanize_duration_buckets(locale: &dyn Locale) -> Vec<String> {
+        humanize_styled_duration_buckets(locale, Style::Long)
+    }
+
+    /// Like [`humanize_duration_buckets`], in `style`.
+    pub(crate) fn humanize_styled_duration_buckets(
+        locale: &dyn Locale,
+        style: Style,
+    ) -> Vec<String> {
+        BUCKET_OFFSETS
+            .iter()
+            .map(|&secs| {
+                crate::relative::humanizer::Humanizer::styled(style)
+                    .humanize_duration_with_locale(locale, chrono::Duration::seconds(secs))
+            })
+            .collect()
+    }
+
+    /// The 22nd of every month of 2026 as a long and a short date.
+    pub(crate) fn month_dates(locale: &dyn Locale) -> (Vec<String>, Vec<String>) {
+        let dates = (1..=12).map(|month| chrono::NaiveDate::from_ymd_opt(2026, month, 22).unwrap());
+        let long = dates
+            .clone()
+            .map(|date| crate::to_long_date_with_locale(date, locale).unwrap())
+            .collect();
+        let short = dates
+            .map(|date| crate::to_short_date_with_locale(date, locale).unwrap())
+            .collect();
+        (long, short)
+    }
+
+    /// Every weekday from Sunday 22 February 2026, as `"EEEE|EEE|EEEEE|EEEEEE"`.
+    pub(crate) fn weekday_names(locale: &dyn Locale) -> Vec<String> {
+        let pattern = crate::DatePattern::compile("EEEE|EEE|EEEEE|EEEEEE").unwrap();
+        (22..=28)
+            .map(|day| {
+                let date = chrono::NaiveDate::from_ymd_opt(2026, 2, day).unwrap();
+                pattern.format_with_locale(&date, locale).unwrap()
+            })
+            .collect()
+    }
+
+    struct Test;
+
+    impl Locale for Test {
+        fn plural_category(&self, n: u64) -> PluralCategory {
+            if n == 2 {
+                PluralCategory::Two
+            } else {
+                PluralCategory::Other
+            }
+        }
+
+        fn just_now(&self) -> &'static str {
+            "now"
+        }
+
+        fn yesterday(&self) -> &'static str {
+            "-1d"
+        }
+
+        fn today(&self) -> &'static str {
+            "now-ish"
+        }
+
+        fn tomorrow(&self) -> &'static str {
+            "+1d"
+        }
+
+        fn single_unit(&self, _: TimeUnit, _: Tense) -> &'static str {
+            "single"
+        }
+
+        fn weekday(&self, _: Weekday, _: Tense) -> &'static str {
+            "weekday"
+        }
+
+        fn adjacent(&self, _: TimeUnit, _: Tense) -> &'static str {
+            "adjacent"
+        }
+
+        fn relative(&self, _: Tense) -> &'static str {
+            "[{0}]"
+        }
+
+        fn list_separators(&self) -> [&'static str; 2] {
+            [";", "&"]
+        }
+
+        fn compact(&self, _: TimeUnit) -> &'static str {
+            "{0}u"
+        }
+
+        fn few_seconds(&self) -> &'static str {
+            "moment"
+        }
+
+        fn about_one(&self, _: TimeUnit) -> &'static str {
+            "about one"
+        }
+
+        fn duration_units(&self, _: TimeUnit, _: PluralCategory) -> &'static str {
+            "{0} units"
+        }
+
+        fn month_name(&self, _: Month, style: Style) -> &'static str {
+            if style == Style::Long { "month" } else { "mon" }
+        }
+
+        fn weekday_name(&self, _: Weekday, _: Style) -> &'static str {
+            "day"
+        }
+
+        fn day_period(&self, pm: bool, _: Style) -> &'static str {
+            if pm { "pm" } else { "am" }
+        }
+
+        fn long_date_pattern(&self) -> &'static str {
+            "[d|MMMM|y]"
+        }
+
+        fn short_date_pattern(&self) -> &'static str {
+            "[d|MMM|yy]"
+        }
+
+        fn units(&self, _: TimeUnit, _: Tense, category: PluralCategory) -> &'static str {
+            if category == PluralCategory::Two {
+                "pair"
+            } else {
+                "<{0}>"
+            }
+        }
+    }
+
+    /// A locale written against the first release of the trait, which
+    /// relies on every later default.
+    struct Minimal;
+
+    impl Locale for Minimal {
+        fn plural_category(&self, _: u64) -> PluralCategory {
+            PluralCategory::Other
+        }
+
+        fn just_now(&self) -> &'static str {
+            "now"
+        }
+
+        fn yesterday(&self) -> &'static str {
+            "-1d"
+        }
+
+        fn tomorrow(&self) -> &'static str {
+            "+1d"
+        }
+
+        fn single_unit(&self, _: TimeUnit, _: Tense) -> &'static str {
+            "single"
+        }
+
+        fn units(&self, _: TimeUnit, _: Tense, _: PluralCategory) -> &'static str {
+            "<{0}>"
+        }
+    }
+
+    #[test]
+    fn test_later_methods_default_to_english() {
+        assert_eq!(
+            Minimal.weekday(Weekday::Tue, Tense::Past),
+            English.weekday(Weekday::Tue, Tense::Past)
+        );
+        assert_eq!(
+            Minimal.adjacent(TimeUnit::Week, Tense::Future),
+            English.adjacent(TimeUnit::Week, Tense::Future)
+        );
+        assert_eq!(Minimal.relative(Tense::Past), "{0} ago");
+        assert_eq!(Minimal.list_separators(), English.list_separators());
+        assert_eq!(Minimal.compact(TimeUnit::Hour), "{0}h");
+        assert_eq!(Minimal.today(), "today");
+        assert_eq!(Minimal.few_seconds(), "a few seconds");
+        assert_eq!(Minimal.about_one(TimeUnit::Minute), "about a minute");
+        assert_eq!(
+            Minimal.duration_units(TimeUnit::Day, PluralCategory::Other),
+            "{0} days"
+        );
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+        assert_eq!(
+            crate::to_long_date_with_locale(date, &Minimal).unwrap(),
+            "February 22, 2026"
+        );
+        assert_eq!(
+            crate::to_short_date_with_locale(date, &Minimal).unwrap(),
+            "Feb 22, 2026"
+        );
+        assert_eq!(Minimal.day_period(true, Style::Long), "PM");
+    }
+
+    #[test]
+    fn test_write_units_fills_pattern() {
+        let mut out = String::new();
+        write_units(&Test, 7, TimeUnit::Day, Tense::Past, Style::Long, &mut out).unwrap();
+        assert_eq!(out, "<7>");
+    }
+
+    #[test]
+    fn test_write_units_pattern_without_number() {
+        let mut out = String::new();
+        write_units(&Test, 2, TimeUnit::Day, Tense::Past, Style::Long, &mut out).unwrap();
+        assert_eq!(out, "pair");
+    }
+
+    #[test]
+    fn test_custom_locale_drives_humanize() {
+        let (past, _) = humanize_buckets(&Test);
+        assert_eq!(past[0], "now");
+        assert_eq!(past[1], "single");
+        assert_eq!(past[3], "pair");
+        assert_eq!(past[4], "<3>");
+        assert_eq!(past[15], "-1d");
+    }
+
+    #[test]
+    fn test_custom_locale_drives_dates() {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+        assert_eq!(
+            crate::to_long_date_with_locale(date, &Test).unwrap(),
+            "[22|month|2026]"
+        );
+        assert_eq!(
+            crate::to_short_date_with_locale(date, &Test).unwrap(),
+            "[22|mon|26]"
+        );
+    }
+
+    #[test]
+    fn test_counted_strips_relative_framing() {
+        fn past(locale: &dyn Locale, unit: TimeUnit, n: u64) -> &str {
+            locale.counted(unit, Tense::Past, locale.plural_category(n))
+        }
+        assert_eq!(past(&English, TimeUnit::Minute, 2), "{0} minutes");
+        assert_eq!(past(&German, TimeUnit::Day, 2), "{0} Tagen");
+        assert_eq!(past(&Arabic, TimeUnit::Hour, 2), "ساعتين");
+        assert_eq!(past(&Japanese, TimeUnit::Year, 2), "{0} 年");
+        assert_eq!(
+            French.counted(TimeUnit::Month, Tense::Future, PluralCategory::One),
+            "{0} mois"
+        );
+    }
+
+    #[test]
+    fn test_counted_keeps_pattern_without_framing() {
+        assert_eq!(
+            Test.counted(TimeUnit::Day, Tense::Past, PluralCategory::Other),
+            "<{0}>"
+        );
+    }
+}
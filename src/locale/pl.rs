@@ -0,0 +1,558 @@
+use chrono::{Month, Weekday};
+
+use super::{Locale, PluralCategory, Style, Tense, TimeUnit};
+
+/// Polish (`pl`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Polish;
+
+impl Locale for Polish {
+    fn plural_category(&self, n: u64) -> PluralCategory {
+        if n == 1 {
+            PluralCategory::One
+        } else if matches!(n % 10, 2..=4) && !matches!(n % 100, 12..=14) {
+            PluralCategory::Few
+        } else {
+            PluralCategory::Many
+        }
+    }
+
+    fn just_now(&self) -> &'static str {
+        "przed chwilą"
+    }
+
+    fn yesterday(&self) -> &'static str {
+        "wczoraj"
+    }
+
+    fn tomorrow(&self) -> &'static str {
+        "jutro"
+    }
+
+    fn today(&self) -> &'static str {
+        "dzisiaj"
+    }
+
+    fn single_unit(&self, unit: TimeUnit, tense: Tense) -> &'static str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Second) => "sekundę temu",
+            (Tense::Past, TimeUnit::Minute) => "minutę temu",
+            (Tense::Past, TimeUnit::Hour) => "godzinę temu",
+            (Tense::Past, TimeUnit::Day) => "dzień temu",
+            (Tense::Past, TimeUnit::Week) => "tydzień temu",
+            (Tense::Past, TimeUnit::Month) => "miesiąc temu",
+            (Tense::Past, TimeUnit::Year) => "rok temu",
+            (Tense::Future, TimeUnit::Second) => "za sekundę",
+            (Tense::Future, TimeUnit::Minute) => "za minutę",
+            (Tense::Future, TimeUnit::Hour) => "za godzinę",
+            (Tense::Future, TimeUnit::Day) => "za dzień",
+            (Tense::Future, TimeUnit::Week) => "za tydzień",
+            (Tense::Future, TimeUnit::Month) => "za miesiąc",
+            (Tense::Future, TimeUnit::Year) => "za rok",
+        }
+    }
+
+    fn weekday(&self, weekday: Weekday, tense: Tense) -> &'static str {
+        match (tense, weekday) {
+            (Tense::Past, Weekday::Mon) => "w zeszły poniedziałek",
+            (Tense::Past, Weekday::Tue) => "w zeszły wtorek",
+            (Tense::Past, Weekday::Wed) => "w zeszłą środę",
+            (Tense::Past, Weekday::Thu) => "w zeszły czwartek",
+            (Tense::Past, Weekday::Fri) => "w zeszły piątek",
+            (Tense::Past, Weekday::Sat) => "w zeszłą sobotę",
+            (Tense::Past, Weekday::Sun) => "w zeszłą niedzielę",
+            (Tense::Future, Weekday::Mon) => "w przyszły poniedziałek",
+            (Tense::Future, Weekday::Tue) => "w przyszły wtorek",
+            (Tense::Future, Weekday::Wed) => "w przyszłą środę",
+            (Tense::Future, Weekday::Thu) => "w przyszły czwartek",
+            (Tense::Future, Weekday::Fri) => "w przyszły piątek",
+            (Tense::Future, Weekday::Sat) => "w przyszłą sobotę",
+            (Tense::Future, Weekday::Sun) => "w przyszłą niedzielę",
+        }
+    }
+
+    fn adjacent(&self, unit: TimeUnit, tense: Tense) -> &str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Week) => "w zeszłym tygodniu",
+            (Tense::Past, TimeUnit::Month) => "w zeszłym miesiącu",
+            (Tense::Past, TimeUnit::Year) => "w zeszłym roku",
+            (Tense::Future, TimeUnit::Week) => "w przyszłym tygodniu",
+            (Tense::Future, TimeUnit::Month) => "w przyszłym miesiącu",
+            (Tense::Future, TimeUnit::Year) => "w przyszłym roku",
+            _ => self.single_unit(unit, tense),
+        }
+    }
+
+    fn units(&self, unit: TimeUnit, tense: Tense, category: PluralCategory) -> &'static str {
+        // `other` only applies to fractions, which humanize never produces.
+        let [one, few, many, other] = match (tense, unit) {
+            (Tense::Past, TimeUnit::Second) => [
+                "{0} sekundę temu",
+                "{0} sekundy temu",
+                "{0} sekund temu",
+                "{0} sekundy temu",
+            ],
+            (Tense::Past, TimeUnit::Minute) => [
+                "{0} minutę temu",
+                "{0} minuty temu",
+                "{0} minut temu",
+                "{0} minuty temu",
+            ],
+            (Tense::Past, TimeUnit::Hour) => [
+                "{0} godzinę temu",
+                "{0} godziny temu",
+                "{0} godzin temu",
+                "{0} godziny temu",
+            ],
+            (Tense::Past, TimeUnit::Day) => [
+                "{0} dzień temu",
+                "{0} dni temu",
+                "{0} dni temu",
+                "{0} dnia temu",
+            ],
+            (Tense::Past, TimeUnit::Week) => [
+                "{0} tydzień temu",
+                "{0} tygodnie temu",
+                "{0} tygodni temu",
+                "{0} tygodnia temu",
+            ],
+            (Tense::Past, TimeUnit::Month) => [
+                "{0} miesiąc temu",
+                "{0} miesiące temu",
+                "{0} miesięcy temu",
+                "{0} miesiąca temu",
+            ],
+            (Tense::Past, TimeUnit::Year) => [
+                "{0} rok temu",
+                "{0} lata temu",
+                "{0} lat temu",
+                "{0} roku temu",
+            ],
+            (Tense::Future, TimeUnit::Second) => [
+                "za {0} sekundę",
+                "za {0} sekundy",
+                "za {0} sekund",
+                "za {0} sekundy",
+            ],
+            (Tense::Future, TimeUnit::Minute) => [
+                "za {0} minutę",
+                "za {0} minuty",
+                "za {0} minut",
+                "za {0} minuty",
+            ],
+            (Tense::Future, TimeUnit::Hour) => [
+                "za {0} godzinę",
+                "za {0} godziny",
+                "za {0} godzin",
+                "za {0} godziny",
+            ],
+            (Tense::Future, TimeUnit::Day) => {
+                ["za {0} dzień", "za {0} dni", "za {0} dni", "za {0} dnia"]
+            }
+            (Tense::Future, TimeUnit::Week) => [
+                "za {0} tydzień",
+                "za {0} tygodnie",
+                "za {0} tygodni",
+                "za {0} tygodnia",
+            ],
+            (Tense::Future, TimeUnit::Month) => [
+                "za {0} miesiąc",
+                "za {0} miesiące",
+                "za {0} miesięcy",
+                "za {0} miesiąca",
+            ],
+            (Tense::Future, TimeUnit::Year) => {
+                ["za {0} rok", "za {0} lata", "za {0} lat", "za {0} roku"]
+            }
+        };
+        match category {
+            PluralCategory::One => one,
+            PluralCategory::Few => few,
+            PluralCategory::Many => many,
+            _ => other,
+        }
+    }
+
+    fn relative(&self, tense: Tense) -> &'static str {
+        match tense {
+            Tense::Past => "{0} temu",
+            Tense::Future => "za {0}",
+        }
+    }
+
+    fn list_separators(&self) -> [&'static str; 2] {
+        [", ", " i "]
+    }
+
+    fn compact(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "{0} s",
+            TimeUnit::Minute => "{0} min",
+            TimeUnit::Hour => "{0} g.",
+            TimeUnit::Day => "{0} d.",
+            TimeUnit::Week => "{0} tydz.",
+            TimeUnit::Month => "{0} mies.",
+            TimeUnit::Year => "{0} r.",
+        }
+    }
+
+    fn few_seconds(&self) -> &'static str {
+        "kilka sekund"
+    }
+
+    fn about_one(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "około sekundy",
+            TimeUnit::Minute => "około minuty",
+            TimeUnit::Hour => "około godziny",
+            TimeUnit::Day => "około dnia",
+            TimeUnit::Week => "około tygodnia",
+            TimeUnit::Month => "około miesiąca",
+            TimeUnit::Year => "około roku",
+        }
+    }
+
+    fn duration_units(&self, unit: TimeUnit, category: PluralCategory) -> &'static str {
+        let [one, few, many, other] = match unit {
+            TimeUnit::Second => ["{0} sekunda", "{0} sekundy", "{0} sekund", "{0} sekundy"],
+            TimeUnit::Minute => ["{0} minuta", "{0} minuty", "{0} minut", "{0} minuty"],
+            TimeUnit::Hour => ["{0} godzina", "{0} godziny", "{0} godzin", "{0} godziny"],
+            TimeUnit::Day => ["{0} dzień", "{0} dni", "{0} dni", "{0} dnia"],
+            TimeUnit::Week => ["{0} tydzień", "{0} tygodnie", "{0} tygodni", "{0} tygodnia"],
+            TimeUnit::Month => [
+                "{0} miesiąc",
+                "{0} miesiące",
+                "{0} miesięcy",
+                "{0} miesiąca",
+            ],
+            TimeUnit::Year => ["{0} rok", "{0} lata", "{0} lat", "{0} roku"],
+        };
+        match category {
+            PluralCategory::One => one,
+            PluralCategory::Few => few,
+            PluralCategory::Many => many,
+            _ => other,
+        }
+    }
+
+    fn month_name(&self, month: Month, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long => [
+                "stycznia",
+                "lutego",
+                "marca",
+                "kwietnia",
+                "maja",
+                "czerwca",
+                "lipca",
+                "sierpnia",
+                "września",
+                "października",
+                "listopada",
+                "grudnia",
+            ],
+            Style::Short => [
+                "sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru",
+            ],
+            Style::Narrow => ["s", "l", "m", "k", "m", "c", "l", "s", "w", "p", "l", "g"],
+        };
+        names[month.number_from_month() as usize - 1]
+    }
+
+    fn weekday_name(&self, weekday: Weekday, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long => [
+                "niedziela",
+                "poniedziałek",
+                "wtorek",
+                "środa",
+                "czwartek",
+                "piątek",
+                "sobota",
+            ],
+            Style::Short => ["niedz.", "pon.", "wt.", "śr.", "czw.", "pt.", "sob."],
+            Style::Narrow => ["n", "p", "w", "ś", "c", "p", "s"],
+        };
+        names[weekday.num_days_from_sunday() as usize]
+    }
+
+    fn weekday_short(&self, weekday: Weekday) -> &'static str {
+        ["nie", "pon", "wto", "śro", "czw", "pią", "sob"][weekday.num_days_from_sunday() as usize]
+    }
+
+    fn day_period(&self, pm: bool, style: Style) -> &'static str {
+        match (style, pm) {
+            (Style::Narrow, false) => "a",
+            (Style::Narrow, true) => "p",
+            (_, false) => "AM",
+            (_, true) => "PM",
+        }
+    }
+
+    fn long_date_pattern(&self) -> &'static str {
+        "d MMMM y"
+    }
+
+    fn short_date_pattern(&self) -> &'static str {
+        "d MMM y"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::tests::{
+        humanize_buckets, humanize_duration_buckets, month_dates, weekday_names,
+    };
+
+    #[test]
+    fn test_plural_categories() {
+        assert_eq!(Polish.plural_category(1), PluralCategory::One);
+        assert_eq!(Polish.plural_category(2), PluralCategory::Few);
+        assert_eq!(Polish.plural_category(4), PluralCategory::Few);
+        assert_eq!(Polish.plural_category(5), PluralCategory::Many);
+        assert_eq!(Polish.plural_category(11), PluralCategory::Many);
+        assert_eq!(Polish.plural_category(12), PluralCategory::Many);
+        assert_eq!(Polish.plural_category(21), PluralCategory::Many);
+        assert_eq!(Polish.plural_category(22), PluralCategory::Few);
+        assert_eq!(Polish.plural_category(0), PluralCategory::Many);
+    }
+
+    #[test]
+    fn test_every_bucket() {
+        let (past, future) = humanize_buckets(&Polish);
+        assert_eq!(
+            past,
+            [
+                "przed chwilą",
+                "minutę temu",
+                "1 minutę temu",
+                "2 minuty temu",
+                "3 minuty temu",
+                "5 minut temu",
+                "11 minut temu",
+                "21 minut temu",
+                "22 minuty temu",
+                "godzinę temu",
+                "1 godzinę temu",
+                "2 godziny temu",
+                "3 godziny temu",
+                "5 godzin temu",
+                "21 godzin temu",
+                "wczoraj",
+                "1 dzień temu",
+                "2 dni temu",
+                "3 dni temu",
+                "5 dni temu",
+                "11 dni temu",
+                "22 dni temu",
+                "miesiąc temu",
+                "1 miesiąc temu",
+                "2 miesiące temu",
+                "5 miesięcy temu",
+                "rok temu",
+                "1 rok temu",
+                "2 lata temu",
+                "3 lata temu",
+                "5 lat temu",
+                "11 lat temu",
+                "21 lat temu",
+                "22 lata temu",
+                "100 lat temu",
+                "102 lata temu",
+            ]
+        );
+        assert_eq!(
+            future,
+            [
+                "przed chwilą",
+                "za minutę",
+                "za 1 minutę",
+                "za 2 minuty",
+                "za 3 minuty",
+                "za 5 minut",
+                "za 11 minut",
+                "za 21 minut",
+                "za 22 minuty",
+                "za godzinę",
+                "za 1 godzinę",
+                "za 2 godziny",
+                "za 3 godziny",
+                "za 5 godzin",
+                "za 21 godzin",
+                "jutro",
+                "za 1 dzień",
+                "za 2 dni",
+                "za 3 dni",
+                "za 5 dni",
+                "za 11 dni",
+                "za 22 dni",
+                "za miesiąc",
+                "za 1 miesiąc",
+                "za 2 miesiące",
+                "za 5 miesięcy",
+                "za rok",
+                "za 1 rok",
+                "za 2 lata",
+                "za 3 lata",
+                "za 5 lat",
+                "za 11 lat",
+                "za 21 lat",
+                "za 22 lata",
+                "za 100 lat",
+                "za 102 lata",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_duration_bucket() {
+        assert_eq!(
+            humanize_duration_buckets(&Polish),
+            [
+                "kilka sekund",
+                "około minuty",
+                "1 minuta",
+                "2 minuty",
+                "3 minuty",
+                "5 minut",
+                "11 minut",
+                "21 minut",
+                "22 minuty",
+                "około godziny",
+                "1 godzina",
+                "2 godziny",
+                "3 godziny",
+                "5 godzin",
+                "21 godzin",
+                "około dnia",
+                "1 dzień",
+                "2 dni",
+                "3 dni",
+                "5 dni",
+                "11 dni",
+                "22 dni",
+                "około miesiąca",
+                "1 miesiąc",
+                "2 miesiące",
+                "5 miesięcy",
+                "około roku",
+                "1 rok",
+                "2 lata",
+                "3 lata",
+                "5 lat",
+                "11 lat",
+                "21 lat",
+                "22 lata",
+                "100 lat",
+                "102 lata",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calendar_phrases() {
+        assert_eq!(Polish.today(), "dzisiaj");
+        let days = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        assert_eq!(
+            days.map(|day| Polish.weekday(day, Tense::Past)),
+            [
+                "w zeszły poniedziałek",
+                "w zeszły wtorek",
+                "w zeszłą środę",
+                "w zeszły czwartek",
+                "w zeszły piątek",
+                "w zeszłą sobotę",
+                "w zeszłą niedzielę",
+            ]
+        );
+        assert_eq!(
+            days.map(|day| Polish.weekday(day, Tense::Future)),
+            [
+                "w przyszły poniedziałek",
+                "w przyszły wtorek",
+                "w przyszłą środę",
+                "w przyszły czwartek",
+                "w przyszły piątek",
+                "w przyszłą sobotę",
+                "w przyszłą niedzielę",
+            ]
+        );
+        let units = [TimeUnit::Week, TimeUnit::Month, TimeUnit::Year];
+        assert_eq!(
+            units.map(|unit| Polish.adjacent(unit, Tense::Past)),
+            ["w zeszłym tygodniu", "w zeszłym miesiącu", "w zeszłym roku"]
+        );
+        assert_eq!(
+            units.map(|unit| Polish.adjacent(unit, Tense::Future)),
+            [
+                "w przyszłym tygodniu",
+                "w przyszłym miesiącu",
+                "w przyszłym roku"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_month_date() {
+        let (long, short) = month_dates(&Polish);
+        assert_eq!(
+            long,
+            [
+                "22 stycznia 2026",
+                "22 lutego 2026",
+                "22 marca 2026",
+                "22 kwietnia 2026",
+                "22 maja 2026",
+                "22 czerwca 2026",
+                "22 lipca 2026",
+                "22 sierpnia 2026",
+                "22 września 2026",
+                "22 października 2026",
+                "22 listopada 2026",
+                "22 grudnia 2026",
+            ]
+        );
+        assert_eq!(
+            short,
+            [
+                "22 sty 2026",
+                "22 lut 2026",
+                "22 mar 2026",
+                "22 kwi 2026",
+                "22 maj 2026",
+                "22 cze 2026",
+                "22 lip 2026",
+                "22 sie 2026",
+                "22 wrz 2026",
+                "22 paź 2026",
+                "22 lis 2026",
+                "22 gru 2026",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekday_names() {
+        assert_eq!(
+            weekday_names(&Polish),
+            [
+                "niedziela|niedz.|n|nie",
+                "poniedziałek|pon.|p|pon",
+                "wtorek|wt.|w|wto",
+                "środa|śr.|ś|śro",
+                "czwartek|czw.|c|czw",
+                "piątek|pt.|p|pią",
+                "sobota|sob.|s|sob",
+            ]
+        );
+    }
+}
@@ -0,0 +1,561 @@
+use chrono::{Month, Weekday};
+
+use super::{Locale, PluralCategory, Style, Tense, TimeUnit};
+
+/// Russian (`ru`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Russian;
+
+impl Locale for Russian {
+    fn plural_category(&self, n: u64) -> PluralCategory {
+        if n % 10 == 1 && n % 100 != 11 {
+            PluralCategory::One
+        } else if matches!(n % 10, 2..=4) && !matches!(n % 100, 12..=14) {
+            PluralCategory::Few
+        } else {
+            PluralCategory::Many
+        }
+    }
+
+    fn just_now(&self) -> &'static str {
+        "только что"
+    }
+
+    fn yesterday(&self) -> &'static str {
+        "вчера"
+    }
+
+    fn tomorrow(&self) -> &'static str {
+        "завтра"
+    }
+
+    fn today(&self) -> &'static str {
+        "сегодня"
+    }
+
+    fn single_unit(&self, unit: TimeUnit, tense: Tense) -> &'static str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Second) => "секунду назад",
+            (Tense::Past, TimeUnit::Minute) => "минуту назад",
+            (Tense::Past, TimeUnit::Hour) => "час назад",
+            (Tense::Past, TimeUnit::Day) => "день назад",
+            (Tense::Past, TimeUnit::Week) => "неделю назад",
+            (Tense::Past, TimeUnit::Month) => "месяц назад",
+            (Tense::Past, TimeUnit::Year) => "год назад",
+            (Tense::Future, TimeUnit::Second) => "через секунду",
+            (Tense::Future, TimeUnit::Minute) => "через минуту",
+            (Tense::Future, TimeUnit::Hour) => "через час",
+            (Tense::Future, TimeUnit::Day) => "через день",
+            (Tense::Future, TimeUnit::Week) => "через неделю",
+            (Tense::Future, TimeUnit::Month) => "через месяц",
+            (Tense::Future, TimeUnit::Year) => "через год",
+        }
+    }
+
+    fn weekday(&self, weekday: Weekday, tense: Tense) -> &'static str {
+        match (tense, weekday) {
+            (Tense::Past, Weekday::Mon) => "в прошлый понедельник",
+            (Tense::Past, Weekday::Tue) => "в прошлый вторник",
+            (Tense::Past, Weekday::Wed) => "в прошлую среду",
+            (Tense::Past, Weekday::Thu) => "в прошлый четверг",
+            (Tense::Past, Weekday::Fri) => "в прошлую пятницу",
+            (Tense::Past, Weekday::Sat) => "в прошлую субботу",
+            (Tense::Past, Weekday::Sun) => "в прошлое воскресенье",
+            (Tense::Future, Weekday::Mon) => "в следующий понедельник",
+            (Tense::Future, Weekday::Tue) => "в следующий вторник",
+            (Tense::Future, Weekday::Wed) => "в следующую среду",
+            (Tense::Future, Weekday::Thu) => "в следующий четверг",
+            (Tense::Future, Weekday::Fri) => "в следующую пятницу",
+            (Tense::Future, Weekday::Sat) => "в следующую субботу",
+            (Tense::Future, Weekday::Sun) => "в следующее воскресенье",
+        }
+    }
+
+    fn adjacent(&self, unit: TimeUnit, tense: Tense) -> &str {
+        match (tense, unit) {
+            (Tense::Past, TimeUnit::Week) => "на прошлой неделе",
+            (Tense::Past, TimeUnit::Month) => "в прошлом месяце",
+            (Tense::Past, TimeUnit::Year) => "в прошлом году",
+            (Tense::Future, TimeUnit::Week) => "на следующей неделе",
+            (Tense::Future, TimeUnit::Month) => "в следующем месяце",
+            (Tense::Future, TimeUnit::Year) => "в следующем году",
+            _ => self.single_unit(unit, tense),
+        }
+    }
+
+    fn units(&self, unit: TimeUnit, tense: Tense, category: PluralCategory) -> &'static str {
+        // `other` only applies to fractions, which humanize never produces.
+        let [one, few, many, other] = match (tense, unit) {
+            (Tense::Past, TimeUnit::Second) => [
+                "{0} секунду назад",
+                "{0} секунды назад",
+                "{0} секунд назад",
+                "{0} секунды назад",
+            ],
+            (Tense::Past, TimeUnit::Minute) => [
+                "{0} минуту назад",
+                "{0} минуты назад",
+                "{0} минут назад",
+                "{0} минуты назад",
+            ],
+            (Tense::Past, TimeUnit::Hour) => [
+                "{0} час назад",
+                "{0} часа назад",
+                "{0} часов назад",
+                "{0} часа назад",
+            ],
+            (Tense::Past, TimeUnit::Day) => [
+                "{0} день назад",
+                "{0} дня назад",
+                "{0} дней назад",
+                "{0} дня назад",
+            ],
+            (Tense::Past, TimeUnit::Week) => [
+                "{0} неделю назад",
+                "{0} недели назад",
+                "{0} недель назад",
+                "{0} недели назад",
+            ],
+            (Tense::Past, TimeUnit::Month) => [
+                "{0} месяц назад",
+                "{0} месяца назад",
+                "{0} месяцев назад",
+                "{0} месяца назад",
+            ],
+            (Tense::Past, TimeUnit::Year) => [
+                "{0} год назад",
+                "{0} года назад",
+                "{0} лет назад",
+                "{0} года назад",
+            ],
+            (Tense::Future, TimeUnit::Second) => [
+                "через {0} секунду",
+                "через {0} секунды",
+                "через {0} секунд",
+                "через {0} секунды",
+            ],
+            (Tense::Future, TimeUnit::Minute) => [
+                "через {0} минуту",
+                "через {0} минуты",
+                "через {0} минут",
+                "через {0} минуты",
+            ],
+            (Tense::Future, TimeUnit::Hour) => [
+                "через {0} час",
+                "через {0} часа",
+                "через {0} часов",
+                "через {0} часа",
+            ],
+            (Tense::Future, TimeUnit::Day) => [
+                "через {0} день",
+                "через {0} дня",
+                "через {0} дней",
+                "через {0} дня",
+            ],
+            (Tense::Future, TimeUnit::Week) => [
+                "через {0} неделю",
+                "через {0} недели",
+                "через {0} недель",
+                "через {0} недели",
+            ],
+            (Tense::Future, TimeUnit::Month) => [
+                "через {0} месяц",
+                "через {0} месяца",
+                "через {0} месяцев",
+                "через {0} месяца",
+            ],
+            (Tense::Future, TimeUnit::Year) => [
+                "через {0} год",
+                "через {0} года",
+                "через {0} лет",
+                "через {0} года",
+            ],
+        };
+        match category {
+            PluralCategory::One => one,
+            PluralCategory::Few => few,
+            PluralCategory::Many => many,
+            _ => other,
+        }
+    }
+
+    fn relative(&self, tense: Tense) -> &'static str {
+        match tense {
+            Tense::Past => "{0} назад",
+            Tense::Future => "через {0}",
+        }
+    }
+
+    fn list_separators(&self) -> [&'static str; 2] {
+        [", ", " и "]
+    }
+
+    fn compact(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "{0} с",
+            TimeUnit::Minute => "{0} мин",
+            TimeUnit::Hour => "{0} ч",
+            TimeUnit::Day => "{0} д",
+            TimeUnit::Week => "{0} нед.",
+            TimeUnit::Month => "{0} м.",
+            TimeUnit::Year => "{0} г.",
+        }
+    }
+
+    fn few_seconds(&self) -> &'static str {
+        "несколько секунд"
+    }
+
+    fn about_one(&self, unit: TimeUnit) -> &'static str {
+        match unit {
+            TimeUnit::Second => "около секунды",
+            TimeUnit::Minute => "около минуты",
+            TimeUnit::Hour => "около часа",
+            TimeUnit::Day => "около дня",
+            TimeUnit::Week => "около недели",
+            TimeUnit::Month => "около месяца",
+            TimeUnit::Year => "около года",
+        }
+    }
+
+    fn duration_units(&self, unit: TimeUnit, category: PluralCategory) -> &'static str {
+        let [one, few, many, other] = match unit {
+            TimeUnit::Second => ["{0} секунда", "{0} секунды", "{0} секунд", "{0} секунды"],
+            TimeUnit::Minute => ["{0} минута", "{0} минуты", "{0} минут", "{0} минуты"],
+            TimeUnit::Hour => ["{0} час", "{0} часа", "{0} часов", "{0} часа"],
+            TimeUnit::Day => ["{0} день", "{0} дня", "{0} дней", "{0} дня"],
+            TimeUnit::Week => ["{0} неделя", "{0} недели", "{0} недель", "{0} недели"],
+            TimeUnit::Month => ["{0} месяц", "{0} месяца", "{0} месяцев", "{0} месяца"],
+            TimeUnit::Year => ["{0} год", "{0} года", "{0} лет", "{0} года"],
+        };
+        match category {
+            PluralCategory::One => one,
+            PluralCategory::Few => few,
+            PluralCategory::Many => many,
+            _ => other,
+        }
+    }
+
+    fn month_name(&self, month: Month, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long => [
+                "января",
+                "февраля",
+                "марта",
+                "апреля",
+                "мая",
+                "июня",
+                "июля",
+                "августа",
+                "сентября",
+                "октября",
+                "ноября",
+                "декабря",
+            ],
+            Style::Short => [
+                "янв.",
+                "февр.",
+                "мар.",
+                "апр.",
+                "мая",
+                "июн.",
+                "июл.",
+                "авг.",
+                "сент.",
+                "окт.",
+                "нояб.",
+                "дек.",
+            ],
+            Style::Narrow => ["Я", "Ф", "М", "А", "М", "И", "И", "А", "С", "О", "Н", "Д"],
+        };
+        names[month.number_from_month() as usize - 1]
+    }
+
+    fn weekday_name(&self, weekday: Weekday, style: Style) -> &'static str {
+        let names = match style {
+            Style::Long => [
+                "воскресенье",
+                "понедельник",
+                "вторник",
+                "среда",
+                "четверг",
+                "пятница",
+                "суббота",
+            ],
+            Style::Short => ["вс", "пн", "вт", "ср", "чт", "пт", "сб"],
+            Style::Narrow => ["В", "П", "В", "С", "Ч", "П", "С"],
+        };
+        names[weekday.num_days_from_sunday() as usize]
+    }
+
+    fn day_period(&self, pm: bool, _: Style) -> &'static str {
+        if pm { "PM" } else { "AM" }
+    }
+
+    fn long_date_pattern(&self) -> &'static str {
+        "d MMMM y 'г'."
+    }
+
+    fn short_date_pattern(&self) -> &'static str {
+        "d MMM y 'г'."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::tests::{
+        humanize_buckets, humanize_duration_buckets, month_dates, weekday_names,
+    };
+
+    #[test]
+    fn test_plural_categories() {
+        assert_eq!(Russian.plural_category(1), PluralCategory::One);
+        assert_eq!(Russian.plural_category(2), PluralCategory::Few);
+        assert_eq!(Russian.plural_category(5), PluralCategory::Many);
+        assert_eq!(Russian.plural_category(11), PluralCategory::Many);
+        assert_eq!(Russian.plural_category(14), PluralCategory::Many);
+        assert_eq!(Russian.plural_category(21), PluralCategory::One);
+        assert_eq!(Russian.plural_category(22), PluralCategory::Few);
+        assert_eq!(Russian.plural_category(111), PluralCategory::Many);
+        assert_eq!(Russian.plural_category(0), PluralCategory::Many);
+    }
+
+    #[test]
+    fn test_every_bucket() {
+        let (past, future) = humanize_buckets(&Russian);
+        assert_eq!(
+            past,
+            [
+                "только что",
+                "минуту назад",
+                "1 минуту назад",
+                "2 минуты назад",
+                "3 минуты назад",
+                "5 минут назад",
+                "11 минут назад",
+                "21 минуту назад",
+                "22 минуты назад",
+                "час назад",
+                "1 час назад",
+                "2 часа назад",
+                "3 часа назад",
+                "5 часов назад",
+                "21 час назад",
+                "вчера",
+                "1 день назад",
+                "2 дня назад",
+                "3 дня назад",
+                "5 дней назад",
+                "11 дней назад",
+                "22 дня назад",
+                "месяц назад",
+                "1 месяц назад",
+                "2 месяца назад",
+                "5 месяцев назад",
+                "год назад",
+                "1 год назад",
+                "2 года назад",
+                "3 года назад",
+                "5 лет назад",
+                "11 лет назад",
+                "21 год назад",
+                "22 года назад",
+                "100 лет назад",
+                "102 года назад",
+            ]
+        );
+        assert_eq!(
+            future,
+            [
+                "только что",
+                "через минуту",
+                "через 1 минуту",
+                "через 2 минуты",
+                "через 3 минуты",
+                "через 5 минут",
+                "через 11 минут",
+                "через 21 минуту",
+                "через 22 минуты",
+                "через час",
+                "через 1 час",
+                "через 2 часа",
+                "через 3 часа",
+                "через 5 часов",
+                "через 21 час",
+                "завтра",
+                "через 1 день",
+                "через 2 дня",
+                "через 3 дня",
+                "через 5 дней",
+                "через 11 дней",
+                "через 22 дня",
+                "через месяц",
+                "через 1 месяц",
+                "через 2 месяца",
+                "через 5 месяцев",
+                "через год",
+                "через 1 год",
+                "через 2 года",
+                "через 3 года",
+                "через 5 лет",
+                "через 11 лет",
+                "через 21 год",
+                "через 22 года",
+                "через 100 лет",
+                "через 102 года",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_duration_bucket() {
+        assert_eq!(
+            humanize_duration_buckets(&Russian),
+            [
+                "несколько секунд",
+                "около минуты",
+                "1 минута",
+                "2 минуты",
+                "3 минуты",
+                "5 минут",
+                "11 минут",
+                "21 минута",
+                "22 минуты",
+                "около часа",
+                "1 час",
+                "2 часа",
+                "3 часа",
+                "5 часов",
+                "21 час",
+                "около дня",
+                "1 день",
+                "2 дня",
+                "3 дня",
+                "5 дней",
+                "11 дней",
+                "22 дня",
+                "около месяца",
+                "1 месяц",
+                "2 месяца",
+                "5 месяцев",
+                "около года",
+                "1 год",
+                "2 года",
+                "3 года",
+                "5 лет",
+                "11 лет",
+                "21 год",
+                "22 года",
+                "100 лет",
+                "102 года",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calendar_phrases() {
+        assert_eq!(Russian.today(), "сегодня");
+        let days = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+        assert_eq!(
+            days.map(|day| Russian.weekday(day, Tense::Past)),
+            [
+                "в прошлый понедельник",
+                "в прошлый вторник",
+                "в прошлую среду",
+                "в прошлый четверг",
+                "в прошлую пятницу",
+                "в прошлую субботу",
+                "в прошлое воскресенье",
+            ]
+        );
+        assert_eq!(
+            days.map(|day| Russian.weekday(day, Tense::Future)),
+            [
+                "в следующий понедельник",
+                "в следующий вторник",
+                "в следующую среду",
+                "в следующий четверг",
+                "в следующую пятницу",
+                "в следующую субботу",
+                "в следующее воскресенье",
+            ]
+        );
+        let units = [TimeUnit::Week, TimeUnit::Month, TimeUnit::Year];
+        assert_eq!(
+            units.map(|unit| Russian.adjacent(unit, Tense::Past)),
+            ["на прошлой неделе", "в прошлом месяце", "в прошлом году"]
+        );
+        assert_eq!(
+            units.map(|unit| Russian.adjacent(unit, Tense::Future)),
+            [
+                "на следующей неделе",
+                "в следующем месяце",
+                "в следующем году"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_month_date() {
+        let (long, short) = month_dates(&Russian);
+        assert_eq!(
+            long,
+            [
+                "22 января 2026 г.",
+                "22 февраля 2026 г.",
+                "22 марта 2026 г.",
+                "22 апреля 2026 г.",
+                "22 мая 2026 г.",
+                "22 июня 2026 г.",
+                "22 июля 2026 г.",
+                "22 августа 2026 г.",
+                "22 сентября 2026 г.",
+                "22 октября 2026 г.",
+                "22 ноября 2026 г.",
+                "22 декабря 2026 г.",
+            ]
+        );
+        assert_eq!(
+            short,
+            [
+                "22 янв. 2026 г.",
+                "22 февр. 2026 г.",
+                "22 мар. 2026 г.",
+                "22 апр. 2026 г.",
+                "22 мая 2026 г.",
+                "22 июн. 2026 г.",
+                "22 июл. 2026 г.",
+                "22 авг. 2026 г.",
+                "22 сент. 2026 г.",
+                "22 окт. 2026 г.",
+                "22 нояб. 2026 г.",
+                "22 дек. 2026 г.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekday_names() {
+        assert_eq!(
+            weekday_names(&Russian),
+            [
+                "воскресенье|вс|В|вс",
+                "понедельник|пн|П|пн",
+                "вторник|вт|В|вт",
+                "среда|ср|С|ср",
+                "четверг|чт|Ч|чт",
+                "пятница|пт|П|пт",
+                "суббота|сб|С|сб",
+            ]
+        );
+    }
+}
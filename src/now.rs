@@ -1,23 +1,54 @@
-use chrono::{DateTime, Local, NaiveDate};
+use chrono::{DateTime, Local, NaiveDate, TimeZone};
+
+use crate::clock::{Clock, SystemClock};
 
 /// Returns the current local date and time.
 #[must_use]
 #[inline]
 pub fn now() -> DateTime<Local> {
-    Local::now()
+    now_with(&SystemClock)
+}
+
+/// Returns the current local date and time as reported by `clock`.
+#[must_use]
+#[inline]
+pub fn now_with(clock: &(impl Clock + ?Sized)) -> DateTime<Local> {
+    clock.now().with_timezone(&Local)
+}
+
+/// Returns the current date and time in the timezone `tz`.
+#[must_use]
+#[inline]
+pub fn now_in<Tz: TimeZone>(tz: &Tz) -> DateTime<Tz> {
+    SystemClock.now().with_timezone(tz)
 }
 
 /// Returns today's local date (no time component).
 #[must_use]
 #[inline]
 pub fn today() -> NaiveDate {
-    Local::now().date_naive()
+    today_with(&SystemClock)
+}
+
+/// Returns the local date (no time component) as reported by `clock`.
+#[must_use]
+#[inline]
+pub fn today_with(clock: &(impl Clock + ?Sized)) -> NaiveDate {
+    now_with(clock).date_naive()
+}
+
+/// Returns today's date in the timezone `tz` (no time component).
+#[must_use]
+#[inline]
+pub fn today_in<Tz: TimeZone>(tz: &Tz) -> NaiveDate {
+    now_in(tz).date_naive()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Local;
+    use crate::clock::{Clock, MockClock};
+    use chrono::{FixedOffset, Local, TimeZone, Utc};
 
     #[test]
     fn test_now_returns_current_datetime() {
@@ -35,4 +66,39 @@ mod tests {
         let expected = Local::now().date_naive();
         assert_eq!(date, expected);
     }
+
+    #[test]
+    fn test_now_with_returns_clock_instant() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 14, 30, 0).unwrap());
+        assert_eq!(now_with(&clock).to_utc(), clock.now());
+    }
+
+    #[test]
+    fn test_today_with_returns_clock_local_date() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 14, 30, 0).unwrap());
+        assert_eq!(
+            today_with(&clock),
+            clock.now().with_timezone(&Local).date_naive()
+        );
+    }
+
+    #[test]
+    fn test_now_in_returns_current_instant_in_zone() {
+        let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+        let before = Utc::now();
+        let result = now_in(&tz);
+        let after = Utc::now();
+
+        assert!(result >= before && result <= after);
+        assert_eq!(result.offset(), &tz);
+    }
+
+    #[test]
+    fn test_today_in_matches_zone_date() {
+        let tz = FixedOffset::west_opt(11 * 3600).unwrap();
+        let lower = Utc::now().with_timezone(&tz).date_naive();
+        let date = today_in(&tz);
+        let upper = Utc::now().with_timezone(&tz).date_naive();
+        assert!(date >= lower && date <= upper);
+    }
 }
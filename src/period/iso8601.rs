@@ -0,0 +1,614 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::{NANOS_PER_SECOND, Period};
+use crate::error::PeriodError;
+
+const FORMAT: &str = "ISO 8601 duration";
+
+/// Fractions are kept to 18 decimal places, so that even a fraction of a
+/// week is exact down to the nanosecond.
+const FRACTION_SCALE: i128 = 1_000_000_000_000_000_000;
+const FRACTION_DIGITS: usize = 18;
+
+/// Indices into the component array used while parsing, in the order the
+/// designators must appear.
+const YEARS: usize = 0;
+const MONTHS: usize = 1;
+const WEEKS: usize = 2;
+const DAYS: usize = 3;
+const HOURS: usize = 4;
+const MINUTES: usize = 5;
+const SECONDS: usize = 6;
+const NANOSECONDS: usize = 7;
+
+impl Period {
+    /// Parses an ISO 8601 duration such as `"P1Y2M3DT4H5M6S"`.
+    ///
+    /// Accepted forms:
+    ///
+    /// - the date part `nYnMnWnD` and time part `TnHnMnS`, each component
+    ///   optional but in that order, and at least one present. `W` may be
+    ///   combined with the other date components (ISO 8601-2), so both
+    ///   `"P2W"` and `"P1M2W"` parse;
+    /// - a fraction on the last component, with `.` or `,` as the separator
+    ///   (`"PT1.5S"`, `"P0,5D"`). The fraction is carried into the smaller
+    ///   units down to nanoseconds, so `"P1.5D"` is 1 day 12 hours. Months
+    ///   have no exact length in days, so a fraction that would leave part
+    ///   of a month (`"P1.5M"`, `"P0.1Y"`) is rejected;
+    /// - a leading `-` or `+` sign, and a `-` on individual components
+    ///   (`"-P1D"`, `"P1M-3D"`), as used by several other libraries.
+    ///
+    /// Designators are case-sensitive. The result can be applied directly with
+    /// [`ago`](Period::ago), [`from_now`](Period::from_now) or
+    /// [`add_to`](Period::add_to).
+    ///
+    /// # Errors
+    /// Returns [`PeriodError::Parse`] with the byte offset of the first
+    /// invalid character, or of the number that does not fit in an `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use period::Period;
+    ///
+    /// # fn main() -> Result<(), period::PeriodError> {
+    /// let p = Period::parse_iso8601("P1DT2.5H")?;
+    /// assert_eq!(p, Period::days(1).with_hours(2).with_minutes(30));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_iso8601(s: &str) -> Result<Period, PeriodError> {
+        let bytes = s.as_bytes();
+        let mut pos = 0;
+        let negative = match bytes.first() {
+            Some(b'-') => {
+                pos += 1;
+                true
+            }
+            Some(b'+') => {
+                pos += 1;
+                false
+            }
+            _ => false,
+        };
+        if bytes.get(pos) != Some(&b'P') {
+            return Err(error(pos, "expected 'P'"));
+        }
+        pos += 1;
+
+        let mut components = [0_i64; 8];
+        let mut last: Option<usize> = None;
+        let mut in_time = false;
+        let mut fraction_at: Option<usize> = None;
+
+        while pos < bytes.len() {
+            if bytes[pos] == b'T' {
+                if in_time {
+                    return Err(error(pos, "'T' may only appear once"));
+                }
+                in_time = true;
+                pos += 1;
+                if pos == bytes.len() {
+                    return Err(error(pos, "expected a time component after 'T'"));
+                }
+                continue;
+            }
+            if let Some(at) = fraction_at {
+                return Err(error(at, "only the last component may have a fraction"));
+            }
+
+            let start = pos;
+            let mut component_negative = negative;
+            if bytes[pos] == b'-' {
+                component_negative = !component_negative;
+                pos += 1;
+            }
+            let (whole, fraction) = parse_number(bytes, &mut pos, start)?;
+            let fraction = match fraction {
+                Some((separator, fraction)) => {
+                    fraction_at = Some(separator);
+                    fraction
+                }
+                None => 0,
+            };
+            let index = designator(bytes, pos, in_time)?;
+            if last.is_some_and(|last| index <= last) {
+                return Err(error(pos, "components are out of order or repeated"));
+            }
+            last = Some(index);
+            pos += 1;
+
+            // Magnitudes are kept in i128 and signed at the end, so that
+            // `-P9223372036854775808D` reaches `i64::MIN`.
+            let mut part = [0_i128; 8];
+            part[index] = whole;
+            carry_fraction(&mut part, index, fraction).map_err(|reason| error(start, reason))?;
+            // Each component is written once and a fraction only carries into
+            // units after the last one, so no slot is ever filled twice.
+            for (total, value) in components.iter_mut().zip(part) {
+                if value != 0 {
+                    let value = if component_negative { -value } else { value };
+                    *total =
+                        i64::try_from(value).map_err(|_| error(start, "value is too large"))?;
+                }
+            }
+        }
+
+        if last.is_none() {
+            return Err(error(pos, "expected at least one component"));
+        }
+
+        let [
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+            nanoseconds,
+        ] = components;
+        Ok(Period {
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+            nanoseconds,
+        })
+    }
+}
+
+impl FromStr for Period {
+    type Err = PeriodError;
+
+    /// Same as [`Period::parse_iso8601`].
+    fn from_str(s: &str) -> Result<Period, PeriodError> {
+        Period::parse_iso8601(s)
+    }
+}
+
+/// Formats the period as an ISO 8601 duration, e.g. `"P1Y2M3DT4H5M6.5S"`.
+///
+/// Zero components are omitted and a zero period is `"PT0S"`. Weeks are kept
+/// as a separate `W` component rather than folded into days. A period whose
+/// components are all zero or negative gets a single leading `-`
+/// (`"-P1DT2H"`); a period with mixed signs puts the `-` on each negative
+/// component (`"P1M-3D"`).
+///
+/// Seconds and nanoseconds are written as one decimal number, so the output
+/// parses back to an equal [`Period`] whenever the nanoseconds are under one
+/// second and share the sign of the seconds, as they do for any parsed or
+/// [`normalized`](Period::normalized) period.
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ];
+        let time = [(self.hours, 'H'), (self.minutes, 'M')];
+        let seconds = i128::from(self.seconds) * NANOS_PER_SECOND + i128::from(self.nanoseconds);
+
+        let mut values = date
+            .iter()
+            .chain(&time)
+            .map(|&(value, _)| i128::from(value));
+        let negative = values.clone().all(|v| v <= 0)
+            && seconds <= 0
+            && (values.any(|v| v != 0) || seconds != 0);
+        let sign = if negative { -1 } else { 1 };
+
+        f.write_str(if negative { "-P" } else { "P" })?;
+        let mut empty = true;
+        for (value, designator) in date {
+            if value != 0 {
+                write!(f, "{}{designator}", sign * i128::from(value))?;
+                empty = false;
+            }
+        }
+        if time.iter().any(|&(value, _)| value != 0) || seconds != 0 {
+            f.write_str("T")?;
+            for (value, designator) in time {
+                if value != 0 {
+                    write!(f, "{}{designator}", sign * i128::from(value))?;
+                }
+            }
+            if seconds != 0 {
+                write_seconds(f, sign * seconds)?;
+            }
+            empty = false;
+        }
+        if empty {
+            f.write_str("T0S")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `nanos` as a seconds component, with trailing zeros of the
+/// fraction trimmed (`1.5S`, not `1.500000000S`).
+fn write_seconds(f: &mut fmt::Formatter<'_>, nanos: i128) -> fmt::Result {
+    let sign = if nanos < 0 { "-" } else { "" };
+    let nanos = nanos.unsigned_abs();
+    let whole = nanos / NANOS_PER_SECOND.unsigned_abs();
+    let mut fraction = nanos % NANOS_PER_SECOND.unsigned_abs();
+    if fraction == 0 {
+        return write!(f, "{sign}{whole}S");
+    }
+    let mut width = 9;
+    while fraction.is_multiple_of(10) {
+        fraction /= 10;
+        width -= 1;
+    }
+    write!(f, "{sign}{whole}.{fraction:0width$}S")
+}
+
+fn error(position: usize, reason: &'static str) -> PeriodError {
+    PeriodError::Parse {
+        format: FORMAT,
+        position,
+        reason,
+    }
+}
+
+/// Reads `digits[(.|,)digits]` at `pos`, returning the whole part and, if
+/// present, the position of the separator and the fraction.
+fn parse_number(
+    bytes: &[u8],
+    pos: &mut usize,
+    start: usize,
+) -> Result<(i128, Option<(usize, i128)>), PeriodError> {
+    let digits = take_digits(bytes, pos);
+    if digits.is_empty() {
+        return Err(error(*pos, "expected a number"));
+    }
+    let whole = parse_whole(digits).ok_or_else(|| error(start, "value is too large"))?;
+    if !matches!(bytes.get(*pos), Some(b'.' | b',')) {
+        return Ok((whole, None));
+    }
+    let separator = *pos;
+    *pos += 1;
+    let digits = take_digits(bytes, pos);
+    if digits.is_empty() {
+        return Err(error(*pos, "expected a digit after the decimal separator"));
+    }
+    Ok((whole, Some((separator, parse_fraction(digits)))))
+}
+
+/// Maps the designator at `pos` to its component index.
+fn designator(bytes: &[u8], pos: usize, in_time: bool) -> Result<usize, PeriodError> {
+    match (in_time, bytes.get(pos)) {
+        (false, Some(b'Y')) => Ok(YEARS),
+        (false, Some(b'M')) => Ok(MONTHS),
+        (false, Some(b'W')) => Ok(WEEKS),
+        (false, Some(b'D')) => Ok(DAYS),
+        (true, Some(b'H')) => Ok(HOURS),
+        (true, Some(b'M')) => Ok(MINUTES),
+        (true, Some(b'S')) => Ok(SECONDS),
+        (false, _) => Err(error(pos, "expected 'Y', 'M', 'W' or 'D'")),
+        (true, _) => Err(error(pos, "expected 'H', 'M' or 'S'")),
+    }
+}
+
+fn take_digits<'a>(bytes: &'a [u8], pos: &mut usize) -> &'a [u8] {
+    let start = *pos;
+    while bytes.get(*pos).is_some_and(u8::is_ascii_digit) {
+        *pos += 1;
+    }
+    &bytes[start..*pos]
+}
+
+fn parse_whole(digits: &[u8]) -> Option<i128> {
+    digits.iter().try_fold(0_i128, |acc, &digit| {
+        acc.checked_mul(10)?.checked_add(i128::from(digit - b'0'))
+    })
+}
+
+/// Reads fraction digits as a multiple of `1 / FRACTION_SCALE`; digits past
+/// [`FRACTION_DIGITS`] are dropped.
+fn parse_fraction(digits: &[u8]) -> i128 {
+    let mut fraction = 0;
+    for i in 0..FRACTION_DIGITS {
+        let digit = digits.get(i).map_or(0, |&d| i128::from(d - b'0'));
+        fraction = fraction * 10 + digit;
+    }
+    fraction
+}
+
+/// The next smaller unit a fraction of `index` can be expressed in exactly,
+/// and how many of it make one `index`.
+fn subdivision(index: usize) -> Option<(usize, i128)> {
+    match index {
+        YEARS => Some((MONTHS, 12)),
+        WEEKS => Some((DAYS, 7)),
+        DAYS => Some((HOURS, 24)),
+        HOURS => Some((MINUTES, 60)),
+        MINUTES => Some((SECONDS, 60)),
+        SECONDS => Some((NANOSECONDS, NANOS_PER_SECOND)),
+        _ => None,
+    }
+}
+
+/// Spreads `fraction` of component `index` over the smaller units of `part`.
+///
+/// Fails if part of a month would be left over. Anything below a nanosecond
+/// is truncated.
+fn carry_fraction(
+    part: &mut [i128; 8],
+    mut index: usize,
+    mut fraction: i128,
+) -> Result<(), &'static str> {
+    while fraction != 0 {
+        let Some((next, factor)) = subdivision(index) else {
+            if index == NANOSECONDS {
+                break;
+            }
+            return Err("a fraction of a month has no exact length");
+        };
+        let scaled = fraction * factor;
+        part[next] = scaled / FRACTION_SCALE;
+        fraction = scaled % FRACTION_SCALE;
+        index = next;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{Clock, MockClock};
+    use chrono::{Duration, NaiveDate, TimeZone, Utc};
+
+    fn parse(s: &str) -> Period {
+        Period::parse_iso8601(s).unwrap()
+    }
+
+    fn parse_err(s: &str) -> (usize, &'static str) {
+        match Period::parse_iso8601(s).unwrap_err() {
+            PeriodError::Parse {
+                position, reason, ..
+            } => (position, reason),
+            other => panic!("unexpected error {other:?}"),
+        }
+    }
+
+    // -- parsing --------------------------------------------------------------
+
+    #[test]
+    fn test_parse_full_form() {
+        assert_eq!(
+            parse("P1Y2M3DT4H5M6S"),
+            Period::years(1)
+                .with_months(2)
+                .with_days(3)
+                .with_hours(4)
+                .with_minutes(5)
+                .with_seconds(6)
+        );
+    }
+
+    #[test]
+    fn test_parse_week_form() {
+        assert_eq!(parse("P2W"), Period::weeks(2));
+        assert_eq!(
+            parse("P1M2W3D"),
+            Period::months(1).with_weeks(2).with_days(3)
+        );
+    }
+
+    #[test]
+    fn test_parse_time_only_and_date_only() {
+        assert_eq!(parse("PT36H"), Period::hours(36));
+        assert_eq!(parse("P10D"), Period::days(10));
+        assert_eq!(parse("PT0S"), Period::ZERO);
+    }
+
+    #[test]
+    fn test_parse_m_means_months_before_t_and_minutes_after() {
+        assert_eq!(parse("P1MT1M"), Period::months(1).with_minutes(1));
+    }
+
+    #[test]
+    fn test_parse_fractional_seconds() {
+        assert_eq!(
+            parse("PT1.5S"),
+            Period::seconds(1).with_nanoseconds(500_000_000)
+        );
+        assert_eq!(parse("PT0,000000001S"), Period::nanoseconds(1));
+    }
+
+    #[test]
+    fn test_parse_fraction_carries_into_smaller_units() {
+        assert_eq!(parse("P1.5D"), Period::days(1).with_hours(12));
+        assert_eq!(parse("P0.5W"), Period::days(3).with_hours(12));
+        assert_eq!(parse("PT0.01H"), Period::seconds(36));
+        assert_eq!(parse("P1.5Y"), Period::years(1).with_months(6));
+    }
+
+    #[test]
+    fn test_parse_fraction_of_a_month_is_rejected() {
+        assert_eq!(
+            parse_err("P1.5M"),
+            (1, "a fraction of a month has no exact length")
+        );
+        assert_eq!(
+            parse_err("P0.1Y"),
+            (1, "a fraction of a month has no exact length")
+        );
+    }
+
+    #[test]
+    fn test_parse_fraction_only_on_last_component() {
+        assert_eq!(
+            parse_err("P1.5DT2H"),
+            (2, "only the last component may have a fraction")
+        );
+    }
+
+    #[test]
+    fn test_parse_negative_forms() {
+        assert_eq!(parse("-P1DT2H"), Period::days(-1).with_hours(-2));
+        assert_eq!(parse("+P1D"), Period::days(1));
+        assert_eq!(parse("P1M-3D"), Period::months(1).with_days(-3));
+        assert_eq!(parse("-P-1D"), Period::days(1));
+        assert_eq!(
+            parse("-PT1.5S"),
+            Period::seconds(-1).with_nanoseconds(-500_000_000)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_report_position() {
+        assert_eq!(parse_err(""), (0, "expected 'P'"));
+        assert_eq!(parse_err("1D"), (0, "expected 'P'"));
+        assert_eq!(parse_err("P"), (1, "expected at least one component"));
+        assert_eq!(parse_err("PT"), (2, "expected a time component after 'T'"));
+        assert_eq!(
+            parse_err("P1DT"),
+            (4, "expected a time component after 'T'")
+        );
+        assert_eq!(parse_err("PD"), (1, "expected a number"));
+        assert_eq!(parse_err("P1"), (2, "expected 'Y', 'M', 'W' or 'D'"));
+        assert_eq!(parse_err("P1H"), (2, "expected 'Y', 'M', 'W' or 'D'"));
+        assert_eq!(parse_err("PT1D"), (3, "expected 'H', 'M' or 'S'"));
+        assert_eq!(
+            parse_err("P1.D"),
+            (3, "expected a digit after the decimal separator")
+        );
+        assert_eq!(parse_err("PT1HT1M"), (4, "'T' may only appear once"));
+        assert_eq!(parse_err("p1d"), (0, "expected 'P'"));
+    }
+
+    #[test]
+    fn test_parse_rejects_out_of_order_or_repeated_components() {
+        assert_eq!(
+            parse_err("P1D2Y"),
+            (4, "components are out of order or repeated")
+        );
+        assert_eq!(
+            parse_err("P1D1D"),
+            (4, "components are out of order or repeated")
+        );
+    }
+
+    #[test]
+    fn test_parse_overflow_is_an_error() {
+        assert_eq!(
+            parse_err("P99999999999999999999D"),
+            (1, "value is too large")
+        );
+        assert_eq!(
+            parse_err("P9223372036854775808D"),
+            (1, "value is too large")
+        );
+        assert_eq!(parse("P9223372036854775807D"), Period::days(i64::MAX));
+        assert_eq!(parse("-P9223372036854775808D"), Period::days(i64::MIN));
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        assert_eq!(
+            Period::parse_iso8601("P1X").unwrap_err().to_string(),
+            "invalid ISO 8601 duration at position 2: expected 'Y', 'M', 'W' or 'D'"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("P3D".parse::<Period>().unwrap(), Period::days(3));
+        assert!("3 days".parse::<Period>().is_err());
+    }
+
+    // -- formatting -----------------------------------------------------------
+
+    #[test]
+    fn test_display_full_form() {
+        let p = Period::years(1)
+            .with_months(2)
+            .with_days(3)
+            .with_hours(4)
+            .with_minutes(5)
+            .with_seconds(6);
+        assert_eq!(p.to_string(), "P1Y2M3DT4H5M6S");
+    }
+
+    #[test]
+    fn test_display_zero() {
+        assert_eq!(Period::ZERO.to_string(), "PT0S");
+        assert_eq!(
+            Period::seconds(1)
+                .with_nanoseconds(-1_000_000_000)
+                .to_string(),
+            "PT0S"
+        );
+    }
+
+    #[test]
+    fn test_display_weeks_and_time_only() {
+        assert_eq!(Period::weeks(2).to_string(), "P2W");
+        assert_eq!(Period::minutes(90).to_string(), "PT90M");
+    }
+
+    #[test]
+    fn test_display_fractional_seconds() {
+        assert_eq!(
+            Period::seconds(1).with_nanoseconds(500_000_000).to_string(),
+            "PT1.5S"
+        );
+        assert_eq!(Period::nanoseconds(1).to_string(), "PT0.000000001S");
+        assert_eq!(Period::nanoseconds(-250_000_000).to_string(), "-PT0.25S");
+    }
+
+    #[test]
+    fn test_display_signs() {
+        assert_eq!(Period::days(-1).with_hours(-2).to_string(), "-P1DT2H");
+        assert_eq!(Period::months(1).with_days(-3).to_string(), "P1M-3D");
+        assert_eq!(Period::days(i64::MIN).to_string(), "-P9223372036854775808D");
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        let periods = [
+            Period::years(1).with_weeks(2).with_nanoseconds(7),
+            Period::months(-1).with_days(3).with_seconds(-4),
+            Period::seconds(2).with_nanoseconds(500_000_000),
+            Period::days(i64::MIN),
+            Period::days(i64::MAX).with_hours(i64::MIN),
+            Period::days(-1).with_hours(-2),
+        ];
+        for p in periods {
+            assert_eq!(parse(&p.to_string()), p, "{p}");
+        }
+    }
+
+    // -- feeding relative functions -------------------------------------------
+
+    #[test]
+    fn test_parsed_period_ago_with_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let result = parse("PT1H30M").ago_with(&clock).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), clock.now() - Duration::minutes(90));
+    }
+
+    #[test]
+    fn test_parsed_period_from_now_in_utc() {
+        let lower = Utc::now() + Duration::milliseconds(1_500);
+        let result = parse("PT1.5S").from_now_in(&Utc).unwrap().as_datetime();
+        let upper = Utc::now() + Duration::milliseconds(1_500);
+        assert!(result >= lower && result <= upper);
+    }
+
+    #[test]
+    fn test_parsed_period_add_to_date() {
+        let start = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap();
+        assert_eq!(
+            parse("P1M1D").add_to(start).unwrap().as_date(),
+            NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()
+        );
+    }
+}
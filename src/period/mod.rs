@@ -0,0 +1,1013 @@
+mod iso8601;
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use chrono::{DateTime, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+
+use crate::clock::{Clock, SystemClock};
+use crate::error::PeriodError;
+use crate::now::{now_in, now_with};
+use crate::relative::Anchor;
+use crate::relative::anchor::private::Sealed;
+use crate::relative::functions::{
+    days_after, days_before, hours_after, hours_before, minutes_after, minutes_before,
+    months_after, months_before, seconds_after, seconds_before, weeks_after, weeks_before,
+    years_after, years_before,
+};
+use crate::relative::types::Relative;
+
+/// A calendar-aware amount of time, such as "1 year, 2 months and 3 days".
+///
+/// Unlike [`chrono::Duration`], a `Period` keeps each unit separately, so
+/// "1 month" stays one month instead of being fixed to a number of seconds.
+/// Its exact length depends on where it is applied: see
+/// [`add_to`](Period::add_to) and [`to_duration`](Period::to_duration).
+///
+/// Components are signed and independent; `Period::months(1).with_days(-3)`
+/// is a valid value.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use period::Period;
+///
+/// # fn main() -> Result<(), period::PeriodError> {
+/// let p = Period::years(1).with_months(2).with_days(3);
+/// let start = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap();
+/// assert_eq!(
+///     p.add_to(start)?.as_date(),
+///     NaiveDate::from_ymd_opt(2027, 4, 3).unwrap()
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Period {
+    years: i64,
+    months: i64,
+    weeks: i64,
+    days: i64,
+    hours: i64,
+    minutes: i64,
+    seconds: i64,
+    nanoseconds: i64,
+}
+
+impl Period {
+    /// A period with every component zero.
+    pub const ZERO: Period = Period {
+        years: 0,
+        months: 0,
+        weeks: 0,
+        days: 0,
+        hours: 0,
+        minutes: 0,
+        seconds: 0,
+        nanoseconds: 0,
+    };
+
+    /// A period of `years` calendar years.
+    #[must_use]
+    #[inline]
+    pub const fn years(years: i64) -> Period {
+        Period {
+            years,
+            ..Period::ZERO
+        }
+    }
+
+    /// A period of `months` calendar months.
+    #[must_use]
+    #[inline]
+    pub const fn months(months: i64) -> Period {
+        Period {
+            months,
+            ..Period::ZERO
+        }
+    }
+
+    /// A period of `weeks` weeks.
+    #[must_use]
+    #[inline]
+    pub const fn weeks(weeks: i64) -> Period {
+        Period {
+            weeks,
+            ..Period::ZERO
+        }
+    }
+
+    /// A period of `days` days.
+    #[must_use]
+    #[inline]
+    pub const fn days(days: i64) -> Period {
+        Period {
+            days,
+            ..Period::ZERO
+        }
+    }
+
+    /// A period of `hours` hours.
+    #[must_use]
+    #[inline]
+    pub const fn hours(hours: i64) -> Period {
+        Period {
+            hours,
+            ..Period::ZERO
+        }
+    }
+
+    /// A period of `minutes` minutes.
+    #[must_use]
+    #[inline]
+    pub const fn minutes(minutes: i64) -> Period {
+        Period {
+            minutes,
+            ..Period::ZERO
+        }
+    }
+
+    /// A period of `seconds` seconds.
+    #[must_use]
+    #[inline]
+    pub const fn seconds(seconds: i64) -> Period {
+        Period {
+            seconds,
+            ..Period::ZERO
+        }
+    }
+
+    /// A period of `nanoseconds` nanoseconds.
+    #[must_use]
+    #[inline]
+    pub const fn nanoseconds(nanoseconds: i64) -> Period {
+        Period {
+            nanoseconds,
+            ..Period::ZERO
+        }
+    }
+
+    /// This period with the years component replaced by `years`.
+    #[must_use]
+    #[inline]
+    pub const fn with_years(self, years: i64) -> Period {
+        Period { years, ..self }
+    }
+
+    /// This period with the months component replaced by `months`.
+    #[must_use]
+    #[inline]
+    pub const fn with_months(self, months: i64) -> Period {
+        Period { months, ..self }
+    }
+
+    /// This period with the weeks component replaced by `weeks`.
+    #[must_use]
+    #[inline]
+    pub const fn with_weeks(self, weeks: i64) -> Period {
+        Period { weeks, ..self }
+    }
+
+    /// This period with the days component replaced by `days`.
+    #[must_use]
+    #[inline]
+    pub const fn with_days(self, days: i64) -> Period {
+        Period { days, ..self }
+    }
+
+    /// This period with the hours component replaced by `hours`.
+    #[must_use]
+    #[inline]
+    pub const fn with_hours(self, hours: i64) -> Period {
+        Period { hours, ..self }
+    }
+
+    /// This period with the minutes component replaced by `minutes`.
+    #[must_use]
+    #[inline]
+    pub const fn with_minutes(self, minutes: i64) -> Period {
+        Period { minutes, ..self }
+    }
+
+    /// This period with the seconds component replaced by `seconds`.
+    #[must_use]
+    #[inline]
+    pub const fn with_seconds(self, seconds: i64) -> Period {
+        Period { seconds, ..self }
+    }
+
+    /// This period with the nanoseconds component replaced by `nanoseconds`.
+    #[must_use]
+    #[inline]
+    pub const fn with_nanoseconds(self, nanoseconds: i64) -> Period {
+        Period {
+            nanoseconds,
+            ..self
+        }
+    }
+
+    /// The years component.
+    #[must_use]
+    #[inline]
+    pub const fn num_years(&self) -> i64 {
+        self.years
+    }
+
+    /// The months component (not including years).
+    #[must_use]
+    #[inline]
+    pub const fn num_months(&self) -> i64 {
+        self.months
+    }
+
+    /// The weeks component.
+    #[must_use]
+    #[inline]
+    pub const fn num_weeks(&self) -> i64 {
+        self.weeks
+    }
+
+    /// The days component (not including weeks).
+    #[must_use]
+    #[inline]
+    pub const fn num_days(&self) -> i64 {
+        self.days
+    }
+
+    /// The hours component.
+    #[must_use]
+    #[inline]
+    pub const fn num_hours(&self) -> i64 {
+        self.hours
+    }
+
+    /// The minutes component.
+    #[must_use]
+    #[inline]
+    pub const fn num_minutes(&self) -> i64 {
+        self.minutes
+    }
+
+    /// The seconds component.
+    #[must_use]
+    #[inline]
+    pub const fn num_seconds(&self) -> i64 {
+        self.seconds
+    }
+
+    /// The nanoseconds component (not including seconds).
+    #[must_use]
+    #[inline]
+    pub const fn num_nanoseconds(&self) -> i64 {
+        self.nanoseconds
+    }
+
+    /// Returns `true` if every component is zero.
+    #[must_use]
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        *self == Period::ZERO
+    }
+
+    /// Returns an equivalent period with each component carried into the
+    /// next larger unit.
+    ///
+    /// Months carry into years (12 months = 1 year). Nanoseconds, seconds,
+    /// minutes, hours and weeks are folded into days using their exact lengths
+    /// (10^9, 60, 60, 24 and 7), which matches how [`add_to`](Period::add_to)
+    /// applies them. The calendar part (years, months) and the exact part
+    /// (days and smaller) are never mixed, since a month has no fixed number
+    /// of days. Within each part every component takes the sign of the part's
+    /// total.
+    ///
+    /// Returns `None` if a total does not fit in an `i64`.
+    #[must_use]
+    pub fn checked_normalized(&self) -> Option<Period> {
+        let total_months = self.years.checked_mul(12)?.checked_add(self.months)?;
+        let total_nanos = ((((i128::from(self.weeks) * 7 + i128::from(self.days)) * 24
+            + i128::from(self.hours))
+            * 60
+            + i128::from(self.minutes))
+            * 60
+            + i128::from(self.seconds))
+            * NANOS_PER_SECOND
+            + i128::from(self.nanoseconds);
+        let total_seconds = total_nanos / NANOS_PER_SECOND;
+        Some(Period {
+            years: total_months / 12,
+            months: total_months % 12,
+            weeks: 0,
+            days: i64::try_from(total_seconds / 86_400).ok()?,
+            hours: i64::try_from(total_seconds % 86_400 / 3_600).ok()?,
+            minutes: i64::try_from(total_seconds % 3_600 / 60).ok()?,
+            seconds: i64::try_from(total_seconds % 60).ok()?,
+            nanoseconds: i64::try_from(total_nanos % NANOS_PER_SECOND).ok()?,
+        })
+    }
+
+    /// Like [`checked_normalized`](Period::checked_normalized).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a total does not fit in an `i64`.
+    #[must_use]
+    pub fn normalized(&self) -> Period {
+        self.checked_normalized().expect("period overflow")
+    }
+
+    /// Adds two periods component by component. Returns `None` on overflow.
+    #[must_use]
+    pub fn checked_add(&self, rhs: &Period) -> Option<Period> {
+        Some(Period {
+            years: self.years.checked_add(rhs.years)?,
+            months: self.months.checked_add(rhs.months)?,
+            weeks: self.weeks.checked_add(rhs.weeks)?,
+            days: self.days.checked_add(rhs.days)?,
+            hours: self.hours.checked_add(rhs.hours)?,
+            minutes: self.minutes.checked_add(rhs.minutes)?,
+            seconds: self.seconds.checked_add(rhs.seconds)?,
+            nanoseconds: self.nanoseconds.checked_add(rhs.nanoseconds)?,
+        })
+    }
+
+    /// Subtracts two periods component by component. Returns `None` on overflow.
+    #[must_use]
+    pub fn checked_sub(&self, rhs: &Period) -> Option<Period> {
+        self.checked_add(&rhs.checked_neg()?)
+    }
+
+    /// Negates every component. Returns `None` on overflow.
+    #[must_use]
+    pub fn checked_neg(&self) -> Option<Period> {
+        self.checked_mul(-1)
+    }
+
+    /// Multiplies every component by `factor`. Returns `None` on overflow.
+    #[must_use]
+    pub fn checked_mul(&self, factor: i64) -> Option<Period> {
+        Some(Period {
+            years: self.years.checked_mul(factor)?,
+            months: self.months.checked_mul(factor)?,
+            weeks: self.weeks.checked_mul(factor)?,
+            days: self.days.checked_mul(factor)?,
+            hours: self.hours.checked_mul(factor)?,
+            minutes: self.minutes.checked_mul(factor)?,
+            seconds: self.seconds.checked_mul(factor)?,
+            nanoseconds: self.nanoseconds.checked_mul(factor)?,
+        })
+    }
+
+    /// Returns the moment this period after `anchor`.
+    ///
+    /// Components are applied one at a time in the same order as
+    /// [`RelativeBuilder`](crate::RelativeBuilder): years, months, weeks,
+    /// days, hours, minutes, seconds, nanoseconds. Negative components move
+    /// backwards.
+    /// See [`Anchor`] for how each kind of anchor is interpreted; with a
+    /// [`NaiveDate`] or [`NaiveDateTime`] anchor every component is applied to
+    /// the wall clock before the result is resolved, so days stay calendar
+    /// days even when the period spans a DST transition.
+    ///
+    /// # Errors
+    /// Returns [`PeriodError::Overflow`] if the resulting date-time is out of
+    /// range; `unit` names the component that overflowed.
+    pub fn add_to<A: Anchor>(&self, anchor: A) -> Result<Relative<A::Tz>, PeriodError> {
+        anchor.add_period(self)
+    }
+
+    /// Returns the moment this period before `anchor`.
+    ///
+    /// Components are applied in the same order as [`add_to`](Period::add_to).
+    ///
+    /// # Errors
+    /// Returns [`PeriodError::Overflow`] if the resulting date-time is out of
+    /// range; `unit` names the component that overflowed.
+    pub fn sub_from<A: Anchor>(&self, anchor: A) -> Result<Relative<A::Tz>, PeriodError> {
+        anchor.sub_period(self)
+    }
+
+    /// Returns the moment this period before the current local date-time.
+    ///
+    /// Together with [`parse_iso8601`](Period::parse_iso8601) this turns a
+    /// duration string straight into a point in time:
+    /// `"P3DT4H".parse::<Period>()?.ago()?`.
+    ///
+    /// # Errors
+    /// Same as [`sub_from`](Period::sub_from).
+    #[inline]
+    pub fn ago(&self) -> Result<Relative, PeriodError> {
+        self.ago_with(&SystemClock)
+    }
+
+    /// Like [`ago`](Period::ago), but measured from the instant reported by `clock`.
+    ///
+    /// # Errors
+    /// Same as [`sub_from`](Period::sub_from).
+    #[inline]
+    pub fn ago_with(&self, clock: &(impl Clock + ?Sized)) -> Result<Relative, PeriodError> {
+        self.sub_from(now_with(clock))
+    }
+
+    /// Like [`ago`](Period::ago), but read in the timezone `tz` instead of [`chrono::Local`].
+    ///
+    /// # Errors
+    /// Same as [`sub_from`](Period::sub_from).
+    #[inline]
+    pub fn ago_in<Tz: TimeZone>(&self, tz: &Tz) -> Result<Relative<Tz>, PeriodError> {
+        self.sub_from(now_in(tz))
+    }
+
+    /// Returns the moment this period after the current local date-time.
+    ///
+    /// # Errors
+    /// Same as [`add_to`](Period::add_to).
+    #[inline]
+    pub fn from_now(&self) -> Result<Relative, PeriodError> {
+        self.from_now_with(&SystemClock)
+    }
+
+    /// Like [`from_now`](Period::from_now), but measured from the instant reported by `clock`.
+    ///
+    /// # Errors
+    /// Same as [`add_to`](Period::add_to).
+    #[inline]
+    pub fn from_now_with(&self, clock: &(impl Clock + ?Sized)) -> Result<Relative, PeriodError> {
+        self.add_to(now_with(clock))
+    }
+
+    /// Like [`from_now`](Period::from_now), but read in the timezone `tz` instead of [`chrono::Local`].
+    ///
+    /// # Errors
+    /// Same as [`add_to`](Period::add_to).
+    #[inline]
+    pub fn from_now_in<Tz: TimeZone>(&self, tz: &Tz) -> Result<Relative<Tz>, PeriodError> {
+        self.add_to(now_in(tz))
+    }
+
+    /// The exact length of this period when applied to the calendar date
+    /// `reference` (from midnight, with no timezone or DST involved).
+    ///
+    /// `Period::months(1).to_duration(jan_1)` is 31 days, while the same
+    /// period from February 1 is 28 or 29 days.
+    ///
+    /// # Errors
+    /// Returns [`PeriodError::Overflow`] if the period cannot be applied to
+    /// `reference` without leaving chrono's supported range.
+    pub fn to_duration(&self, reference: NaiveDate) -> Result<Duration, PeriodError> {
+        self.to_duration_at(&reference.and_time(NaiveTime::MIN).and_utc())
+    }
+
+    /// The exact length of this period when applied to `reference`, with
+    /// calendar units resolved in `reference`'s timezone.
+    ///
+    /// # Errors
+    /// Returns [`PeriodError::Overflow`] if the period cannot be applied to
+    /// `reference` without leaving chrono's supported range.
+    pub fn to_duration_at<Tz: TimeZone>(
+        &self,
+        reference: &DateTime<Tz>,
+    ) -> Result<Duration, PeriodError> {
+        let end = self.add_to(reference.clone())?.as_datetime();
+        Ok(end.signed_duration_since(reference))
+    }
+
+    /// [`add_to`](Period::add_to) for zoned anchors: each component goes
+    /// through the matching anchor-based function, such as [`days_after`].
+    pub(crate) fn add_exact<A: Anchor>(&self, anchor: A) -> Result<Relative<A::Tz>, PeriodError> {
+        let r = shift(anchor, self.years, "years", years_after, years_before)?;
+        let r = shift(r, self.months, "months", months_after, months_before)?;
+        let r = shift(r, self.weeks, "weeks", weeks_after, weeks_before)?;
+        let r = shift(r, self.days, "days", days_after, days_before)?;
+        let r = shift(r, self.hours, "hours", hours_after, hours_before)?;
+        let r = shift(r, self.minutes, "minutes", minutes_after, minutes_before)?;
+        let r = shift(r, self.seconds, "seconds", seconds_after, seconds_before)?;
+        shift(
+            r,
+            self.nanoseconds,
+            "nanoseconds",
+            nanoseconds_after,
+            nanoseconds_before,
+        )
+    }
+
+    /// [`sub_from`](Period::sub_from) for zoned anchors.
+    pub(crate) fn sub_exact<A: Anchor>(&self, anchor: A) -> Result<Relative<A::Tz>, PeriodError> {
+        let r = shift(anchor, self.years, "years", years_before, years_after)?;
+        let r = shift(r, self.months, "months", months_before, months_after)?;
+        let r = shift(r, self.weeks, "weeks", weeks_before, weeks_after)?;
+        let r = shift(r, self.days, "days", days_before, days_after)?;
+        let r = shift(r, self.hours, "hours", hours_before, hours_after)?;
+        let r = shift(r, self.minutes, "minutes", minutes_before, minutes_after)?;
+        let r = shift(r, self.seconds, "seconds", seconds_before, seconds_after)?;
+        shift(
+            r,
+            self.nanoseconds,
+            "nanoseconds",
+            nanoseconds_before,
+            nanoseconds_after,
+        )
+    }
+
+    /// [`add_to`](Period::add_to) for naive anchors: every component is
+    /// applied to the wall-clock time, which the anchor resolves once at the
+    /// end.
+    pub(crate) fn add_wall(&self, wall: NaiveDateTime) -> Result<NaiveDateTime, PeriodError> {
+        self.shift_wall(wall, false)
+    }
+
+    /// [`sub_from`](Period::sub_from) for naive anchors.
+    pub(crate) fn sub_wall(&self, wall: NaiveDateTime) -> Result<NaiveDateTime, PeriodError> {
+        self.shift_wall(wall, true)
+    }
+
+    fn shift_wall(
+        &self,
+        wall: NaiveDateTime,
+        backward: bool,
+    ) -> Result<NaiveDateTime, PeriodError> {
+        let wall = step_wall(wall, self.years, "years", backward, |w, n| {
+            shift_months(w, n.checked_mul(12)?)
+        })?;
+        let wall = step_wall(wall, self.months, "months", backward, shift_months)?;
+        let wall = step_wall(wall, self.weeks, "weeks", backward, |w, n| {
+            w.checked_add_signed(Duration::try_weeks(n)?)
+        })?;
+        let wall = step_wall(wall, self.days, "days", backward, |w, n| {
+            w.checked_add_signed(Duration::try_days(n)?)
+        })?;
+        let wall = step_wall(wall, self.hours, "hours", backward, |w, n| {
+            w.checked_add_signed(Duration::try_hours(n)?)
+        })?;
+        let wall = step_wall(wall, self.minutes, "minutes", backward, |w, n| {
+            w.checked_add_signed(Duration::try_minutes(n)?)
+        })?;
+        let wall = step_wall(wall, self.seconds, "seconds", backward, |w, n| {
+            w.checked_add_signed(Duration::try_seconds(n)?)
+        })?;
+        step_wall(wall, self.nanoseconds, "nanoseconds", backward, |w, n| {
+            w.checked_add_signed(Duration::nanoseconds(n))
+        })
+    }
+
+    /// The error for a result outside chrono's range, naming the smallest
+    /// non-zero component.
+    pub(crate) fn out_of_range(&self) -> PeriodError {
+        let components = [
+            ("years", self.years),
+            ("months", self.months),
+            ("weeks", self.weeks),
+            ("days", self.days),
+            ("hours", self.hours),
+            ("minutes", self.minutes),
+            ("seconds", self.seconds),
+            ("nanoseconds", self.nanoseconds),
+        ];
+        let (unit, value) = components
+            .into_iter()
+            .rev()
+            .find(|&(_, value)| value != 0)
+            .unwrap_or(("seconds", 0));
+        PeriodError::Overflow {
+            unit,
+            value: value.checked_abs().unwrap_or(value),
+        }
+    }
+}
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// One of the anchor-based relative functions, such as [`days_after`].
+type Step<A> = fn(A, i64) -> Result<Relative<<A as Sealed>::Tz>, PeriodError>;
+
+/// One step of [`Period::shift_wall`], taking a signed amount.
+type WallStep = fn(NaiveDateTime, i64) -> Option<NaiveDateTime>;
+
+/// Applies one signed component to a wall-clock time, negated when going
+/// `backward`. Errors match [`shift`]: the magnitude of the component is
+/// reported, or the component itself if it has none.
+fn step_wall(
+    wall: NaiveDateTime,
+    value: i64,
+    unit: &'static str,
+    backward: bool,
+    apply: WallStep,
+) -> Result<NaiveDateTime, PeriodError> {
+    if value == 0 {
+        return Ok(wall);
+    }
+    let signed = if backward {
+        value.checked_neg()
+    } else {
+        Some(value)
+    };
+    signed
+        .and_then(|n| apply(wall, n))
+        .ok_or(PeriodError::Overflow {
+            unit,
+            value: value.checked_abs().unwrap_or(value),
+        })
+}
+
+fn shift_months(wall: NaiveDateTime, months: i64) -> Option<NaiveDateTime> {
+    let magnitude = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+    if months >= 0 {
+        wall.checked_add_months(magnitude)
+    } else {
+        wall.checked_sub_months(magnitude)
+    }
+}
+
+/// Applies one signed component: non-negative amounts go through `forward`,
+/// negative amounts through `backward` with the magnitude.
+fn shift<A: Anchor>(
+    anchor: A,
+    value: i64,
+    unit: &'static str,
+    forward: Step<A>,
+    backward: Step<A>,
+) -> Result<Relative<A::Tz>, PeriodError> {
+    if value >= 0 {
+        forward(anchor, value)
+    } else {
+        let magnitude = value
+            .checked_neg()
+            .ok_or(PeriodError::Overflow { unit, value })?;
+        backward(anchor, magnitude)
+    }
+}
+
+/// The nanosecond step for [`shift`]. There is no public `nanoseconds_after`,
+/// since sub-second offsets only arise from periods.
+fn nanoseconds_after<A: Anchor>(
+    anchor: A,
+    nanoseconds: i64,
+) -> Result<Relative<A::Tz>, PeriodError> {
+    anchor
+        .add_duration(Duration::nanoseconds(nanoseconds))
+        .ok_or(PeriodError::Overflow {
+            unit: "nanoseconds",
+            value: nanoseconds,
+        })
+}
+
+fn nanoseconds_before<A: Anchor>(
+    anchor: A,
+    nanoseconds: i64,
+) -> Result<Relative<A::Tz>, PeriodError> {
+    anchor
+        .sub_duration(Duration::nanoseconds(nanoseconds))
+        .ok_or(PeriodError::Overflow {
+            unit: "nanoseconds",
+            value: nanoseconds,
+        })
+}
+
+impl Add for Period {
+    type Output = Period;
+
+    fn add(self, rhs: Period) -> Period {
+        self.checked_add(&rhs).expect("period overflow")
+    }
+}
+
+impl Sub for Period {
+    type Output = Period;
+
+    fn sub(self, rhs: Period) -> Period {
+        self.checked_sub(&rhs).expect("period overflow")
+    }
+}
+
+impl Neg for Period {
+    type Output = Period;
+
+    fn neg(self) -> Period {
+        self.checked_neg().expect("period overflow")
+    }
+}
+
+impl Mul<i64> for Period {
+    type Output = Period;
+
+    fn mul(self, rhs: i64) -> Period {
+        self.checked_mul(rhs).expect("period overflow")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use chrono::{FixedOffset, Local, Utc};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    // -- construction ---------------------------------------------------------
+
+    #[test]
+    fn test_constructors_set_single_component() {
+        let p = Period::years(1)
+            .with_months(2)
+            .with_weeks(3)
+            .with_days(4)
+            .with_hours(5)
+            .with_minutes(6)
+            .with_seconds(7);
+        assert_eq!(
+            (
+                p.num_years(),
+                p.num_months(),
+                p.num_weeks(),
+                p.num_days(),
+                p.num_hours(),
+                p.num_minutes(),
+                p.num_seconds()
+            ),
+            (1, 2, 3, 4, 5, 6, 7)
+        );
+    }
+
+    #[test]
+    fn test_default_is_zero() {
+        assert_eq!(Period::default(), Period::ZERO);
+        assert!(Period::ZERO.is_zero());
+        assert!(!Period::seconds(1).is_zero());
+    }
+
+    // -- normalization --------------------------------------------------------
+
+    #[test]
+    fn test_normalized_carries_months_into_years() {
+        assert_eq!(
+            Period::months(26).normalized(),
+            Period::years(2).with_months(2)
+        );
+    }
+
+    #[test]
+    fn test_normalized_carries_exact_units_into_days() {
+        let p = Period::weeks(1)
+            .with_hours(25)
+            .with_minutes(61)
+            .with_seconds(61);
+        assert_eq!(
+            p.normalized(),
+            Period::days(8)
+                .with_hours(2)
+                .with_minutes(2)
+                .with_seconds(1)
+        );
+    }
+
+    #[test]
+    fn test_normalized_does_not_mix_months_and_days() {
+        let p = Period::months(1).with_days(45);
+        assert_eq!(p.normalized(), p);
+    }
+
+    #[test]
+    fn test_normalized_mixed_signs_take_sign_of_total() {
+        let p = Period::years(1)
+            .with_months(-1)
+            .with_hours(1)
+            .with_minutes(-90);
+        assert_eq!(p.normalized(), Period::months(11).with_minutes(-30));
+    }
+
+    #[test]
+    fn test_normalized_carries_nanoseconds_into_seconds() {
+        let p = Period::seconds(1).with_nanoseconds(-1_500_000_000);
+        assert_eq!(p.normalized(), Period::nanoseconds(-500_000_000));
+    }
+
+    #[test]
+    fn test_checked_normalized_overflow_is_none() {
+        assert!(Period::years(i64::MAX).checked_normalized().is_none());
+        assert!(Period::weeks(i64::MAX).checked_normalized().is_none());
+    }
+
+    // -- arithmetic -----------------------------------------------------------
+
+    #[test]
+    fn test_add_and_sub_are_component_wise() {
+        let a = Period::years(1).with_days(3);
+        let b = Period::months(2).with_days(-1);
+        assert_eq!(a + b, Period::years(1).with_months(2).with_days(2));
+        assert_eq!(a - b, Period::years(1).with_months(-2).with_days(4));
+    }
+
+    #[test]
+    fn test_neg_flips_every_component() {
+        let p = Period::years(1).with_months(-2).with_seconds(3);
+        assert_eq!(-p, Period::years(-1).with_months(2).with_seconds(-3));
+        assert_eq!(-(-p), p);
+    }
+
+    #[test]
+    fn test_mul_scales_every_component() {
+        let p = Period::months(1).with_days(2);
+        assert_eq!(p * 3, Period::months(3).with_days(6));
+        assert_eq!(p * -1, -p);
+    }
+
+    #[test]
+    fn test_checked_ops_overflow_is_none() {
+        assert!(
+            Period::days(i64::MAX)
+                .checked_add(&Period::days(1))
+                .is_none()
+        );
+        assert!(Period::days(i64::MIN).checked_neg().is_none());
+        assert!(Period::days(i64::MAX).checked_mul(2).is_none());
+        assert!(
+            Period::days(i64::MIN)
+                .checked_sub(&Period::days(1))
+                .is_none()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "period overflow")]
+    fn test_add_operator_panics_on_overflow() {
+        let _ = Period::days(i64::MAX) + Period::days(1);
+    }
+
+    // -- application ----------------------------------------------------------
+
+    #[test]
+    fn test_add_to_naive_date_applies_calendar_first() {
+        // Jan 31 + 1 month = Feb 28, + 1 day = Mar 1
+        let p = Period::months(1).with_days(1);
+        assert_eq!(
+            p.add_to(date(2026, 1, 31)).unwrap().as_date(),
+            date(2026, 3, 1)
+        );
+    }
+
+    #[test]
+    fn test_sub_from_naive_date() {
+        let p = Period::years(1).with_months(2).with_days(3);
+        assert_eq!(
+            p.sub_from(date(2026, 5, 10)).unwrap().as_date(),
+            date(2025, 3, 7)
+        );
+    }
+
+    #[test]
+    fn test_naive_anchor_applies_every_component_on_wall_clock() {
+        // Crosses the March DST change in many zones; the wall-clock result
+        // must not depend on it.
+        let p = Period::days(3).with_hours(1);
+        let r = p.sub_from(date(2026, 3, 10)).unwrap();
+        assert_eq!(r.as_date(), date(2026, 3, 6));
+        assert_eq!(r.as_time(), NaiveTime::from_hms_opt(23, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_add_to_negative_component_moves_backwards() {
+        let p = Period::months(1).with_days(-3);
+        assert_eq!(
+            p.add_to(date(2026, 2, 10)).unwrap().as_date(),
+            date(2026, 3, 7)
+        );
+    }
+
+    #[test]
+    fn test_add_to_datetime_keeps_timezone() {
+        let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+        let anchor = tz.with_ymd_and_hms(2026, 2, 22, 14, 30, 0).unwrap();
+        let r = Period::hours(2).with_minutes(15).add_to(anchor).unwrap();
+        assert_eq!(
+            r.as_datetime(),
+            tz.with_ymd_and_hms(2026, 2, 22, 16, 45, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_add_to_relative() {
+        let start = Period::ZERO.add_to(date(2026, 2, 22)).unwrap();
+        let r = Period::weeks(1).add_to(start).unwrap();
+        assert_eq!(r.as_date(), date(2026, 3, 1));
+    }
+
+    #[test]
+    fn test_add_then_sub_round_trips_exact_units() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let p = Period::days(3).with_hours(4).with_seconds(5);
+        let there = p.add_to(anchor).unwrap();
+        assert_eq!(p.sub_from(there).unwrap().as_datetime(), anchor);
+    }
+
+    #[test]
+    fn test_add_to_applies_nanoseconds() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let p = Period::seconds(1).with_nanoseconds(250_000_000);
+        assert_eq!(
+            p.add_to(anchor).unwrap().as_datetime(),
+            anchor + Duration::milliseconds(1_250)
+        );
+        assert_eq!(
+            p.sub_from(anchor).unwrap().as_datetime(),
+            anchor - Duration::milliseconds(1_250)
+        );
+    }
+
+    #[test]
+    fn test_ago_and_from_now_with_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let p = Period::months(1).with_hours(2);
+        assert_eq!(
+            p.ago_with(&clock).unwrap(),
+            p.sub_from(now_with(&clock)).unwrap()
+        );
+        assert_eq!(
+            p.from_now_with(&clock).unwrap(),
+            p.add_to(now_with(&clock)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ago_in_keeps_timezone() {
+        let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+        let r = Period::days(1).ago_in(&tz).unwrap();
+        assert_eq!(r.timezone(), tz);
+    }
+
+    #[test]
+    fn test_add_to_overflow_names_component() {
+        let err = Period::months(1)
+            .with_days(200_000_000)
+            .add_to(date(2026, 2, 22))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            PeriodError::Overflow {
+                unit: "days",
+                value: 200_000_000
+            }
+        );
+    }
+
+    #[test]
+    fn test_add_to_min_component_overflows() {
+        let err = Period::days(i64::MIN)
+            .add_to(date(2026, 2, 22))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            PeriodError::Overflow {
+                unit: "days",
+                value: i64::MIN
+            }
+        );
+    }
+
+    #[test]
+    fn test_add_to_local_matches_months_from_now_semantics() {
+        let anchor = Local::now();
+        assert_eq!(
+            Period::months(2).add_to(anchor).unwrap(),
+            crate::relative::functions::months_after(anchor, 2).unwrap()
+        );
+    }
+
+    // -- to_duration ----------------------------------------------------------
+
+    #[test]
+    fn test_to_duration_depends_on_reference_month() {
+        let p = Period::months(1);
+        assert_eq!(p.to_duration(date(2026, 1, 1)).unwrap(), Duration::days(31));
+        assert_eq!(p.to_duration(date(2026, 2, 1)).unwrap(), Duration::days(28));
+        assert_eq!(p.to_duration(date(2028, 2, 1)).unwrap(), Duration::days(29));
+    }
+
+    #[test]
+    fn test_to_duration_of_year_across_leap_day() {
+        let p = Period::years(1);
+        assert_eq!(
+            p.to_duration(date(2028, 1, 1)).unwrap(),
+            Duration::days(366)
+        );
+        assert_eq!(
+            p.to_duration(date(2026, 1, 1)).unwrap(),
+            Duration::days(365)
+        );
+    }
+
+    #[test]
+    fn test_to_duration_negative_period() {
+        assert_eq!(
+            Period::months(-1).to_duration(date(2026, 3, 1)).unwrap(),
+            Duration::days(-28)
+        );
+    }
+
+    #[test]
+    fn test_to_duration_at_exact_units() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let p = Period::days(1).with_hours(2);
+        assert_eq!(p.to_duration_at(&anchor).unwrap(), Duration::hours(26));
+    }
+}
@@ -0,0 +1,275 @@
+use chrono::offset::LocalResult;
+use chrono::{
+    DateTime, Duration, Local, Months, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+};
+
+use crate::error::PeriodError;
+use crate::period::Period;
+use crate::relative::types::Relative;
+
+/// A point in time that the anchor-based relative functions
+/// (`days_before`, `months_after`, …) can count from.
+///
+/// Implemented for:
+///
+/// - [`DateTime<Tz>`] for any timezone — exact units (seconds through weeks)
+///   are added as fixed durations, calendar units (months, years) are applied
+///   to the wall-clock date in the anchor's own timezone. The result is a
+///   `Relative<Tz>`.
+/// - [`NaiveDateTime`] and [`NaiveDate`] — interpreted as local wall-clock
+///   time (a date means local midnight). Arithmetic is done on the wall clock,
+///   so `days_before(date, 30)?.as_date()` is always exactly 30 calendar days
+///   earlier, even across a DST transition. The result is a `Relative<Local>`.
+/// - [`Relative<Tz>`] — so results can be chained. The result is a
+///   `Relative<Tz>`.
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait Anchor: private::Sealed {}
+
+impl<Tz: TimeZone> Anchor for DateTime<Tz> {}
+impl Anchor for NaiveDateTime {}
+impl Anchor for NaiveDate {}
+impl<Tz: TimeZone> Anchor for Relative<Tz> {}
+
+pub(crate) mod private {
+    use chrono::{Duration, Months, TimeZone};
+
+    use crate::error::PeriodError;
+    use crate::period::Period;
+    use crate::relative::types::Relative;
+
+    pub trait Sealed {
+        /// The timezone results computed from this anchor are read in.
+        type Tz: TimeZone;
+
+        fn add_duration(self, rhs: Duration) -> Option<Relative<Self::Tz>>;
+        fn sub_duration(self, rhs: Duration) -> Option<Relative<Self::Tz>>;
+        fn add_months(self, rhs: Months) -> Option<Relative<Self::Tz>>;
+        fn sub_months(self, rhs: Months) -> Option<Relative<Self::Tz>>;
+        fn add_period(self, rhs: &Period) -> Result<Relative<Self::Tz>, PeriodError>;
+        fn sub_period(self, rhs: &Period) -> Result<Relative<Self::Tz>, PeriodError>;
+    }
+}
+
+impl<Tz: TimeZone> private::Sealed for DateTime<Tz> {
+    type Tz = Tz;
+
+    fn add_duration(self, rhs: Duration) -> Option<Relative<Tz>> {
+        self.checked_add_signed(rhs).map(Relative)
+    }
+
+    fn sub_duration(self, rhs: Duration) -> Option<Relative<Tz>> {
+        self.checked_sub_signed(rhs).map(Relative)
+    }
+
+    fn add_months(self, rhs: Months) -> Option<Relative<Tz>> {
+        self.checked_add_months(rhs).map(Relative)
+    }
+
+    fn sub_months(self, rhs: Months) -> Option<Relative<Tz>> {
+        self.checked_sub_months(rhs).map(Relative)
+    }
+
+    fn add_period(self, rhs: &Period) -> Result<Relative<Tz>, PeriodError> {
+        rhs.add_exact(self)
+    }
+
+    fn sub_period(self, rhs: &Period) -> Result<Relative<Tz>, PeriodError> {
+        rhs.sub_exact(self)
+    }
+}
+
+impl private::Sealed for NaiveDateTime {
+    type Tz = Local;
+
+    fn add_duration(self, rhs: Duration) -> Option<Relative> {
+        self.checked_add_signed(rhs)
+            .and_then(|wall| localize(&Local, wall))
+            .map(Relative)
+    }
+
+    fn sub_duration(self, rhs: Duration) -> Option<Relative> {
+        self.checked_sub_signed(rhs)
+            .and_then(|wall| localize(&Local, wall))
+            .map(Relative)
+    }
+
+    fn add_months(self, rhs: Months) -> Option<Relative> {
+        self.checked_add_months(rhs)
+            .and_then(|wall| localize(&Local, wall))
+            .map(Relative)
+    }
+
+    fn sub_months(self, rhs: Months) -> Option<Relative> {
+        self.checked_sub_months(rhs)
+            .and_then(|wall| localize(&Local, wall))
+            .map(Relative)
+    }
+
+    fn add_period(self, rhs: &Period) -> Result<Relative, PeriodError> {
+        let wall = rhs.add_wall(self)?;
+        localize(&Local, wall)
+            .map(Relative)
+            .ok_or_else(|| rhs.out_of_range())
+    }
+
+    fn sub_period(self, rhs: &Period) -> Result<Relative, PeriodError> {
+        let wall = rhs.sub_wall(self)?;
+        localize(&Local, wall)
+            .map(Relative)
+            .ok_or_else(|| rhs.out_of_range())
+    }
+}
+
+impl private::Sealed for NaiveDate {
+    type Tz = Local;
+
+    fn add_duration(self, rhs: Duration) -> Option<Relative> {
+        self.and_time(NaiveTime::MIN).add_duration(rhs)
+    }
+
+    fn sub_duration(self, rhs: Duration) -> Option<Relative> {
+        self.and_time(NaiveTime::MIN).sub_duration(rhs)
+    }
+
+    fn add_months(self, rhs: Months) -> Option<Relative> {
+        self.and_time(NaiveTime::MIN).add_months(rhs)
+    }
+
+    fn sub_months(self, rhs: Months) -> Option<Relative> {
+        self.and_time(NaiveTime::MIN).sub_months(rhs)
+    }
+
+    fn add_period(self, rhs: &Period) -> Result<Relative, PeriodError> {
+        self.and_time(NaiveTime::MIN).add_period(rhs)
+    }
+
+    fn sub_period(self, rhs: &Period) -> Result<Relative, PeriodError> {
+        self.and_time(NaiveTime::MIN).sub_period(rhs)
+    }
+}
+
+impl<Tz: TimeZone> private::Sealed for Relative<Tz> {
+    type Tz = Tz;
+
+    fn add_duration(self, rhs: Duration) -> Option<Relative<Tz>> {
+        self.0.add_duration(rhs)
+    }
+
+    fn sub_duration(self, rhs: Duration) -> Option<Relative<Tz>> {
+        self.0.sub_duration(rhs)
+    }
+
+    fn add_months(self, rhs: Months) -> Option<Relative<Tz>> {
+        self.0.add_months(rhs)
+    }
+
+    fn sub_months(self, rhs: Months) -> Option<Relative<Tz>> {
+        self.0.sub_months(rhs)
+    }
+
+    fn add_period(self, rhs: &Period) -> Result<Relative<Tz>, PeriodError> {
+        self.0.add_period(rhs)
+    }
+
+    fn sub_period(self, rhs: &Period) -> Result<Relative<Tz>, PeriodError> {
+        self.0.sub_period(rhs)
+    }
+}
+
+/// Resolves a wall-clock time in `tz` to a single instant.
+///
+/// An ambiguous wall time (repeated by a backward DST transition) resolves to
+/// the earlier instant. A wall time skipped by a forward transition is read
+/// with the offset in effect before the gap, which lands the same distance
+/// past the transition (02:30 in a 02:00→03:00 gap becomes 03:30).
+pub(crate) fn localize<Tz: TimeZone>(tz: &Tz, wall: NaiveDateTime) -> Option<DateTime<Tz>> {
+    match tz.from_local_datetime(&wall) {
+        LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => Some(dt),
+        LocalResult::None => {
+            let before = wall.checked_sub_signed(Duration::days(1))?;
+            let offset = tz.offset_from_local_datetime(&before).earliest()?.fix();
+            let utc = wall.checked_sub_offset(offset)?;
+            Some(tz.from_utc_datetime(&utc))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::private::Sealed;
+    use super::*;
+    use chrono::{FixedOffset, Utc};
+
+    fn wall(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_localize_unambiguous_fixed_offset() {
+        let tz = FixedOffset::east_opt(3600).unwrap();
+        let dt = localize(&tz, wall(2026, 2, 22, 12, 0)).unwrap();
+        assert_eq!(dt.naive_local(), wall(2026, 2, 22, 12, 0));
+    }
+
+    #[test]
+    fn test_localize_utc_round_trips() {
+        let dt = localize(&Utc, wall(2026, 3, 29, 2, 30)).unwrap();
+        assert_eq!(dt.naive_utc(), wall(2026, 3, 29, 2, 30));
+    }
+
+    #[test]
+    fn test_datetime_anchor_keeps_instant_arithmetic() {
+        let tz = FixedOffset::west_opt(6 * 3600).unwrap();
+        let anchor = tz.with_ymd_and_hms(2026, 2, 22, 14, 30, 0).unwrap();
+        let r = anchor.sub_duration(Duration::hours(3)).unwrap();
+        assert_eq!(r.as_datetime(), anchor - Duration::hours(3));
+        assert_eq!(r.timezone(), tz);
+    }
+
+    #[test]
+    fn test_datetime_anchor_months_use_anchor_calendar() {
+        // 23:30 on Jan 31 at UTC-6 is Feb 1 in UTC; the month step must be
+        // taken from the anchor's own wall-clock date (Jan 31 -> Feb 28).
+        let tz = FixedOffset::west_opt(6 * 3600).unwrap();
+        let anchor = tz.with_ymd_and_hms(2026, 1, 31, 23, 30, 0).unwrap();
+        let r = anchor.add_months(Months::new(1)).unwrap();
+        assert_eq!(r.as_datetime().naive_local(), wall(2026, 2, 28, 23, 30));
+    }
+
+    #[test]
+    fn test_naive_date_anchor_is_local_midnight() {
+        let date = NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+        let r = date.add_duration(Duration::zero()).unwrap();
+        assert_eq!(r.as_date(), date);
+        assert_eq!(r.as_time(), NaiveTime::MIN);
+    }
+
+    #[test]
+    fn test_naive_date_anchor_days_are_calendar_days() {
+        let date = NaiveDate::from_ymd_opt(2026, 4, 15).unwrap();
+        let r = date.sub_duration(Duration::days(30)).unwrap();
+        assert_eq!(r.as_date(), NaiveDate::from_ymd_opt(2026, 3, 16).unwrap());
+        assert_eq!(r.as_time(), NaiveTime::MIN);
+    }
+
+    #[test]
+    fn test_relative_anchor_chains() {
+        let date = NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+        let first = date.sub_months(Months::new(1)).unwrap();
+        let second = first.sub_months(Months::new(1)).unwrap();
+        assert_eq!(
+            second.as_date(),
+            NaiveDate::from_ymd_opt(2025, 12, 22).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_naive_anchor_overflow_is_none() {
+        assert!(NaiveDate::MAX.add_duration(Duration::days(1)).is_none());
+        assert!(NaiveDate::MIN.sub_months(Months::new(1)).is_none());
+    }
+}
@@ -0,0 +1,395 @@
+use chrono::TimeZone;
+
+use crate::clock::{Clock, SystemClock};
+use crate::error::PeriodError;
+use crate::now::{now_in, now_with};
+use crate::period::Period;
+use crate::relative::anchor::Anchor;
+use crate::relative::types::Relative;
+
+/// Starts a compound offset into the past, e.g. `ago().weeks(1).days(2)`.
+///
+/// Finish with [`resolve`](RelativeBuilder::resolve) (or one of its
+/// `_with` / `_in` / `_from` variants) to get a [`Relative`].
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() -> Result<(), period::PeriodError> {
+/// let r = period::ago().weeks(1).days(2).hours(3).resolve()?;
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn ago() -> RelativeBuilder {
+    RelativeBuilder::new(Direction::Past)
+}
+
+/// Starts a compound offset into the future, e.g. `from_now().months(1).days(3)`.
+///
+/// Finish with [`resolve`](RelativeBuilder::resolve) (or one of its
+/// `_with` / `_in` / `_from` variants) to get a [`Relative`].
+#[inline]
+pub fn from_now() -> RelativeBuilder {
+    RelativeBuilder::new(Direction::Future)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Past,
+    Future,
+}
+
+const YEARS: usize = 0;
+const MONTHS: usize = 1;
+const WEEKS: usize = 2;
+const DAYS: usize = 3;
+const HOURS: usize = 4;
+const MINUTES: usize = 5;
+const SECONDS: usize = 6;
+
+const UNITS: [&str; 7] = [
+    "years", "months", "weeks", "days", "hours", "minutes", "seconds",
+];
+
+// Suggestions point at the builder for the opposite direction.
+const PAST_SUGGESTIONS: [&str; 7] = [
+    "from_now().years",
+    "from_now().months",
+    "from_now().weeks",
+    "from_now().days",
+    "from_now().hours",
+    "from_now().minutes",
+    "from_now().seconds",
+];
+
+const FUTURE_SUGGESTIONS: [&str; 7] = [
+    "ago().years",
+    "ago().months",
+    "ago().weeks",
+    "ago().days",
+    "ago().hours",
+    "ago().minutes",
+    "ago().seconds",
+];
+
+/// Accumulates a compound offset — years, months, weeks, days, hours,
+/// minutes and seconds — and resolves it into a [`Relative`].
+///
+/// Created by [`ago`] or [`from_now`]. Calling a unit method more than once
+/// adds to that unit (`.days(1).days(2)` is three days).
+///
+/// Components are applied one at a time in a fixed order: calendar units
+/// first (years, then months, using calendar arithmetic like
+/// [`years_ago`](crate::years_ago) and [`months_ago`](crate::months_ago)),
+/// then exact units (weeks, days, hours, minutes, seconds). So
+/// `from_now().months(1).days(1)` from January 31 lands on March 1
+/// (January 31 → February 28 → March 1).
+///
+/// Invalid input is recorded when it is added and reported by `resolve`:
+/// a negative amount yields [`PeriodError::NegativeValue`], and an amount or
+/// result that is out of range yields [`PeriodError::Overflow`] whose `unit`
+/// names the component that overflowed.
+#[derive(Debug, Clone, PartialEq)]
+#[must_use]
+pub struct RelativeBuilder {
+    direction: Direction,
+    amounts: [i64; 7],
+    error: Option<PeriodError>,
+}
+
+impl RelativeBuilder {
+    fn new(direction: Direction) -> Self {
+        RelativeBuilder {
+            direction,
+            amounts: [0; 7],
+            error: None,
+        }
+    }
+
+    fn accumulate(mut self, index: usize, value: i64) -> Self {
+        if self.error.is_some() {
+            return self;
+        }
+        if value < 0 {
+            let suggestions = match self.direction {
+                Direction::Past => &PAST_SUGGESTIONS,
+                Direction::Future => &FUTURE_SUGGESTIONS,
+            };
+            self.error = Some(PeriodError::NegativeValue {
+                unit: UNITS[index],
+                suggestion: suggestions[index],
+                value: value.unsigned_abs(),
+            });
+            return self;
+        }
+        match self.amounts[index].checked_add(value) {
+            Some(total) => self.amounts[index] = total,
+            None => {
+                self.error = Some(PeriodError::Overflow {
+                    unit: UNITS[index],
+                    value,
+                });
+            }
+        }
+        self
+    }
+
+    /// Adds `years` calendar years.
+    #[inline]
+    pub fn years(self, years: i64) -> Self {
+        self.accumulate(YEARS, years)
+    }
+
+    /// Adds `months` calendar months.
+    #[inline]
+    pub fn months(self, months: i64) -> Self {
+        self.accumulate(MONTHS, months)
+    }
+
+    /// Adds `weeks` weeks.
+    #[inline]
+    pub fn weeks(self, weeks: i64) -> Self {
+        self.accumulate(WEEKS, weeks)
+    }
+
+    /// Adds `days` days.
+    #[inline]
+    pub fn days(self, days: i64) -> Self {
+        self.accumulate(DAYS, days)
+    }
+
+    /// Adds `hours` hours.
+    #[inline]
+    pub fn hours(self, hours: i64) -> Self {
+        self.accumulate(HOURS, hours)
+    }
+
+    /// Adds `minutes` minutes.
+    #[inline]
+    pub fn minutes(self, minutes: i64) -> Self {
+        self.accumulate(MINUTES, minutes)
+    }
+
+    /// Adds `seconds` seconds.
+    #[inline]
+    pub fn seconds(self, seconds: i64) -> Self {
+        self.accumulate(SECONDS, seconds)
+    }
+
+    /// Resolves the offset relative to the current local date-time.
+    ///
+    /// # Errors
+    /// Returns [`PeriodError::NegativeValue`] if any component was given a negative amount.
+    /// Returns [`PeriodError::Overflow`] if any component, or the resulting date-time,
+    /// is out of range; `unit` names the component that overflowed.
+    #[inline]
+    pub fn resolve(self) -> Result<Relative, PeriodError> {
+        self.resolve_with(&SystemClock)
+    }
+
+    /// Like [`resolve`](Self::resolve), but measured from the instant reported by `clock`.
+    ///
+    /// # Errors
+    /// Same as [`resolve`](Self::resolve).
+    #[inline]
+    pub fn resolve_with(self, clock: &(impl Clock + ?Sized)) -> Result<Relative, PeriodError> {
+        self.resolve_from(now_with(clock))
+    }
+
+    /// Like [`resolve`](Self::resolve), but read in the timezone `tz` instead of [`chrono::Local`].
+    ///
+    /// # Errors
+    /// Same as [`resolve`](Self::resolve).
+    #[inline]
+    pub fn resolve_in<Tz: TimeZone>(self, tz: &Tz) -> Result<Relative<Tz>, PeriodError> {
+        self.resolve_from(now_in(tz))
+    }
+
+    /// Resolves the offset relative to `anchor` instead of the current instant.
+    ///
+    /// See [`Anchor`] for how each kind of anchor is interpreted.
+    ///
+    /// # Errors
+    /// Same as [`resolve`](Self::resolve).
+    pub fn resolve_from<A: Anchor>(self, anchor: A) -> Result<Relative<A::Tz>, PeriodError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        let [years, months, weeks, days, hours, minutes, seconds] = self.amounts;
+        let period = Period::years(years)
+            .with_months(months)
+            .with_weeks(weeks)
+            .with_days(days)
+            .with_hours(hours)
+            .with_minutes(minutes)
+            .with_seconds(seconds);
+        match self.direction {
+            Direction::Past => period.sub_from(anchor),
+            Direction::Future => period.add_to(anchor),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use chrono::{Duration, Local, NaiveDate, TimeZone, Utc};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_ago_combines_exact_units() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let r = ago()
+            .weeks(1)
+            .days(2)
+            .hours(3)
+            .minutes(4)
+            .seconds(5)
+            .resolve_from(anchor)
+            .unwrap();
+        let expected = anchor
+            - Duration::weeks(1)
+            - Duration::days(2)
+            - Duration::hours(3)
+            - Duration::minutes(4)
+            - Duration::seconds(5);
+        assert_eq!(r.as_datetime(), expected);
+    }
+
+    #[test]
+    fn test_from_now_applies_calendar_units_first() {
+        // Jan 31 + 1 month = Feb 28, then + 1 day = Mar 1.
+        let r = from_now()
+            .days(1)
+            .months(1)
+            .resolve_from(date(2026, 1, 31))
+            .unwrap();
+        assert_eq!(r.as_date(), date(2026, 3, 1));
+    }
+
+    #[test]
+    fn test_ago_applies_years_before_months() {
+        // Mar 31 2028 - 1 year = Mar 31 2027, - 1 month = Feb 28 2027.
+        let r = ago()
+            .months(1)
+            .years(1)
+            .resolve_from(date(2028, 3, 31))
+            .unwrap();
+        assert_eq!(r.as_date(), date(2027, 2, 28));
+    }
+
+    #[test]
+    fn test_repeated_unit_accumulates() {
+        let anchor = date(2026, 2, 22);
+        assert_eq!(
+            ago().days(1).days(2).resolve_from(anchor).unwrap(),
+            ago().days(3).resolve_from(anchor).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_empty_builder_resolves_to_anchor() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        assert_eq!(ago().resolve_from(anchor).unwrap().as_datetime(), anchor);
+        assert_eq!(
+            from_now().resolve_from(anchor).unwrap().as_datetime(),
+            anchor
+        );
+    }
+
+    #[test]
+    fn test_negative_component_returns_error() {
+        assert_eq!(
+            ago().weeks(1).days(-2).resolve().unwrap_err().to_string(),
+            "days must be positive. Did you mean from_now().days(2)?"
+        );
+        assert_eq!(
+            from_now().hours(-5).resolve().unwrap_err().to_string(),
+            "hours must be positive. Did you mean ago().hours(5)?"
+        );
+    }
+
+    #[test]
+    fn test_first_error_wins() {
+        let err = ago().months(-1).days(-2).resolve().unwrap_err();
+        assert!(
+            matches!(err, PeriodError::NegativeValue { unit: "months", .. }),
+            "expected months error, got {err:?}"
+        );
+    }
+
+    #[test]
+    fn test_accumulation_overflow_names_component() {
+        let err = ago().minutes(i64::MAX).minutes(1).resolve().unwrap_err();
+        assert_eq!(
+            err,
+            PeriodError::Overflow {
+                unit: "minutes",
+                value: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_resolution_overflow_names_component() {
+        let err = from_now()
+            .months(1)
+            .days(200_000_000)
+            .resolve()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            PeriodError::Overflow {
+                unit: "days",
+                value: 200_000_000
+            }
+        );
+    }
+
+    #[test]
+    fn test_years_overflow_names_years() {
+        let err = ago().years(i64::MAX).days(1).resolve().unwrap_err();
+        assert!(
+            matches!(err, PeriodError::Overflow { unit: "years", .. }),
+            "expected years overflow, got {err:?}"
+        );
+    }
+
+    #[test]
+    fn test_resolve_with_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let r = ago().days(2).hours(6).resolve_with(&clock).unwrap();
+        assert_eq!(
+            r.as_datetime().to_utc(),
+            Utc.with_ymd_and_hms(2026, 2, 20, 6, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_resolve_in_returns_zone() {
+        let r = from_now().hours(1).resolve_in(&Utc).unwrap();
+        assert_eq!(r.timezone(), Utc);
+    }
+
+    #[test]
+    fn test_resolve_matches_single_unit_function() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        assert_eq!(
+            ago().months(3).resolve_with(&clock).unwrap(),
+            crate::relative::functions::months_ago_with(&clock, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_resolve_uses_local_time() {
+        let lower = Local::now() - Duration::hours(2);
+        let r = ago().hours(2).resolve().unwrap().as_datetime();
+        let upper = Local::now() - Duration::hours(2);
+        assert!(r >= lower && r <= upper);
+    }
+}
@@ -1,7 +1,10 @@
 use crate::error::PeriodError;
-use chrono::{Duration, Local, NaiveDate};
+use chrono::{Duration, NaiveDate, TimeZone};
 
 use super::validate_non_negative;
+use crate::clock::{Clock, SystemClock};
+use crate::now::{now_in, now_with};
+use crate::relative::anchor::Anchor;
 use crate::relative::types::Relative;
 
 /// Returns a [`Relative`] moment `days` days in the past.
@@ -15,18 +18,27 @@ use crate::relative::types::Relative;
 /// Use [`days_from_now`] for future offsets.
 #[inline]
 pub fn days_ago(days: i64) -> Result<Relative, PeriodError> {
+    days_ago_with(&SystemClock, days)
+}
+
+/// Like [`days_ago`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`days_ago`].
+#[inline]
+pub fn days_ago_with(clock: &(impl Clock + ?Sized), days: i64) -> Result<Relative, PeriodError> {
     validate_non_negative(days, "days", "days_from_now")?;
-    let duration = Duration::try_days(days).ok_or(PeriodError::Overflow {
-        unit: "days",
-        value: days,
-    })?;
-    Local::now()
-        .checked_sub_signed(duration)
-        .map(Relative)
-        .ok_or(PeriodError::Overflow {
-            unit: "days",
-            value: days,
-        })
+    days_before(now_with(clock), days)
+}
+
+/// Like [`days_ago`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`days_ago`].
+#[inline]
+pub fn days_ago_in<Tz: TimeZone>(tz: &Tz, days: i64) -> Result<Relative<Tz>, PeriodError> {
+    validate_non_negative(days, "days", "days_from_now")?;
+    days_before(now_in(tz), days)
 }
 
 /// Returns a [`Relative`] moment `days` days in the future.
@@ -40,18 +52,72 @@ pub fn days_ago(days: i64) -> Result<Relative, PeriodError> {
 /// Use [`days_ago`] for past offsets.
 #[inline]
 pub fn days_from_now(days: i64) -> Result<Relative, PeriodError> {
+    days_from_now_with(&SystemClock, days)
+}
+
+/// Like [`days_from_now`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`days_from_now`].
+#[inline]
+pub fn days_from_now_with(
+    clock: &(impl Clock + ?Sized),
+    days: i64,
+) -> Result<Relative, PeriodError> {
     validate_non_negative(days, "days", "days_ago")?;
+    days_after(now_with(clock), days)
+}
+
+/// Like [`days_from_now`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`days_from_now`].
+#[inline]
+pub fn days_from_now_in<Tz: TimeZone>(tz: &Tz, days: i64) -> Result<Relative<Tz>, PeriodError> {
+    validate_non_negative(days, "days", "days_ago")?;
+    days_after(now_in(tz), days)
+}
+
+/// Returns a [`Relative`] moment `days` days before `anchor`.
+///
+/// See [`Anchor`] for how each kind of anchor is interpreted.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `days` is negative.
+/// Returns [`PeriodError::Overflow`] if the resulting date-time is out of range.
+/// Use [`days_after`] for later offsets.
+#[inline]
+pub fn days_before<A: Anchor>(anchor: A, days: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(days, "days", "days_after")?;
     let duration = Duration::try_days(days).ok_or(PeriodError::Overflow {
         unit: "days",
         value: days,
     })?;
-    Local::now()
-        .checked_add_signed(duration)
-        .map(Relative)
-        .ok_or(PeriodError::Overflow {
-            unit: "days",
-            value: days,
-        })
+    anchor.sub_duration(duration).ok_or(PeriodError::Overflow {
+        unit: "days",
+        value: days,
+    })
+}
+
+/// Returns a [`Relative`] moment `days` days after `anchor`.
+///
+/// See [`Anchor`] for how each kind of anchor is interpreted.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `days` is negative.
+/// Returns [`PeriodError::Overflow`] if the resulting date-time is out of range.
+/// Use [`days_before`] for earlier offsets.
+#[inline]
+pub fn days_after<A: Anchor>(anchor: A, days: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(days, "days", "days_before")?;
+    let duration = Duration::try_days(days).ok_or(PeriodError::Overflow {
+        unit: "days",
+        value: days,
+    })?;
+    anchor.add_duration(duration).ok_or(PeriodError::Overflow {
+        unit: "days",
+        value: days,
+    })
 }
 
 /// Returns yesterday's local date.
@@ -62,12 +128,34 @@ pub fn days_from_now(days: i64) -> Result<Relative, PeriodError> {
 #[must_use]
 #[inline]
 pub fn yesterday() -> NaiveDate {
-    Local::now()
+    yesterday_with(&SystemClock)
+}
+
+/// Returns the local date before the one reported by `clock`.
+///
+/// # Panics
+///
+/// Panics if the clock's date is [`NaiveDate::MIN`].
+#[must_use]
+#[inline]
+pub fn yesterday_with(clock: &(impl Clock + ?Sized)) -> NaiveDate {
+    now_with(clock)
         .date_naive()
         .pred_opt()
         .expect("date underflow")
 }
 
+/// Returns yesterday's date in the timezone `tz`.
+///
+/// # Panics
+///
+/// Panics if today in `tz` is [`NaiveDate::MIN`], which cannot occur in practice.
+#[must_use]
+#[inline]
+pub fn yesterday_in<Tz: TimeZone>(tz: &Tz) -> NaiveDate {
+    now_in(tz).date_naive().pred_opt().expect("date underflow")
+}
+
 /// Returns tomorrow's local date.
 ///
 /// # Panics
@@ -76,14 +164,40 @@ pub fn yesterday() -> NaiveDate {
 #[must_use]
 #[inline]
 pub fn tomorrow() -> NaiveDate {
-    Local::now().date_naive().succ_opt().expect("date overflow")
+    tomorrow_with(&SystemClock)
+}
+
+/// Returns the local date after the one reported by `clock`.
+///
+/// # Panics
+///
+/// Panics if the clock's date is [`NaiveDate::MAX`].
+#[must_use]
+#[inline]
+pub fn tomorrow_with(clock: &(impl Clock + ?Sized)) -> NaiveDate {
+    now_with(clock)
+        .date_naive()
+        .succ_opt()
+        .expect("date overflow")
+}
+
+/// Returns tomorrow's date in the timezone `tz`.
+///
+/// # Panics
+///
+/// Panics if today in `tz` is [`NaiveDate::MAX`], which cannot occur in practice.
+#[must_use]
+#[inline]
+pub fn tomorrow_in<Tz: TimeZone>(tz: &Tz) -> NaiveDate {
+    now_in(tz).date_naive().succ_opt().expect("date overflow")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::{Clock, MockClock};
     use crate::error::PeriodError;
-    use chrono::{Duration, Local};
+    use chrono::{Duration, FixedOffset, Local, TimeZone, Utc};
 
     #[test]
     fn test_days_ago_returns_correct_date() {
@@ -235,4 +349,158 @@ mod tests {
     fn test_tomorrow_equals_days_from_now_1_date() {
         assert_eq!(tomorrow(), days_from_now(1).unwrap().as_date());
     }
+
+    #[test]
+    fn test_days_ago_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let result = days_ago_with(&clock, 3).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), clock.now() - Duration::days(3));
+    }
+
+    #[test]
+    fn test_days_from_now_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let result = days_from_now_with(&clock, 3).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), clock.now() + Duration::days(3));
+    }
+
+    #[test]
+    fn test_days_ago_with_negative_returns_error() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        assert_eq!(
+            days_ago_with(&clock, -3).unwrap_err().to_string(),
+            "days must be positive. Did you mean days_from_now(3)?"
+        );
+    }
+
+    #[test]
+    fn test_yesterday_and_tomorrow_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let today = clock.now().with_timezone(&Local).date_naive();
+        assert_eq!(yesterday_with(&clock), today.pred_opt().unwrap());
+        assert_eq!(tomorrow_with(&clock), today.succ_opt().unwrap());
+    }
+
+    #[test]
+    fn test_days_ago_with_accepts_dyn_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let dyn_clock: &dyn crate::clock::Clock = &clock;
+        assert_eq!(
+            days_ago_with(dyn_clock, 1).unwrap(),
+            days_ago_with(&clock, 1).unwrap()
+        );
+    }
+
+    // -- days_before / days_after ---------------------------------------------
+
+    #[test]
+    fn test_days_before_naive_date_anchor() {
+        let invoice = NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
+        assert_eq!(
+            days_before(invoice, 30).unwrap().as_date(),
+            NaiveDate::from_ymd_opt(2026, 2, 13).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_days_after_naive_date_anchor() {
+        let invoice = NaiveDate::from_ymd_opt(2026, 2, 15).unwrap();
+        assert_eq!(
+            days_after(invoice, 14).unwrap().as_date(),
+            NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_days_before_datetime_anchor() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let result = days_before(anchor, 3).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), anchor - Duration::days(3));
+    }
+
+    #[test]
+    fn test_days_before_negative_suggests_days_after() {
+        let anchor = NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+        assert_eq!(
+            days_before(anchor, -3).unwrap_err().to_string(),
+            "days must be positive. Did you mean days_after(3)?"
+        );
+    }
+
+    #[test]
+    fn test_days_after_negative_suggests_days_before() {
+        let anchor = NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+        assert_eq!(
+            days_after(anchor, -3).unwrap_err().to_string(),
+            "days must be positive. Did you mean days_before(3)?"
+        );
+    }
+
+    #[test]
+    fn test_days_after_overflow_returns_error() {
+        assert_eq!(
+            days_after(NaiveDate::MAX, 1).unwrap_err(),
+            PeriodError::Overflow {
+                unit: "days",
+                value: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_days_before_relative_anchor_chains() {
+        let anchor = NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+        let once = days_before(anchor, 2).unwrap();
+        assert_eq!(
+            days_before(once, 3).unwrap(),
+            days_before(anchor, 5).unwrap()
+        );
+    }
+
+    // -- days_ago_in / days_from_now_in ---------------------------------------
+
+    #[test]
+    fn test_days_ago_in_returns_zone_date() {
+        let tz = FixedOffset::east_opt(14 * 3600).unwrap();
+        let lower = Utc::now().with_timezone(&tz) - Duration::days(3);
+        let result = days_ago_in(&tz, 3).unwrap();
+        let upper = Utc::now().with_timezone(&tz) - Duration::days(3);
+        assert!(result.as_datetime() >= lower && result.as_datetime() <= upper);
+        assert_eq!(result.timezone(), tz);
+    }
+
+    #[test]
+    fn test_days_from_now_in_utc_is_relative_utc() {
+        let result: Relative<Utc> = days_from_now_in(&Utc, 2).unwrap();
+        let expected = Utc::now().date_naive() + Duration::days(2);
+        assert_eq!(result.as_date(), expected);
+    }
+
+    #[test]
+    fn test_days_ago_in_negative_returns_error() {
+        assert_eq!(
+            days_ago_in(&Utc, -3).unwrap_err().to_string(),
+            "days must be positive. Did you mean days_from_now(3)?"
+        );
+    }
+
+    #[test]
+    fn test_yesterday_in_and_tomorrow_in() {
+        let tz = FixedOffset::west_opt(12 * 3600).unwrap();
+        let today = Utc::now().with_timezone(&tz).date_naive();
+        assert_eq!(yesterday_in(&tz), today.pred_opt().unwrap());
+        assert_eq!(tomorrow_in(&tz), today.succ_opt().unwrap());
+    }
+
+    #[test]
+    fn test_days_before_keeps_anchor_timezone() {
+        let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+        let anchor = tz.with_ymd_and_hms(2026, 2, 22, 0, 30, 0).unwrap();
+        let result = days_before(anchor, 1).unwrap();
+        assert_eq!(result.timezone(), tz);
+        assert_eq!(
+            result.as_date(),
+            NaiveDate::from_ymd_opt(2026, 2, 21).unwrap()
+        );
+    }
 }
@@ -1,7 +1,10 @@
 use crate::error::PeriodError;
-use chrono::{Duration, Local};
+use chrono::{Duration, TimeZone};
 
 use super::validate_non_negative;
+use crate::clock::{Clock, SystemClock};
+use crate::now::{now_in, now_with};
+use crate::relative::anchor::Anchor;
 use crate::relative::types::Relative;
 
 /// Returns a [`Relative`] moment `hours` hours in the past.
@@ -14,18 +17,27 @@ use crate::relative::types::Relative;
 /// Use [`hours_from_now`] for future offsets.
 #[inline]
 pub fn hours_ago(hours: i64) -> Result<Relative, PeriodError> {
+    hours_ago_with(&SystemClock, hours)
+}
+
+/// Like [`hours_ago`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`hours_ago`].
+#[inline]
+pub fn hours_ago_with(clock: &(impl Clock + ?Sized), hours: i64) -> Result<Relative, PeriodError> {
     validate_non_negative(hours, "hours", "hours_from_now")?;
-    let duration = Duration::try_hours(hours).ok_or(PeriodError::Overflow {
-        unit: "hours",
-        value: hours,
-    })?;
-    Local::now()
-        .checked_sub_signed(duration)
-        .map(Relative)
-        .ok_or(PeriodError::Overflow {
-            unit: "hours",
-            value: hours,
-        })
+    hours_before(now_with(clock), hours)
+}
+
+/// Like [`hours_ago`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`hours_ago`].
+#[inline]
+pub fn hours_ago_in<Tz: TimeZone>(tz: &Tz, hours: i64) -> Result<Relative<Tz>, PeriodError> {
+    validate_non_negative(hours, "hours", "hours_from_now")?;
+    hours_before(now_in(tz), hours)
 }
 
 /// Returns a [`Relative`] moment `hours` hours in the future.
@@ -38,25 +50,80 @@ pub fn hours_ago(hours: i64) -> Result<Relative, PeriodError> {
 /// Use [`hours_ago`] for past offsets.
 #[inline]
 pub fn hours_from_now(hours: i64) -> Result<Relative, PeriodError> {
+    hours_from_now_with(&SystemClock, hours)
+}
+
+/// Like [`hours_from_now`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`hours_from_now`].
+#[inline]
+pub fn hours_from_now_with(
+    clock: &(impl Clock + ?Sized),
+    hours: i64,
+) -> Result<Relative, PeriodError> {
     validate_non_negative(hours, "hours", "hours_ago")?;
+    hours_after(now_with(clock), hours)
+}
+
+/// Like [`hours_from_now`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`hours_from_now`].
+#[inline]
+pub fn hours_from_now_in<Tz: TimeZone>(tz: &Tz, hours: i64) -> Result<Relative<Tz>, PeriodError> {
+    validate_non_negative(hours, "hours", "hours_ago")?;
+    hours_after(now_in(tz), hours)
+}
+
+/// Returns a [`Relative`] moment `hours` hours before `anchor`.
+///
+/// See [`Anchor`] for how each kind of anchor is interpreted.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `hours` is negative.
+/// Returns [`PeriodError::Overflow`] if the resulting date-time is out of range.
+/// Use [`hours_after`] for later offsets.
+#[inline]
+pub fn hours_before<A: Anchor>(anchor: A, hours: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(hours, "hours", "hours_after")?;
+    let duration = Duration::try_hours(hours).ok_or(PeriodError::Overflow {
+        unit: "hours",
+        value: hours,
+    })?;
+    anchor.sub_duration(duration).ok_or(PeriodError::Overflow {
+        unit: "hours",
+        value: hours,
+    })
+}
+
+/// Returns a [`Relative`] moment `hours` hours after `anchor`.
+///
+/// See [`Anchor`] for how each kind of anchor is interpreted.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `hours` is negative.
+/// Returns [`PeriodError::Overflow`] if the resulting date-time is out of range.
+/// Use [`hours_before`] for earlier offsets.
+#[inline]
+pub fn hours_after<A: Anchor>(anchor: A, hours: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(hours, "hours", "hours_before")?;
     let duration = Duration::try_hours(hours).ok_or(PeriodError::Overflow {
         unit: "hours",
         value: hours,
     })?;
-    Local::now()
-        .checked_add_signed(duration)
-        .map(Relative)
-        .ok_or(PeriodError::Overflow {
-            unit: "hours",
-            value: hours,
-        })
+    anchor.add_duration(duration).ok_or(PeriodError::Overflow {
+        unit: "hours",
+        value: hours,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::{Clock, MockClock};
     use crate::error::PeriodError;
-    use chrono::{Duration, Local};
+    use chrono::{Duration, Local, TimeZone, Utc};
 
     #[test]
     fn test_hours_ago_returns_correct_datetime() {
@@ -149,4 +216,68 @@ mod tests {
                 .as_date()
         );
     }
+
+    #[test]
+    fn test_hours_ago_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let result = hours_ago_with(&clock, 5).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), clock.now() - Duration::hours(5));
+    }
+
+    #[test]
+    fn test_hours_from_now_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let result = hours_from_now_with(&clock, 5).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), clock.now() + Duration::hours(5));
+    }
+
+    // -- hours_before / hours_after -------------------------------------------
+
+    #[test]
+    fn test_hours_before_datetime_anchor() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let result = hours_before(anchor, 5).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), anchor - Duration::hours(5));
+    }
+
+    #[test]
+    fn test_hours_after_naive_date_anchor() {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+        let result = hours_after(date, 36).unwrap();
+        assert_eq!(
+            result.as_date(),
+            chrono::NaiveDate::from_ymd_opt(2026, 2, 23).unwrap()
+        );
+        assert_eq!(
+            result.as_time(),
+            chrono::NaiveTime::from_hms_opt(12, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_hours_before_negative_suggests_hours_after() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        assert_eq!(
+            hours_before(anchor, -2).unwrap_err().to_string(),
+            "hours must be positive. Did you mean hours_after(2)?"
+        );
+    }
+
+    #[test]
+    fn test_hours_ago_in_returns_zone_datetime() {
+        let tz = chrono::FixedOffset::east_opt(3 * 3600).unwrap();
+        let lower = Utc::now().with_timezone(&tz) - Duration::hours(2);
+        let result = hours_ago_in(&tz, 2).unwrap().as_datetime();
+        let upper = Utc::now().with_timezone(&tz) - Duration::hours(2);
+        assert!(result >= lower && result <= upper);
+        assert_eq!(result.offset(), &tz);
+    }
+
+    #[test]
+    fn test_hours_from_now_in_negative_returns_error() {
+        assert_eq!(
+            hours_from_now_in(&Utc, -2).unwrap_err().to_string(),
+            "hours must be positive. Did you mean hours_ago(2)?"
+        );
+    }
 }
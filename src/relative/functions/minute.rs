@@ -1,7 +1,10 @@
 use crate::error::PeriodError;
-use chrono::{Duration, Local};
+use chrono::{Duration, TimeZone};
 
 use super::validate_non_negative;
+use crate::clock::{Clock, SystemClock};
+use crate::now::{now_in, now_with};
+use crate::relative::anchor::Anchor;
 use crate::relative::types::Relative;
 
 /// Returns a [`Relative`] moment `minutes` minutes in the past.
@@ -14,18 +17,30 @@ use crate::relative::types::Relative;
 /// Use [`minutes_from_now`] for future offsets.
 #[inline]
 pub fn minutes_ago(minutes: i64) -> Result<Relative, PeriodError> {
+    minutes_ago_with(&SystemClock, minutes)
+}
+
+/// Like [`minutes_ago`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`minutes_ago`].
+#[inline]
+pub fn minutes_ago_with(
+    clock: &(impl Clock + ?Sized),
+    minutes: i64,
+) -> Result<Relative, PeriodError> {
     validate_non_negative(minutes, "minutes", "minutes_from_now")?;
-    let duration = Duration::try_minutes(minutes).ok_or(PeriodError::Overflow {
-        unit: "minutes",
-        value: minutes,
-    })?;
-    Local::now()
-        .checked_sub_signed(duration)
-        .map(Relative)
-        .ok_or(PeriodError::Overflow {
-            unit: "minutes",
-            value: minutes,
-        })
+    minutes_before(now_with(clock), minutes)
+}
+
+/// Like [`minutes_ago`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`minutes_ago`].
+#[inline]
+pub fn minutes_ago_in<Tz: TimeZone>(tz: &Tz, minutes: i64) -> Result<Relative<Tz>, PeriodError> {
+    validate_non_negative(minutes, "minutes", "minutes_from_now")?;
+    minutes_before(now_in(tz), minutes)
 }
 
 /// Returns a [`Relative`] moment `minutes` minutes in the future.
@@ -38,25 +53,83 @@ pub fn minutes_ago(minutes: i64) -> Result<Relative, PeriodError> {
 /// Use [`minutes_ago`] for past offsets.
 #[inline]
 pub fn minutes_from_now(minutes: i64) -> Result<Relative, PeriodError> {
+    minutes_from_now_with(&SystemClock, minutes)
+}
+
+/// Like [`minutes_from_now`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`minutes_from_now`].
+#[inline]
+pub fn minutes_from_now_with(
+    clock: &(impl Clock + ?Sized),
+    minutes: i64,
+) -> Result<Relative, PeriodError> {
     validate_non_negative(minutes, "minutes", "minutes_ago")?;
+    minutes_after(now_with(clock), minutes)
+}
+
+/// Like [`minutes_from_now`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`minutes_from_now`].
+#[inline]
+pub fn minutes_from_now_in<Tz: TimeZone>(
+    tz: &Tz,
+    minutes: i64,
+) -> Result<Relative<Tz>, PeriodError> {
+    validate_non_negative(minutes, "minutes", "minutes_ago")?;
+    minutes_after(now_in(tz), minutes)
+}
+
+/// Returns a [`Relative`] moment `minutes` minutes before `anchor`.
+///
+/// See [`Anchor`] for how each kind of anchor is interpreted.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `minutes` is negative.
+/// Returns [`PeriodError::Overflow`] if the resulting date-time is out of range.
+/// Use [`minutes_after`] for later offsets.
+#[inline]
+pub fn minutes_before<A: Anchor>(anchor: A, minutes: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(minutes, "minutes", "minutes_after")?;
     let duration = Duration::try_minutes(minutes).ok_or(PeriodError::Overflow {
         unit: "minutes",
         value: minutes,
     })?;
-    Local::now()
-        .checked_add_signed(duration)
-        .map(Relative)
-        .ok_or(PeriodError::Overflow {
-            unit: "minutes",
-            value: minutes,
-        })
+    anchor.sub_duration(duration).ok_or(PeriodError::Overflow {
+        unit: "minutes",
+        value: minutes,
+    })
+}
+
+/// Returns a [`Relative`] moment `minutes` minutes after `anchor`.
+///
+/// See [`Anchor`] for how each kind of anchor is interpreted.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `minutes` is negative.
+/// Returns [`PeriodError::Overflow`] if the resulting date-time is out of range.
+/// Use [`minutes_before`] for earlier offsets.
+#[inline]
+pub fn minutes_after<A: Anchor>(anchor: A, minutes: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(minutes, "minutes", "minutes_before")?;
+    let duration = Duration::try_minutes(minutes).ok_or(PeriodError::Overflow {
+        unit: "minutes",
+        value: minutes,
+    })?;
+    anchor.add_duration(duration).ok_or(PeriodError::Overflow {
+        unit: "minutes",
+        value: minutes,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::{Clock, MockClock};
     use crate::error::PeriodError;
-    use chrono::{Duration, Local};
+    use chrono::{Duration, Local, TimeZone, Utc};
 
     #[test]
     fn test_minutes_ago_returns_correct_datetime() {
@@ -128,4 +201,51 @@ mod tests {
             PeriodError::NegativeValue { value: 1_000, .. }
         ));
     }
+
+    #[test]
+    fn test_minutes_ago_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let result = minutes_ago_with(&clock, 15).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), clock.now() - Duration::minutes(15));
+    }
+
+    #[test]
+    fn test_minutes_from_now_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let result = minutes_from_now_with(&clock, 15).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), clock.now() + Duration::minutes(15));
+    }
+
+    // -- minutes_before / minutes_after ---------------------------------------
+
+    #[test]
+    fn test_minutes_before_datetime_anchor() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let result = minutes_before(anchor, 90).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), anchor - Duration::minutes(90));
+    }
+
+    #[test]
+    fn test_minutes_after_datetime_anchor() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let result = minutes_after(anchor, 90).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), anchor + Duration::minutes(90));
+    }
+
+    #[test]
+    fn test_minutes_after_negative_suggests_minutes_before() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        assert_eq!(
+            minutes_after(anchor, -4).unwrap_err().to_string(),
+            "minutes must be positive. Did you mean minutes_before(4)?"
+        );
+    }
+
+    #[test]
+    fn test_minutes_from_now_in_returns_zone_datetime() {
+        let lower = Utc::now() + Duration::minutes(10);
+        let result = minutes_from_now_in(&Utc, 10).unwrap().as_datetime();
+        let upper = Utc::now() + Duration::minutes(10);
+        assert!(result >= lower && result <= upper);
+    }
 }
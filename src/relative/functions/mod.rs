@@ -6,13 +6,35 @@ pub mod second;
 pub mod week;
 pub mod year;
 
-pub use day::{days_ago, days_from_now, tomorrow, yesterday};
-pub use hour::{hours_ago, hours_from_now};
-pub use minute::{minutes_ago, minutes_from_now};
-pub use month::{months_ago, months_from_now};
-pub use second::{seconds_ago, seconds_from_now};
-pub use week::{weeks_ago, weeks_from_now};
-pub use year::{years_ago, years_from_now};
+pub use day::{
+    days_after, days_ago, days_ago_in, days_ago_with, days_before, days_from_now, days_from_now_in,
+    days_from_now_with, tomorrow, tomorrow_in, tomorrow_with, yesterday, yesterday_in,
+    yesterday_with,
+};
+pub use hour::{
+    hours_after, hours_ago, hours_ago_in, hours_ago_with, hours_before, hours_from_now,
+    hours_from_now_in, hours_from_now_with,
+};
+pub use minute::{
+    minutes_after, minutes_ago, minutes_ago_in, minutes_ago_with, minutes_before, minutes_from_now,
+    minutes_from_now_in, minutes_from_now_with,
+};
+pub use month::{
+    months_after, months_ago, months_ago_in, months_ago_with, months_before, months_from_now,
+    months_from_now_in, months_from_now_with,
+};
+pub use second::{
+    seconds_after, seconds_ago, seconds_ago_in, seconds_ago_with, seconds_before, seconds_from_now,
+    seconds_from_now_in, seconds_from_now_with,
+};
+pub use week::{
+    weeks_after, weeks_ago, weeks_ago_in, weeks_ago_with, weeks_before, weeks_from_now,
+    weeks_from_now_in, weeks_from_now_with,
+};
+pub use year::{
+    years_after, years_ago, years_ago_in, years_ago_with, years_before, years_from_now,
+    years_from_now_in, years_from_now_with,
+};
 
 pub(super) fn validate_non_negative(
     value: i64,
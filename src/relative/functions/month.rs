@@ -1,7 +1,10 @@
 use crate::error::PeriodError;
-use chrono::{Local, Months};
+use chrono::{Months, TimeZone};
 
 use super::validate_non_negative;
+use crate::clock::{Clock, SystemClock};
+use crate::now::{now_in, now_with};
+use crate::relative::anchor::Anchor;
 use crate::relative::types::Relative;
 
 /// Returns a [`Relative`] moment `months` calendar months in the past.
@@ -15,39 +18,109 @@ use crate::relative::types::Relative;
 /// Use [`months_from_now`] for future offsets.
 #[inline]
 pub fn months_ago(months: i64) -> Result<Relative, PeriodError> {
+    months_ago_with(&SystemClock, months)
+}
+
+/// Like [`months_ago`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`months_ago`].
+#[inline]
+pub fn months_ago_with(
+    clock: &(impl Clock + ?Sized),
+    months: i64,
+) -> Result<Relative, PeriodError> {
+    validate_non_negative(months, "months", "months_from_now")?;
+    months_before(now_with(clock), months)
+}
+
+/// Like [`months_ago`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`months_ago`].
+#[inline]
+pub fn months_ago_in<Tz: TimeZone>(tz: &Tz, months: i64) -> Result<Relative<Tz>, PeriodError> {
     validate_non_negative(months, "months", "months_from_now")?;
+    months_before(now_in(tz), months)
+}
+
+/// Returns a [`Relative`] moment `months` calendar months in the future.
+///
+/// A value of `0` returns the current date-time. Use `.as_date()` to get a
+/// [`chrono::NaiveDate`] if you do not need the time component.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `months` is negative.
+/// Returns [`PeriodError::Overflow`] if `months` exceeds [`u32::MAX`] or the resulting date-time is out of range.
+/// Use [`months_ago`] for past offsets.
+#[inline]
+pub fn months_from_now(months: i64) -> Result<Relative, PeriodError> {
+    months_from_now_with(&SystemClock, months)
+}
+
+/// Like [`months_from_now`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`months_from_now`].
+#[inline]
+pub fn months_from_now_with(
+    clock: &(impl Clock + ?Sized),
+    months: i64,
+) -> Result<Relative, PeriodError> {
+    validate_non_negative(months, "months", "months_ago")?;
+    months_after(now_with(clock), months)
+}
+
+/// Like [`months_from_now`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`months_from_now`].
+#[inline]
+pub fn months_from_now_in<Tz: TimeZone>(tz: &Tz, months: i64) -> Result<Relative<Tz>, PeriodError> {
+    validate_non_negative(months, "months", "months_ago")?;
+    months_after(now_in(tz), months)
+}
+
+/// Returns a [`Relative`] moment `months` calendar months before `anchor`.
+///
+/// See [`Anchor`] for how each kind of anchor is interpreted.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `months` is negative.
+/// Returns [`PeriodError::Overflow`] if `months` exceeds [`u32::MAX`] or the resulting date-time is out of range.
+/// Use [`months_after`] for later offsets.
+#[inline]
+pub fn months_before<A: Anchor>(anchor: A, months: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(months, "months", "months_after")?;
     let months_u32 = u32::try_from(months).map_err(|_| PeriodError::Overflow {
         unit: "months",
         value: months,
     })?;
-    Local::now()
-        .checked_sub_months(Months::new(months_u32))
-        .map(Relative)
+    anchor
+        .sub_months(Months::new(months_u32))
         .ok_or(PeriodError::Overflow {
             unit: "months",
             value: months,
         })
 }
 
-/// Returns a [`Relative`] moment `months` calendar months in the future.
+/// Returns a [`Relative`] moment `months` calendar months after `anchor`.
 ///
-/// A value of `0` returns the current date-time. Use `.as_date()` to get a
-/// [`chrono::NaiveDate`] if you do not need the time component.
+/// See [`Anchor`] for how each kind of anchor is interpreted.
 ///
 /// # Errors
 /// Returns [`PeriodError::NegativeValue`] if `months` is negative.
 /// Returns [`PeriodError::Overflow`] if `months` exceeds [`u32::MAX`] or the resulting date-time is out of range.
-/// Use [`months_ago`] for past offsets.
+/// Use [`months_before`] for earlier offsets.
 #[inline]
-pub fn months_from_now(months: i64) -> Result<Relative, PeriodError> {
-    validate_non_negative(months, "months", "months_ago")?;
+pub fn months_after<A: Anchor>(anchor: A, months: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(months, "months", "months_before")?;
     let months_u32 = u32::try_from(months).map_err(|_| PeriodError::Overflow {
         unit: "months",
         value: months,
     })?;
-    Local::now()
-        .checked_add_months(Months::new(months_u32))
-        .map(Relative)
+    anchor
+        .add_months(Months::new(months_u32))
         .ok_or(PeriodError::Overflow {
             unit: "months",
             value: months,
@@ -57,8 +130,9 @@ pub fn months_from_now(months: i64) -> Result<Relative, PeriodError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::{Clock, MockClock};
     use crate::error::PeriodError;
-    use chrono::{Local, Months};
+    use chrono::{FixedOffset, Local, Months, NaiveDate, TimeZone, Utc};
 
     #[test]
     fn test_months_ago_returns_correct_date() {
@@ -197,4 +271,99 @@ mod tests {
     fn test_months_from_now_is_in_the_future() {
         assert!(months_from_now(1).unwrap().as_date() > Local::now().date_naive());
     }
+
+    #[test]
+    fn test_months_ago_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let expected = clock
+            .now()
+            .with_timezone(&Local)
+            .checked_sub_months(Months::new(2))
+            .unwrap();
+        assert_eq!(months_ago_with(&clock, 2).unwrap().as_datetime(), expected);
+    }
+
+    #[test]
+    fn test_months_from_now_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let expected = clock
+            .now()
+            .with_timezone(&Local)
+            .checked_add_months(Months::new(2))
+            .unwrap();
+        assert_eq!(
+            months_from_now_with(&clock, 2).unwrap().as_datetime(),
+            expected
+        );
+    }
+
+    // -- months_before / months_after -----------------------------------------
+
+    #[test]
+    fn test_months_before_naive_date_anchor() {
+        let date = NaiveDate::from_ymd_opt(2026, 5, 15).unwrap();
+        assert_eq!(
+            months_before(date, 3).unwrap().as_date(),
+            NaiveDate::from_ymd_opt(2026, 2, 15).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_months_after_clamps_to_month_end() {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap();
+        assert_eq!(
+            months_after(date, 1).unwrap().as_date(),
+            NaiveDate::from_ymd_opt(2026, 2, 28).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_months_after_uses_anchor_timezone_calendar() {
+        let tz = FixedOffset::west_opt(6 * 3600).unwrap();
+        let anchor = tz.with_ymd_and_hms(2026, 1, 31, 23, 0, 0).unwrap();
+        let result = months_after(anchor, 1).unwrap().as_datetime();
+        assert_eq!(
+            result.with_timezone(&tz),
+            tz.with_ymd_and_hms(2026, 2, 28, 23, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_months_before_negative_suggests_months_after() {
+        let date = NaiveDate::from_ymd_opt(2026, 5, 15).unwrap();
+        assert_eq!(
+            months_before(date, -2).unwrap_err().to_string(),
+            "months must be positive. Did you mean months_after(2)?"
+        );
+    }
+
+    #[test]
+    fn test_months_after_overflow_returns_error() {
+        let date = NaiveDate::from_ymd_opt(2026, 5, 15).unwrap();
+        assert_eq!(
+            months_after(date, 5_000_000_000).unwrap_err().to_string(),
+            "months value 5000000000 is too large"
+        );
+    }
+
+    // -- months_ago_in / months_from_now_in -----------------------------------
+
+    #[test]
+    fn test_months_ago_in_uses_zone_calendar() {
+        let tz = FixedOffset::west_opt(10 * 3600).unwrap();
+        let expected = Utc::now()
+            .with_timezone(&tz)
+            .date_naive()
+            .checked_sub_months(Months::new(2))
+            .unwrap();
+        assert_eq!(months_ago_in(&tz, 2).unwrap().as_date(), expected);
+    }
+
+    #[test]
+    fn test_months_from_now_in_negative_returns_error() {
+        assert_eq!(
+            months_from_now_in(&Utc, -2).unwrap_err().to_string(),
+            "months must be positive. Did you mean months_ago(2)?"
+        );
+    }
 }
@@ -1,7 +1,10 @@
 use crate::error::PeriodError;
-use chrono::{Duration, Local};
+use chrono::{Duration, TimeZone};
 
 use super::validate_non_negative;
+use crate::clock::{Clock, SystemClock};
+use crate::now::{now_in, now_with};
+use crate::relative::anchor::Anchor;
 use crate::relative::types::Relative;
 
 /// Returns a [`Relative`] moment `seconds` seconds in the past.
@@ -14,18 +17,30 @@ use crate::relative::types::Relative;
 /// Use [`seconds_from_now`] for future offsets.
 #[inline]
 pub fn seconds_ago(seconds: i64) -> Result<Relative, PeriodError> {
+    seconds_ago_with(&SystemClock, seconds)
+}
+
+/// Like [`seconds_ago`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`seconds_ago`].
+#[inline]
+pub fn seconds_ago_with(
+    clock: &(impl Clock + ?Sized),
+    seconds: i64,
+) -> Result<Relative, PeriodError> {
     validate_non_negative(seconds, "seconds", "seconds_from_now")?;
-    let duration = Duration::try_seconds(seconds).ok_or(PeriodError::Overflow {
-        unit: "seconds",
-        value: seconds,
-    })?;
-    Local::now()
-        .checked_sub_signed(duration)
-        .map(Relative)
-        .ok_or(PeriodError::Overflow {
-            unit: "seconds",
-            value: seconds,
-        })
+    seconds_before(now_with(clock), seconds)
+}
+
+/// Like [`seconds_ago`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`seconds_ago`].
+#[inline]
+pub fn seconds_ago_in<Tz: TimeZone>(tz: &Tz, seconds: i64) -> Result<Relative<Tz>, PeriodError> {
+    validate_non_negative(seconds, "seconds", "seconds_from_now")?;
+    seconds_before(now_in(tz), seconds)
 }
 
 /// Returns a [`Relative`] moment `seconds` seconds in the future.
@@ -38,24 +53,82 @@ pub fn seconds_ago(seconds: i64) -> Result<Relative, PeriodError> {
 /// Use [`seconds_ago`] for past offsets.
 #[inline]
 pub fn seconds_from_now(seconds: i64) -> Result<Relative, PeriodError> {
+    seconds_from_now_with(&SystemClock, seconds)
+}
+
+/// Like [`seconds_from_now`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`seconds_from_now`].
+#[inline]
+pub fn seconds_from_now_with(
+    clock: &(impl Clock + ?Sized),
+    seconds: i64,
+) -> Result<Relative, PeriodError> {
     validate_non_negative(seconds, "seconds", "seconds_ago")?;
+    seconds_after(now_with(clock), seconds)
+}
+
+/// Like [`seconds_from_now`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`seconds_from_now`].
+#[inline]
+pub fn seconds_from_now_in<Tz: TimeZone>(
+    tz: &Tz,
+    seconds: i64,
+) -> Result<Relative<Tz>, PeriodError> {
+    validate_non_negative(seconds, "seconds", "seconds_ago")?;
+    seconds_after(now_in(tz), seconds)
+}
+
+/// Returns a [`Relative`] moment `seconds` seconds before `anchor`.
+///
+/// See [`Anchor`] for how each kind of anchor is interpreted.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `seconds` is negative.
+/// Returns [`PeriodError::Overflow`] if the resulting date-time is out of range.
+/// Use [`seconds_after`] for later offsets.
+#[inline]
+pub fn seconds_before<A: Anchor>(anchor: A, seconds: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(seconds, "seconds", "seconds_after")?;
     let duration = Duration::try_seconds(seconds).ok_or(PeriodError::Overflow {
         unit: "seconds",
         value: seconds,
     })?;
-    Local::now()
-        .checked_add_signed(duration)
-        .map(Relative)
-        .ok_or(PeriodError::Overflow {
-            unit: "seconds",
-            value: seconds,
-        })
+    anchor.sub_duration(duration).ok_or(PeriodError::Overflow {
+        unit: "seconds",
+        value: seconds,
+    })
+}
+
+/// Returns a [`Relative`] moment `seconds` seconds after `anchor`.
+///
+/// See [`Anchor`] for how each kind of anchor is interpreted.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `seconds` is negative.
+/// Returns [`PeriodError::Overflow`] if the resulting date-time is out of range.
+/// Use [`seconds_before`] for earlier offsets.
+#[inline]
+pub fn seconds_after<A: Anchor>(anchor: A, seconds: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(seconds, "seconds", "seconds_before")?;
+    let duration = Duration::try_seconds(seconds).ok_or(PeriodError::Overflow {
+        unit: "seconds",
+        value: seconds,
+    })?;
+    anchor.add_duration(duration).ok_or(PeriodError::Overflow {
+        unit: "seconds",
+        value: seconds,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::{Duration, Local};
+    use crate::clock::{Clock, MockClock};
+    use chrono::{Duration, Local, TimeZone, Utc};
 
     #[test]
     fn test_seconds_ago_returns_correct_datetime() {
@@ -134,4 +207,48 @@ mod tests {
     fn test_seconds_from_now_is_in_the_future() {
         assert!(seconds_from_now(10).unwrap().as_datetime() > Local::now());
     }
+
+    #[test]
+    fn test_seconds_ago_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let result = seconds_ago_with(&clock, 30).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), clock.now() - Duration::seconds(30));
+    }
+
+    #[test]
+    fn test_seconds_from_now_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let result = seconds_from_now_with(&clock, 30).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), clock.now() + Duration::seconds(30));
+    }
+
+    // -- seconds_before / seconds_after ---------------------------------------
+
+    #[test]
+    fn test_seconds_before_datetime_anchor() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let result = seconds_before(anchor, 45).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), anchor - Duration::seconds(45));
+    }
+
+    #[test]
+    fn test_seconds_after_datetime_anchor() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let result = seconds_after(anchor, 45).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), anchor + Duration::seconds(45));
+    }
+
+    #[test]
+    fn test_seconds_before_overflow_returns_error() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        assert!(seconds_before(anchor, i64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_seconds_ago_in_returns_zone_datetime() {
+        let lower = Utc::now() - Duration::seconds(10);
+        let result = seconds_ago_in(&Utc, 10).unwrap().as_datetime();
+        let upper = Utc::now() - Duration::seconds(10);
+        assert!(result >= lower && result <= upper);
+    }
 }
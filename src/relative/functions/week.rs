@@ -1,7 +1,10 @@
 use crate::error::PeriodError;
-use chrono::{Duration, Local};
+use chrono::{Duration, TimeZone};
 
 use super::validate_non_negative;
+use crate::clock::{Clock, SystemClock};
+use crate::now::{now_in, now_with};
+use crate::relative::anchor::Anchor;
 use crate::relative::types::Relative;
 
 /// Returns a [`Relative`] moment `weeks` weeks in the past.
@@ -15,18 +18,27 @@ use crate::relative::types::Relative;
 /// Use [`weeks_from_now`] for future offsets.
 #[inline]
 pub fn weeks_ago(weeks: i64) -> Result<Relative, PeriodError> {
+    weeks_ago_with(&SystemClock, weeks)
+}
+
+/// Like [`weeks_ago`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`weeks_ago`].
+#[inline]
+pub fn weeks_ago_with(clock: &(impl Clock + ?Sized), weeks: i64) -> Result<Relative, PeriodError> {
     validate_non_negative(weeks, "weeks", "weeks_from_now")?;
-    let duration = Duration::try_weeks(weeks).ok_or(PeriodError::Overflow {
-        unit: "weeks",
-        value: weeks,
-    })?;
-    Local::now()
-        .checked_sub_signed(duration)
-        .map(Relative)
-        .ok_or(PeriodError::Overflow {
-            unit: "weeks",
-            value: weeks,
-        })
+    weeks_before(now_with(clock), weeks)
+}
+
+/// Like [`weeks_ago`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`weeks_ago`].
+#[inline]
+pub fn weeks_ago_in<Tz: TimeZone>(tz: &Tz, weeks: i64) -> Result<Relative<Tz>, PeriodError> {
+    validate_non_negative(weeks, "weeks", "weeks_from_now")?;
+    weeks_before(now_in(tz), weeks)
 }
 
 /// Returns a [`Relative`] moment `weeks` weeks in the future.
@@ -40,25 +52,80 @@ pub fn weeks_ago(weeks: i64) -> Result<Relative, PeriodError> {
 /// Use [`weeks_ago`] for past offsets.
 #[inline]
 pub fn weeks_from_now(weeks: i64) -> Result<Relative, PeriodError> {
+    weeks_from_now_with(&SystemClock, weeks)
+}
+
+/// Like [`weeks_from_now`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`weeks_from_now`].
+#[inline]
+pub fn weeks_from_now_with(
+    clock: &(impl Clock + ?Sized),
+    weeks: i64,
+) -> Result<Relative, PeriodError> {
     validate_non_negative(weeks, "weeks", "weeks_ago")?;
+    weeks_after(now_with(clock), weeks)
+}
+
+/// Like [`weeks_from_now`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`weeks_from_now`].
+#[inline]
+pub fn weeks_from_now_in<Tz: TimeZone>(tz: &Tz, weeks: i64) -> Result<Relative<Tz>, PeriodError> {
+    validate_non_negative(weeks, "weeks", "weeks_ago")?;
+    weeks_after(now_in(tz), weeks)
+}
+
+/// Returns a [`Relative`] moment `weeks` weeks before `anchor`.
+///
+/// See [`Anchor`] for how each kind of anchor is interpreted.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `weeks` is negative.
+/// Returns [`PeriodError::Overflow`] if the resulting date-time is out of range.
+/// Use [`weeks_after`] for later offsets.
+#[inline]
+pub fn weeks_before<A: Anchor>(anchor: A, weeks: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(weeks, "weeks", "weeks_after")?;
     let duration = Duration::try_weeks(weeks).ok_or(PeriodError::Overflow {
         unit: "weeks",
         value: weeks,
     })?;
-    Local::now()
-        .checked_add_signed(duration)
-        .map(Relative)
-        .ok_or(PeriodError::Overflow {
-            unit: "weeks",
-            value: weeks,
-        })
+    anchor.sub_duration(duration).ok_or(PeriodError::Overflow {
+        unit: "weeks",
+        value: weeks,
+    })
+}
+
+/// Returns a [`Relative`] moment `weeks` weeks after `anchor`.
+///
+/// See [`Anchor`] for how each kind of anchor is interpreted.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `weeks` is negative.
+/// Returns [`PeriodError::Overflow`] if the resulting date-time is out of range.
+/// Use [`weeks_before`] for earlier offsets.
+#[inline]
+pub fn weeks_after<A: Anchor>(anchor: A, weeks: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(weeks, "weeks", "weeks_before")?;
+    let duration = Duration::try_weeks(weeks).ok_or(PeriodError::Overflow {
+        unit: "weeks",
+        value: weeks,
+    })?;
+    anchor.add_duration(duration).ok_or(PeriodError::Overflow {
+        unit: "weeks",
+        value: weeks,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::{Clock, MockClock};
     use crate::error::PeriodError;
-    use chrono::{Duration, Local};
+    use chrono::{Duration, Local, TimeZone, Utc};
 
     #[test]
     fn test_weeks_ago_returns_correct_date() {
@@ -148,4 +215,51 @@ mod tests {
     fn test_weeks_from_now_large_valid_value() {
         assert!(weeks_from_now(52).is_ok());
     }
+
+    #[test]
+    fn test_weeks_ago_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let result = weeks_ago_with(&clock, 2).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), clock.now() - Duration::weeks(2));
+    }
+
+    #[test]
+    fn test_weeks_from_now_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let result = weeks_from_now_with(&clock, 2).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), clock.now() + Duration::weeks(2));
+    }
+
+    // -- weeks_before / weeks_after -------------------------------------------
+
+    #[test]
+    fn test_weeks_before_naive_date_anchor() {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        assert_eq!(
+            weeks_before(date, 2).unwrap().as_date(),
+            chrono::NaiveDate::from_ymd_opt(2026, 2, 15).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_weeks_after_datetime_anchor() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let result = weeks_after(anchor, 2).unwrap().as_datetime();
+        assert_eq!(result.to_utc(), anchor + Duration::weeks(2));
+    }
+
+    #[test]
+    fn test_weeks_before_negative_suggests_weeks_after() {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        assert_eq!(
+            weeks_before(date, -1).unwrap_err().to_string(),
+            "weeks must be positive. Did you mean weeks_after(1)?"
+        );
+    }
+
+    #[test]
+    fn test_weeks_ago_in_returns_zone_date() {
+        let result = weeks_ago_in(&Utc, 1).unwrap().as_date();
+        assert_eq!(result, Utc::now().date_naive() - Duration::weeks(1));
+    }
 }
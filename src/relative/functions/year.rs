@@ -1,7 +1,10 @@
 use crate::error::PeriodError;
-use chrono::{Local, Months};
+use chrono::{Months, TimeZone};
 
 use super::validate_non_negative;
+use crate::clock::{Clock, SystemClock};
+use crate::now::{now_in, now_with};
+use crate::relative::anchor::Anchor;
 use crate::relative::types::Relative;
 
 /// Returns a [`Relative`] moment `years` calendar years in the past.
@@ -16,7 +19,78 @@ use crate::relative::types::Relative;
 /// Use [`years_from_now`] for future offsets.
 #[inline]
 pub fn years_ago(years: i64) -> Result<Relative, PeriodError> {
+    years_ago_with(&SystemClock, years)
+}
+
+/// Like [`years_ago`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`years_ago`].
+#[inline]
+pub fn years_ago_with(clock: &(impl Clock + ?Sized), years: i64) -> Result<Relative, PeriodError> {
+    validate_non_negative(years, "years", "years_from_now")?;
+    years_before(now_with(clock), years)
+}
+
+/// Like [`years_ago`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`years_ago`].
+#[inline]
+pub fn years_ago_in<Tz: TimeZone>(tz: &Tz, years: i64) -> Result<Relative<Tz>, PeriodError> {
     validate_non_negative(years, "years", "years_from_now")?;
+    years_before(now_in(tz), years)
+}
+
+/// Returns a [`Relative`] moment `years` calendar years in the future.
+///
+/// Internally converts years to months. A value of `0` returns the current
+/// date-time. Use `.as_date()` to get a [`chrono::NaiveDate`] if you do not need the
+/// time component.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `years` is negative.
+/// Returns [`PeriodError::Overflow`] if the equivalent month count overflows or the resulting date-time is out of range.
+/// Use [`years_ago`] for past offsets.
+#[inline]
+pub fn years_from_now(years: i64) -> Result<Relative, PeriodError> {
+    years_from_now_with(&SystemClock, years)
+}
+
+/// Like [`years_from_now`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`years_from_now`].
+#[inline]
+pub fn years_from_now_with(
+    clock: &(impl Clock + ?Sized),
+    years: i64,
+) -> Result<Relative, PeriodError> {
+    validate_non_negative(years, "years", "years_ago")?;
+    years_after(now_with(clock), years)
+}
+
+/// Like [`years_from_now`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// # Errors
+/// Same as [`years_from_now`].
+#[inline]
+pub fn years_from_now_in<Tz: TimeZone>(tz: &Tz, years: i64) -> Result<Relative<Tz>, PeriodError> {
+    validate_non_negative(years, "years", "years_ago")?;
+    years_after(now_in(tz), years)
+}
+
+/// Returns a [`Relative`] moment `years` calendar years before `anchor`.
+///
+/// See [`Anchor`] for how each kind of anchor is interpreted.
+///
+/// # Errors
+/// Returns [`PeriodError::NegativeValue`] if `years` is negative.
+/// Returns [`PeriodError::Overflow`] if the equivalent month count overflows or the resulting date-time is out of range.
+/// Use [`years_after`] for later offsets.
+#[inline]
+pub fn years_before<A: Anchor>(anchor: A, years: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(years, "years", "years_after")?;
     let months_i64 = years.checked_mul(12).ok_or(PeriodError::Overflow {
         unit: "years",
         value: years,
@@ -25,28 +99,25 @@ pub fn years_ago(years: i64) -> Result<Relative, PeriodError> {
         unit: "years",
         value: years,
     })?;
-    Local::now()
-        .checked_sub_months(Months::new(months))
-        .map(Relative)
+    anchor
+        .sub_months(Months::new(months))
         .ok_or(PeriodError::Overflow {
             unit: "years",
             value: years,
         })
 }
 
-/// Returns a [`Relative`] moment `years` calendar years in the future.
+/// Returns a [`Relative`] moment `years` calendar years after `anchor`.
 ///
-/// Internally converts years to months. A value of `0` returns the current
-/// date-time. Use `.as_date()` to get a [`chrono::NaiveDate`] if you do not need the
-/// time component.
+/// See [`Anchor`] for how each kind of anchor is interpreted.
 ///
 /// # Errors
 /// Returns [`PeriodError::NegativeValue`] if `years` is negative.
 /// Returns [`PeriodError::Overflow`] if the equivalent month count overflows or the resulting date-time is out of range.
-/// Use [`years_ago`] for past offsets.
+/// Use [`years_before`] for earlier offsets.
 #[inline]
-pub fn years_from_now(years: i64) -> Result<Relative, PeriodError> {
-    validate_non_negative(years, "years", "years_ago")?;
+pub fn years_after<A: Anchor>(anchor: A, years: i64) -> Result<Relative<A::Tz>, PeriodError> {
+    validate_non_negative(years, "years", "years_before")?;
     let months_i64 = years.checked_mul(12).ok_or(PeriodError::Overflow {
         unit: "years",
         value: years,
@@ -55,9 +126,8 @@ pub fn years_from_now(years: i64) -> Result<Relative, PeriodError> {
         unit: "years",
         value: years,
     })?;
-    Local::now()
-        .checked_add_months(Months::new(months))
-        .map(Relative)
+    anchor
+        .add_months(Months::new(months))
         .ok_or(PeriodError::Overflow {
             unit: "years",
             value: years,
@@ -67,8 +137,9 @@ pub fn years_from_now(years: i64) -> Result<Relative, PeriodError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::MockClock;
     use crate::error::PeriodError;
-    use chrono::{Local, Months};
+    use chrono::{Local, Months, TimeZone, Utc};
 
     #[test]
     fn test_years_ago_returns_correct_date() {
@@ -177,4 +248,61 @@ mod tests {
     fn test_years_from_now_is_in_the_future() {
         assert!(years_from_now(1).unwrap().as_date() > Local::now().date_naive());
     }
+
+    #[test]
+    fn test_years_ago_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        assert_eq!(
+            years_ago_with(&clock, 1).unwrap(),
+            crate::relative::functions::month::months_ago_with(&clock, 12).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_years_from_now_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        assert_eq!(
+            years_from_now_with(&clock, 1).unwrap(),
+            crate::relative::functions::month::months_from_now_with(&clock, 12).unwrap()
+        );
+    }
+
+    // -- years_before / years_after -------------------------------------------
+
+    #[test]
+    fn test_years_before_leap_day_anchor() {
+        let date = chrono::NaiveDate::from_ymd_opt(2028, 2, 29).unwrap();
+        assert_eq!(
+            years_before(date, 1).unwrap().as_date(),
+            chrono::NaiveDate::from_ymd_opt(2027, 2, 28).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_years_after_naive_date_anchor() {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+        assert_eq!(
+            years_after(date, 5).unwrap().as_date(),
+            chrono::NaiveDate::from_ymd_opt(2031, 2, 22).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_years_after_negative_suggests_years_before() {
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 2, 22).unwrap();
+        assert_eq!(
+            years_after(date, -5).unwrap_err().to_string(),
+            "years must be positive. Did you mean years_before(5)?"
+        );
+    }
+
+    #[test]
+    fn test_years_from_now_in_matches_months_from_now_in() {
+        assert_eq!(
+            years_from_now_in(&Utc, 1).unwrap().as_date(),
+            crate::relative::functions::month::months_from_now_in(&Utc, 12)
+                .unwrap()
+                .as_date()
+        );
+    }
 }
@@ -1,4 +1,20 @@
-use chrono::{DateTime, Local};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+use crate::clock::{Clock, SystemClock};
+use crate::error::PeriodError;
+use crate::locale::{English, Locale, Style};
+use crate::now::now_with;
+use crate::relative::humanizer::{
+    DAY, DEFAULT_HUMANIZER, HOUR, Humanized, Humanizer, LiveLabel, MINUTE, MONTH, YEAR,
+};
+use crate::relative::parse::tokenize;
+use crate::relative::precise::Precise;
+use crate::relative::types::Relative;
+
+const FORMAT: &str = "humanized time";
 
 /// Returns a human-readable relative-time string for `datetime`.
 ///
@@ -28,106 +44,652 @@ use chrono::{DateTime, Local};
 ///
 /// Note: `"yesterday"` / `"tomorrow"` are triggered by elapsed seconds (22–35 h),
 /// not by calendar-day boundaries. A datetime 23 hours ago may return `"yesterday"`
-/// even if it falls on the current calendar day.
+/// even if it falls on the current calendar day; [`humanize_calendar`] compares
+/// calendar dates instead.
 ///
-/// Months are approximated as 30 days for bucketing purposes.
+/// Months are approximated as 30 days for bucketing purposes. A
+/// [`Humanizer`] built with
+/// [`calendar_months`](crate::HumanizerBuilder::calendar_months) counts
+/// calendar months and years instead.
+///
+/// This is `Humanizer::default().humanize(datetime)`; use a [`Humanizer`] to
+/// change the thresholds.
 #[inline]
 #[must_use]
 pub fn humanize(datetime: DateTime<Local>) -> String {
-    humanize_impl(datetime, Local::now())
+    humanize_with(&SystemClock, datetime)
+}
+
+/// Like [`humanize`], but relative to the instant reported by `clock`.
+#[inline]
+#[must_use]
+pub fn humanize_with(clock: &(impl Clock + ?Sized), datetime: DateTime<Local>) -> String {
+    humanize_impl(datetime, now_with(clock))
+}
+
+/// Like [`humanize`], but also returns the instant at which the label will
+/// next change, so a live view can schedule a single refresh.
+///
+/// This is `Humanizer::default().humanize_live(datetime)`.
+#[inline]
+#[must_use]
+pub fn humanize_live(datetime: DateTime<Local>) -> LiveLabel {
+    humanize_live_with(&SystemClock, datetime)
+}
+
+/// Like [`humanize_live`], but relative to the instant reported by `clock`.
+#[inline]
+#[must_use]
+pub fn humanize_live_with(clock: &(impl Clock + ?Sized), datetime: DateTime<Local>) -> LiveLabel {
+    Humanizer::DEFAULT.humanize_live_with(clock, datetime)
+}
+
+/// Like [`humanize`], but returns a [`Humanized`] that writes the phrase
+/// when displayed, without allocating.
+#[inline]
+pub fn humanized(datetime: DateTime<Local>) -> Humanized<'static> {
+    humanized_with(&SystemClock, datetime)
+}
+
+/// Like [`humanized`], but relative to the instant reported by `clock`.
+#[inline]
+pub fn humanized_with(
+    clock: &(impl Clock + ?Sized),
+    datetime: DateTime<Local>,
+) -> Humanized<'static> {
+    Humanized::new(&DEFAULT_HUMANIZER, datetime, now_with(clock))
+}
+
+/// Writes the [`humanize`] phrase for `datetime` to `out`, without
+/// allocating.
+///
+/// ```
+/// use chrono::{Duration, Local};
+/// use period::write_humanized;
+///
+/// let mut cell = String::new();
+/// for minutes in [3, 90] {
+///     cell.clear();
+///     write_humanized(&mut cell, Local::now() - Duration::minutes(minutes))?;
+///     assert!(cell.ends_with(" ago"));
+/// }
+/// # Ok::<(), std::fmt::Error>(())
+/// ```
+///
+/// # Errors
+/// Returns an error if writing to `out` fails.
+#[inline]
+pub fn write_humanized(
+    out: &mut (impl fmt::Write + ?Sized),
+    datetime: DateTime<Local>,
+) -> fmt::Result {
+    write_humanized_with(out, &SystemClock, datetime)
+}
+
+/// Like [`write_humanized`], but relative to the instant reported by
+/// `clock`.
+///
+/// # Errors
+/// Returns an error if writing to `out` fails.
+#[inline]
+pub fn write_humanized_with(
+    out: &mut (impl fmt::Write + ?Sized),
+    clock: &(impl Clock + ?Sized),
+    datetime: DateTime<Local>,
+) -> fmt::Result {
+    write!(out, "{}", humanized_with(clock, datetime))
+}
+
+/// Like [`write_humanized`], but in the language of `locale`.
+///
+/// # Errors
+/// Returns an error if writing to `out` fails.
+#[inline]
+pub fn write_humanized_with_locale(
+    out: &mut (impl fmt::Write + ?Sized),
+    locale: &(impl Locale + ?Sized),
+    datetime: DateTime<Local>,
+) -> fmt::Result {
+    write_humanized_with_locale_with(out, &SystemClock, locale, datetime)
+}
+
+/// Like [`write_humanized_with_locale`], but relative to the instant
+/// reported by `clock`.
+///
+/// # Errors
+/// Returns an error if writing to `out` fails.
+#[inline]
+pub fn write_humanized_with_locale_with(
+    out: &mut (impl fmt::Write + ?Sized),
+    clock: &(impl Clock + ?Sized),
+    locale: &(impl Locale + ?Sized),
+    datetime: DateTime<Local>,
+) -> fmt::Result {
+    write!(
+        out,
+        "{}",
+        DEFAULT_HUMANIZER.display_with_locale_with(clock, locale, datetime)
+    )
+}
+
+/// Like [`humanize`], but phrases offsets that cross midnight by local
+/// calendar dates: `"yesterday"`, `"last Tuesday"`, `"last month"`.
+///
+/// This is [`Humanizer`] in [calendar mode](crate::HumanizerBuilder::calendar),
+/// which documents the full table.
+///
+/// ```
+/// use chrono::{Duration, Local};
+/// use period::humanize_calendar;
+///
+/// let label = humanize_calendar(Local::now() - Duration::days(1));
+/// assert_eq!(label, "yesterday");
+/// ```
+#[inline]
+#[must_use]
+pub fn humanize_calendar(datetime: DateTime<Local>) -> String {
+    humanize_calendar_with(&SystemClock, datetime)
+}
+
+/// Like [`humanize_calendar`], but relative to the instant reported by
+/// `clock`.
+#[inline]
+#[must_use]
+pub fn humanize_calendar_with(clock: &(impl Clock + ?Sized), datetime: DateTime<Local>) -> String {
+    Humanizer::CALENDAR.humanize_with(clock, datetime)
+}
+
+/// Like [`humanize`], but in up to two units: `"2 hours, 5 minutes ago"`.
+///
+/// This is `Precise::new().humanize(datetime)`; use a [`Precise`] to change
+/// the number of units, the smallest unit, rounding and how units are joined.
+#[inline]
+#[must_use]
+pub fn humanize_precise(datetime: DateTime<Local>) -> String {
+    humanize_precise_with(&SystemClock, datetime)
+}
+
+/// Like [`humanize_precise`], but relative to the instant reported by
+/// `clock`.
+#[inline]
+#[must_use]
+pub fn humanize_precise_with(clock: &(impl Clock + ?Sized), datetime: DateTime<Local>) -> String {
+    Precise::new().humanize_with(clock, datetime)
+}
+
+/// Like [`humanize`], but with the words of `style`: `"3 min. ago"` or
+/// `"3m ago"` instead of `"3 minutes ago"`.
+///
+/// Single-unit buckets are counted as one, and `"just now"`, `"yesterday"`
+/// and `"tomorrow"` read the same in every style. For another language use a
+/// [`Humanizer`] built with [`style`](crate::HumanizerBuilder::style).
+///
+/// ```
+/// use chrono::{Duration, Local};
+/// use period::{Style, humanize_styled};
+///
+/// let then = Local::now() - Duration::minutes(3);
+/// assert_eq!(humanize_styled(Style::Short, then), "3 min. ago");
+/// assert_eq!(humanize_styled(Style::Narrow, then), "3m ago");
+/// ```
+#[inline]
+#[must_use]
+pub fn humanize_styled(style: Style, datetime: DateTime<Local>) -> String {
+    Humanizer::styled(style).humanize(datetime)
+}
+
+/// Like [`humanize_styled`], but relative to the instant reported by
+/// `clock`.
+#[inline]
+#[must_use]
+pub fn humanize_styled_with(
+    clock: &(impl Clock + ?Sized),
+    style: Style,
+    datetime: DateTime<Local>,
+) -> String {
+    Humanizer::styled(style).humanize_with(clock, datetime)
+}
+
+/// Phrases the length of `duration` on the [`humanize`] ladder, without
+/// `"ago"` or `"in"`: `"a few seconds"`, `"about a minute"`, `"3 hours"`.
+///
+/// The sign of `duration` is ignored. For another ladder or language use
+/// [`Humanizer::humanize_duration`] or
+/// [`Humanizer::humanize_duration_with_locale`].
+///
+/// ```
+/// use chrono::Duration;
+/// use period::humanize_duration;
+///
+/// assert_eq!(humanize_duration(Duration::seconds(75)), "about a minute");
+/// assert_eq!(humanize_duration(Duration::hours(3)), "3 hours");
+/// assert_eq!(humanize_duration(Duration::days(-2)), "2 days");
+/// ```
+#[inline]
+#[must_use]
+pub fn humanize_duration(duration: Duration) -> String {
+    Humanizer::DEFAULT.humanize_duration(duration)
+}
+
+/// Like [`humanize`], but in the language of `locale`.
+///
+/// The buckets are the same as for [`humanize`]; only the words change.
+///
+/// ```
+/// use chrono::{Duration, Local};
+/// use period::{German, Russian, humanize_with_locale};
+///
+/// let then = Local::now() - Duration::minutes(5);
+/// assert_eq!(humanize_with_locale(&German, then), "vor 5 Minuten");
+/// assert_eq!(humanize_with_locale(&Russian, then), "5 минут назад");
+/// ```
+#[inline]
+#[must_use]
+pub fn humanize_with_locale(locale: &(impl Locale + ?Sized), datetime: DateTime<Local>) -> String {
+    Humanizer::DEFAULT.humanize_with_locale(locale, datetime)
+}
+
+/// Like [`humanize_with_locale`], but relative to the instant reported by
+/// `clock`.
+#[inline]
+#[must_use]
+pub fn humanize_with_locale_with(
+    clock: &(impl Clock + ?Sized),
+    locale: &(impl Locale + ?Sized),
+    datetime: DateTime<Local>,
+) -> String {
+    Humanizer::DEFAULT.humanize_with_locale_with(clock, locale, datetime)
+}
+
+/// Like [`humanize`], for a Unix timestamp in seconds (negative values are
+/// before 1970).
+///
+/// # Errors
+/// Returns [`PeriodError::Overflow`] if the timestamp is out of range.
+#[inline]
+pub fn humanize_timestamp(seconds: i64) -> Result<String, PeriodError> {
+    humanize_timestamp_with(&SystemClock, seconds)
+}
+
+/// Like [`humanize_timestamp`], but relative to the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`humanize_timestamp`].
+#[inline]
+pub fn humanize_timestamp_with(
+    clock: &(impl Clock + ?Sized),
+    seconds: i64,
+) -> Result<String, PeriodError> {
+    let datetime = Relative::from_unix_seconds(seconds)?.as_datetime();
+    Ok(humanize_with(clock, datetime))
+}
+
+/// Date and time values that can be phrased relative to now.
+///
+/// Implemented for:
+///
+/// - [`DateTime<Tz>`] for any timezone ([`Utc`], [`FixedOffset`], [`Local`],
+///   …), [`SystemTime`] and [`Relative<Tz>`] — instants, phrased exactly as
+///   [`humanize`] phrases them.
+/// - [`NaiveDateTime`] — local wall-clock time, compared with the local wall
+///   clock now, so a DST transition in between does not shift the phrase.
+/// - [`NaiveDate`] — a whole day, compared with today's local date.
+///   Differences are counted in calendar days, so the phrase never depends
+///   on the time of day: `"today"`, `"yesterday"`, `"3 days ago"`,
+///   `"in a month"`.
+///
+/// ```
+/// use chrono::{Duration, Utc};
+/// use period::{Humanize, today};
+///
+/// assert_eq!((Utc::now() - Duration::hours(3)).humanize(), "3 hours ago");
+/// assert_eq!(today().humanize(), "today");
+/// assert_eq!((today() - Duration::days(3)).humanize(), "3 days ago");
+/// ```
+///
+/// [`FixedOffset`]: chrono::FixedOffset
+pub trait Humanize {
+    /// Phrases `self` relative to the current time.
+    #[inline]
+    #[must_use]
+    fn humanize(&self) -> String {
+        self.humanize_with(&SystemClock)
+    }
+
+    /// Like [`humanize`](Humanize::humanize), but relative to the instant
+    /// reported by `clock`.
+    #[must_use]
+    fn humanize_with<C: Clock + ?Sized>(&self, clock: &C) -> String;
+}
+
+impl<Tz: TimeZone> Humanize for DateTime<Tz> {
+    #[inline]
+    fn humanize_with<C: Clock + ?Sized>(&self, clock: &C) -> String {
+        humanize_with(clock, self.with_timezone(&Local))
+    }
+}
+
+impl<Tz: TimeZone> Humanize for Relative<Tz> {
+    #[inline]
+    fn humanize_with<C: Clock + ?Sized>(&self, clock: &C) -> String {
+        self.0.humanize_with(clock)
+    }
+}
+
+impl Humanize for SystemTime {
+    fn humanize_with<C: Clock + ?Sized>(&self, clock: &C) -> String {
+        // Saturate instead of panicking like `From<SystemTime>` does for
+        // times beyond chrono's range.
+        let datetime = match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => Duration::from_std(after)
+                .ok()
+                .and_then(|d| DateTime::UNIX_EPOCH.checked_add_signed(d))
+                .unwrap_or(DateTime::<Utc>::MAX_UTC),
+            Err(before) => Duration::from_std(before.duration())
+                .ok()
+                .and_then(|d| DateTime::UNIX_EPOCH.checked_sub_signed(d))
+                .unwrap_or(DateTime::<Utc>::MIN_UTC),
+        };
+        datetime.humanize_with(clock)
+    }
+}
+
+impl Humanize for NaiveDateTime {
+    fn humanize_with<C: Clock + ?Sized>(&self, clock: &C) -> String {
+        let now = now_with(clock);
+        let offset = now.naive_local().signed_duration_since(*self);
+        let datetime = now
+            .checked_sub_signed(offset)
+            .unwrap_or(if offset > Duration::zero() {
+                DateTime::<Utc>::MIN_UTC.with_timezone(&Local)
+            } else {
+                DateTime::<Utc>::MAX_UTC.with_timezone(&Local)
+            });
+        Humanizer::DEFAULT.format(&English, datetime, now)
+    }
+}
+
+impl Humanize for NaiveDate {
+    fn humanize_with<C: Clock + ?Sized>(&self, clock: &C) -> String {
+        let today = now_with(clock).date_naive();
+        let days = self.signed_duration_since(today).num_days();
+        Humanizer::DEFAULT.format_days(&English, days)
+    }
 }
 
 fn humanize_impl(datetime: DateTime<Local>, now: DateTime<Local>) -> String {
-    const MINUTE: i64 = 60;
-    const HOUR: i64 = 3_600;
-    const DAY: i64 = 86_400;
-    const MONTH: i64 = 30 * DAY;
-    const YEAR: i64 = 365 * DAY;
-
-    let secs = now.signed_duration_since(datetime).num_seconds();
-    let is_past = secs >= 0;
-    let abs = secs.saturating_abs();
-
-    if abs < 30 {
-        "just now".to_string()
-    } else if abs < 90 {
-        if is_past {
-            "a minute ago".to_string()
-        } else {
-            "in a minute".to_string()
-        }
-    } else if abs < 45 * MINUTE {
-        let n = abs / MINUTE;
-        let unit = if n == 1 { "minute" } else { "minutes" };
-        if is_past {
-            format!("{n} {unit} ago")
-        } else {
-            format!("in {n} {unit}")
-        }
-    } else if abs < 90 * MINUTE {
-        if is_past {
-            "an hour ago".to_string()
-        } else {
-            "in an hour".to_string()
-        }
-    } else if abs < 22 * HOUR {
-        let n = abs / HOUR;
-        let unit = if n == 1 { "hour" } else { "hours" };
-        if is_past {
-            format!("{n} {unit} ago")
-        } else {
-            format!("in {n} {unit}")
-        }
-    } else if abs < 36 * HOUR {
-        if is_past {
-            "yesterday".to_string()
-        } else {
-            "tomorrow".to_string()
+    Humanized::new(&DEFAULT_HUMANIZER, datetime, now).to_string()
+}
+
+/// The span of instants a [`humanize`] label stands for, as returned by
+/// [`parse_humanized`].
+///
+/// Every instant from [`earliest`](HumanizedRange::earliest) to
+/// [`latest`](HumanizedRange::latest) inclusive, at whole seconds from the
+/// reference time, humanizes back to the parsed label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HumanizedRange {
+    instant: Relative,
+    earliest: Relative,
+    latest: Relative,
+}
+
+impl HumanizedRange {
+    /// The representative instant: the middle of the bucket.
+    #[must_use]
+    #[inline]
+    pub fn instant(&self) -> Relative {
+        self.instant
+    }
+
+    /// The earliest instant that produces the label.
+    #[must_use]
+    #[inline]
+    pub fn earliest(&self) -> Relative {
+        self.earliest
+    }
+
+    /// The latest instant that produces the label.
+    #[must_use]
+    #[inline]
+    pub fn latest(&self) -> Relative {
+        self.latest
+    }
+
+    /// Returns `true` if `datetime` lies within the bucket.
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, datetime: &DateTime<Local>) -> bool {
+        self.earliest.as_datetime() <= *datetime && *datetime <= self.latest.as_datetime()
+    }
+}
+
+/// Recovers the instant behind a [`humanize`] label such as `"3 hours ago"`,
+/// `"yesterday"` or `"in a month"`, measured from the current local date-time.
+///
+/// Accepts every string [`humanize`] can produce, ignoring case and extra
+/// whitespace. Since a label covers a whole bucket (`"3 hours ago"` is
+/// anything from 3 hours to 3 hours 59 minutes 59 seconds ago), the result
+/// carries the bucket bounds along with a representative instant in the
+/// middle, so that `humanize(range.instant().as_datetime())` returns the
+/// original label. Only the default [`Humanizer`] table is recognized.
+///
+/// # Errors
+/// Returns [`PeriodError::Parse`] if `input` is not a label [`humanize`] can
+/// produce, including counts it never uses (`"50 minutes ago"` is always
+/// `"an hour ago"`) and units that disagree with their count or article
+/// (`"3 hour ago"`, `"an minute ago"`), and [`PeriodError::Overflow`] if the
+/// bucket is out of range.
+///
+/// # Example
+///
+/// ```rust
+/// use period::{humanize, parse_humanized};
+///
+/// # fn main() -> Result<(), period::PeriodError> {
+/// let range = parse_humanized("3 hours ago")?;
+/// assert_eq!(humanize(range.instant().as_datetime()), "3 hours ago");
+/// assert!(range.earliest() < range.latest());
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn parse_humanized(input: &str) -> Result<HumanizedRange, PeriodError> {
+    parse_humanized_with(&SystemClock, input)
+}
+
+/// Like [`parse_humanized`], but relative to the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`parse_humanized`].
+#[inline]
+pub fn parse_humanized_with(
+    clock: &(impl Clock + ?Sized),
+    input: &str,
+) -> Result<HumanizedRange, PeriodError> {
+    parse_humanized_impl(input, now_with(clock))
+}
+
+fn parse_humanized_impl(input: &str, now: DateTime<Local>) -> Result<HumanizedRange, PeriodError> {
+    let bucket = humanized_bucket(input)?;
+    let at = |offset: i64| {
+        Duration::try_seconds(offset)
+            .and_then(|offset| now.checked_add_signed(offset))
+            .map(Relative)
+            .ok_or(PeriodError::Overflow {
+                unit: bucket.unit,
+                value: bucket.count,
+            })
+    };
+    Ok(HumanizedRange {
+        instant: at(bucket.lower + (bucket.upper - bucket.lower) / 2)?,
+        earliest: at(bucket.lower)?,
+        latest: at(bucket.upper)?,
+    })
+}
+
+/// A humanize bucket as signed offsets in seconds from now (`datetime - now`),
+/// both inclusive. `unit` and `count` are reported if it is out of range.
+struct Bucket {
+    lower: i64,
+    upper: i64,
+    unit: &'static str,
+    count: i64,
+}
+
+/// The numeric buckets of `humanize_impl` for one unit.
+struct UnitBuckets {
+    name: &'static str,
+    size: i64,
+    /// Range of absolute offsets covered by `"N <unit>"` labels.
+    numeric: (i64, i64),
+    /// The article and range of the `"a <unit>"` / `"an <unit>"` label, if
+    /// any.
+    article: Option<(&'static str, i64, i64)>,
+}
+
+fn unit_buckets(unit: &str) -> Option<UnitBuckets> {
+    let (name, size, numeric, article) = match unit.strip_suffix('s').unwrap_or(unit) {
+        "minute" => (
+            "minutes",
+            MINUTE,
+            (90, 45 * MINUTE - 1),
+            Some(("a", 30, 89)),
+        ),
+        "hour" => (
+            "hours",
+            HOUR,
+            (90 * MINUTE, 22 * HOUR - 1),
+            Some(("an", 45 * MINUTE, 90 * MINUTE - 1)),
+        ),
+        "day" => ("days", DAY, (36 * HOUR, 25 * DAY - 1), None),
+        "month" => (
+            "months",
+            MONTH,
+            (45 * DAY, 10 * MONTH - 1),
+            Some(("a", 25 * DAY, 45 * DAY - 1)),
+        ),
+        "year" => (
+            "years",
+            YEAR,
+            (18 * MONTH, i64::MAX),
+            Some(("a", 10 * MONTH, 18 * MONTH - 1)),
+        ),
+        _ => return None,
+    };
+    Some(UnitBuckets {
+        name,
+        size,
+        numeric,
+        article,
+    })
+}
+
+fn humanized_bucket(input: &str) -> Result<Bucket, PeriodError> {
+    let tokens = tokenize(input);
+    let words: Vec<String> = tokens.iter().map(|(_, w)| w.to_ascii_lowercase()).collect();
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    let fixed = |lower, upper| Bucket {
+        lower,
+        upper,
+        unit: "hours",
+        count: 0,
+    };
+    let (past, amount, unit) = match words.as_slice() {
+        ["just", "now"] => return Ok(fixed(-29, 29)),
+        ["yesterday"] => return Ok(fixed(-(36 * HOUR - 1), -22 * HOUR)),
+        ["tomorrow"] => return Ok(fixed(22 * HOUR, 36 * HOUR - 1)),
+        ["in", _, _] => (false, 1, 2),
+        [_, _, "ago"] => (true, 0, 1),
+        [] => return Err(humanized_error(input.len(), "expected a humanized time")),
+        _ => {
+            return Err(humanized_error(
+                tokens[0].0,
+                "expected 'just now', 'yesterday', 'tomorrow', 'in N <unit>' or 'N <unit> ago'",
+            ));
         }
-    } else if abs < 25 * DAY {
-        let n = abs / DAY;
-        let unit = if n == 1 { "day" } else { "days" };
-        if is_past {
-            format!("{n} {unit} ago")
-        } else {
-            format!("in {n} {unit}")
+    };
+    let (amount_pos, amount) = (tokens[amount].0, words[amount]);
+    let (unit_pos, plural) = (tokens[unit].0, words[unit].ends_with('s'));
+    let buckets = unit_buckets(words[unit]).ok_or_else(|| {
+        humanized_error(unit_pos, "expected minutes, hours, days, months or years")
+    })?;
+
+    let (low, high, count) = if amount == "a" || amount == "an" {
+        let (article, low, high) = buckets
+            .article
+            .ok_or_else(|| humanized_error(amount_pos, "humanize never writes 'a day'"))?;
+        if amount != article {
+            return Err(humanized_error(
+                amount_pos,
+                "expected 'an hour' or 'a <unit>'",
+            ));
         }
-    } else if abs < 45 * DAY {
-        if is_past {
-            "a month ago".to_string()
-        } else {
-            "in a month".to_string()
+        (low, high, 1)
+    } else {
+        if !amount.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(humanized_error(
+                amount_pos,
+                "expected a number, 'a' or 'an'",
+            ));
         }
-    } else if abs < 10 * MONTH {
-        let n = abs / MONTH;
-        let unit = if n == 1 { "month" } else { "months" };
-        if is_past {
-            format!("{n} {unit} ago")
-        } else {
-            format!("in {n} {unit}")
+        let overflow = |count| PeriodError::Overflow {
+            unit: buckets.name,
+            value: count,
+        };
+        let count = amount.parse::<i64>().map_err(|_| overflow(i64::MAX))?;
+        let start = count.checked_mul(buckets.size).ok_or(overflow(count))?;
+        let low = start.max(buckets.numeric.0);
+        let high = start
+            .saturating_add(buckets.size - 1)
+            .min(buckets.numeric.1);
+        if low > high {
+            return Err(humanized_error(
+                amount_pos,
+                "humanize never uses this count for this unit",
+            ));
         }
-    } else if abs < 18 * MONTH {
-        if is_past {
-            "a year ago".to_string()
-        } else {
-            "in a year".to_string()
+        (low, high, count)
+    };
+    match (count, plural) {
+        (1, true) => {
+            return Err(humanized_error(
+                unit_pos,
+                "expected a singular unit after 'a', 'an' or 1",
+            ));
         }
-    } else {
-        let n = abs / YEAR;
-        let unit = if n == 1 { "year" } else { "years" };
-        if is_past {
-            format!("{n} {unit} ago")
-        } else {
-            format!("in {n} {unit}")
+        (2.., false) => {
+            return Err(humanized_error(
+                unit_pos,
+                "expected a plural unit after a count above 1",
+            ));
         }
+        _ => {}
+    }
+
+    let (lower, upper) = if past { (-high, -low) } else { (low, high) };
+    Ok(Bucket {
+        lower,
+        upper,
+        unit: buckets.name,
+        count,
+    })
+}
+
+fn humanized_error(position: usize, reason: &'static str) -> PeriodError {
+    PeriodError::Parse {
+        format: FORMAT,
+        position,
+        reason,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::{Clock, MockClock};
     use crate::relative::functions::{days_ago, hours_from_now};
-    use chrono::Duration;
+    use chrono::{Duration, TimeZone, Utc};
 
     /// Call `humanize_impl` with a single captured `now`, so both the reference
     /// datetime and the comparison point are derived from the same instant.
@@ -463,4 +1025,456 @@ mod tests {
             "expected 'in N years', got: {result}"
         );
     }
+
+    // -- humanize_with --------------------------------------------------------
+
+    #[test]
+    fn test_humanize_with_fixed_clock_is_deterministic() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let dt = clock.now().with_timezone(&Local) - Duration::seconds(3 * 86_400);
+        assert_eq!(humanize_with(&clock, dt), "3 days ago");
+    }
+
+    #[test]
+    fn test_humanize_with_exact_boundary() {
+        // With a pinned clock, exact thresholds are safe to assert on.
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let now = clock.now().with_timezone(&Local);
+        assert_eq!(
+            humanize_with(&clock, now - Duration::seconds(29)),
+            "just now"
+        );
+        assert_eq!(
+            humanize_with(&clock, now - Duration::seconds(30)),
+            "a minute ago"
+        );
+    }
+
+    #[test]
+    fn test_humanize_with_locale_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let now = clock.now().with_timezone(&Local);
+        assert_eq!(
+            humanize_with_locale_with(&clock, &crate::German, now - Duration::seconds(29)),
+            "gerade eben"
+        );
+        assert_eq!(
+            humanize_with_locale_with(&clock, &crate::German, now - Duration::minutes(5)),
+            "vor 5 Minuten"
+        );
+        assert_eq!(
+            humanize_with_locale_with(&clock, &English, now + Duration::days(2)),
+            "in 2 days"
+        );
+    }
+
+    #[test]
+    fn test_humanize_styled_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let then = clock.now().with_timezone(&Local) - Duration::minutes(3);
+        assert_eq!(
+            humanize_styled_with(&clock, Style::Long, then),
+            "3 minutes ago"
+        );
+        assert_eq!(
+            humanize_styled_with(&clock, Style::Short, then),
+            "3 min. ago"
+        );
+        assert_eq!(humanize_styled_with(&clock, Style::Narrow, then), "3m ago");
+    }
+
+    // -- humanize_timestamp ---------------------------------------------------
+
+    #[test]
+    fn test_humanize_timestamp_with_fixed_clock() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let now = clock.now().timestamp();
+        assert_eq!(
+            humanize_timestamp_with(&clock, now - 3 * 3_600).unwrap(),
+            "3 hours ago"
+        );
+        assert_eq!(
+            humanize_timestamp_with(&clock, now + 2 * 86_400).unwrap(),
+            "in 2 days"
+        );
+    }
+
+    #[test]
+    fn test_humanize_timestamp_before_1970() {
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        assert_eq!(
+            humanize_timestamp_with(&clock, -86_400).unwrap(),
+            "56 years ago"
+        );
+    }
+
+    #[test]
+    fn test_humanize_timestamp_system_clock() {
+        let now = Local::now().timestamp();
+        assert_eq!(humanize_timestamp(now - 5 * 60).unwrap(), "5 minutes ago");
+    }
+
+    #[test]
+    fn test_humanize_trait_instants() {
+        let clock = fixed_clock();
+        let then = clock.now() - Duration::hours(3);
+        assert_eq!(then.humanize_with(&clock), "3 hours ago");
+        assert_eq!(then.fixed_offset().humanize_with(&clock), "3 hours ago");
+        assert_eq!(
+            then.with_timezone(&Local).humanize_with(&clock),
+            "3 hours ago"
+        );
+        assert_eq!(Relative(then).humanize_with(&clock), "3 hours ago");
+        assert_eq!(
+            SystemTime::from(clock.now() + Duration::days(2)).humanize_with(&clock),
+            "in 2 days"
+        );
+    }
+
+    #[test]
+    fn test_humanize_trait_system_time_out_of_chrono_range() {
+        let clock = fixed_clock();
+        let before = UNIX_EPOCH - std::time::Duration::from_hours(24);
+        assert_eq!(before.humanize_with(&clock), "56 years ago");
+        let far = UNIX_EPOCH + std::time::Duration::from_secs(u64::MAX / 4);
+        let label = far.humanize_with(&clock);
+        assert!(
+            label.starts_with("in ") && label.ends_with(" years"),
+            "{label}"
+        );
+    }
+
+    #[test]
+    fn test_humanize_trait_naive_datetime_is_wall_clock() {
+        let clock = fixed_clock();
+        let wall = now_with(&clock).naive_local();
+        assert_eq!(
+            (wall - Duration::hours(3)).humanize_with(&clock),
+            "3 hours ago"
+        );
+        assert_eq!(
+            (wall + Duration::minutes(5)).humanize_with(&clock),
+            "in 5 minutes"
+        );
+        assert_eq!(wall.humanize_with(&clock), "just now");
+    }
+
+    #[test]
+    fn test_humanize_trait_naive_date_counts_days() {
+        let clock = fixed_clock();
+        let today = now_with(&clock).date_naive();
+        for (days, expected) in [
+            (0, "today"),
+            (-1, "yesterday"),
+            (1, "tomorrow"),
+            (-3, "3 days ago"),
+            (3, "in 3 days"),
+            (-24, "24 days ago"),
+            (-40, "a month ago"),
+            (100, "in 3 months"),
+            (-400, "a year ago"),
+            (-800, "2 years ago"),
+        ] {
+            assert_eq!(
+                (today + Duration::days(days)).humanize_with(&clock),
+                expected,
+                "{days} days"
+            );
+        }
+    }
+
+    #[test]
+    fn test_humanize_trait_naive_date_ignores_time_of_day() {
+        // Late in the evening, yesterday's date is still "yesterday" and not
+        // "2 days ago", however many hours have passed since its midnight.
+        let clock = MockClock::new(
+            &Local
+                .with_ymd_and_hms(2026, 2, 22, 23, 59, 0)
+                .unwrap()
+                .to_utc(),
+        );
+        let today = now_with(&clock).date_naive();
+        assert_eq!(today.pred_opt().unwrap().humanize_with(&clock), "yesterday");
+        assert_eq!(today.succ_opt().unwrap().humanize_with(&clock), "tomorrow");
+    }
+
+    #[test]
+    fn test_humanize_trait_system_clock() {
+        assert_eq!(crate::now::today().humanize(), "today");
+        assert_eq!(
+            (Utc::now() - Duration::minutes(5)).humanize(),
+            "5 minutes ago"
+        );
+    }
+
+    /// A fixed-size buffer, to show the writers never need to allocate.
+    struct Buffer {
+        bytes: [u8; 32],
+        len: usize,
+    }
+
+    impl fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_humanized_into_fixed_buffer() {
+        let clock = fixed_clock();
+        let now = now_with(&clock);
+        let mut buffer = Buffer {
+            bytes: [0; 32],
+            len: 0,
+        };
+        write_humanized_with(&mut buffer, &clock, now - Duration::hours(5)).unwrap();
+        assert_eq!(&buffer.bytes[..buffer.len], b"5 hours ago");
+
+        buffer.len = 30;
+        assert!(write_humanized_with(&mut buffer, &clock, now).is_err());
+    }
+
+    #[test]
+    fn test_write_humanized_with_locale_with_fixed_clock() {
+        let clock = fixed_clock();
+        let now = now_with(&clock);
+        let mut out = String::new();
+        write_humanized_with_locale_with(
+            &mut out,
+            &clock,
+            &crate::German,
+            now - Duration::hours(5),
+        )
+        .unwrap();
+        assert_eq!(out, "vor 5 Stunden");
+
+        out.clear();
+        write_humanized_with_locale(&mut out, &crate::German, Local::now() - Duration::days(3))
+            .unwrap();
+        assert_eq!(out, "vor 3 Tagen");
+    }
+
+    #[test]
+    fn test_humanized_matches_humanize() {
+        let clock = fixed_clock();
+        let now = now_with(&clock);
+        for secs in [0, 60, 95, 3_000, 80_000, 100_000, 3_000_000, 100_000_000] {
+            let then = now + Duration::seconds(secs);
+            assert_eq!(
+                humanized_with(&clock, then).to_string(),
+                humanize_with(&clock, then)
+            );
+            let mut out = String::new();
+            write_humanized_with(&mut out, &clock, then).unwrap();
+            assert_eq!(out, humanize_with(&clock, then));
+        }
+    }
+
+    #[test]
+    fn test_humanize_live_with_clock() {
+        let clock = fixed_clock();
+        let then = now_with(&clock) - Duration::hours(5);
+        let live = humanize_live_with(&clock, then);
+        assert_eq!(live.label(), "5 hours ago");
+        assert_eq!(
+            live.next_change().unwrap().as_datetime(),
+            then + Duration::hours(6)
+        );
+        assert_eq!(humanize_live(then).label(), humanize(then));
+    }
+
+    #[test]
+    fn test_humanized_system_clock() {
+        let then = Local::now() - Duration::minutes(5);
+        assert_eq!(humanized(then).to_string(), "5 minutes ago");
+        let mut out = String::new();
+        write_humanized(&mut out, then).unwrap();
+        assert_eq!(out, "5 minutes ago");
+    }
+
+    #[test]
+    fn test_humanize_timestamp_out_of_range_is_overflow() {
+        assert_eq!(
+            humanize_timestamp(i64::MAX).unwrap_err(),
+            PeriodError::Overflow {
+                unit: "seconds",
+                value: i64::MAX
+            }
+        );
+    }
+
+    // -- parse_humanized ------------------------------------------------------
+
+    /// Every label `humanize_impl` can produce, years capped at 50.
+    fn all_labels() -> Vec<String> {
+        let mut labels = vec![
+            "just now".to_string(),
+            "yesterday".to_string(),
+            "tomorrow".to_string(),
+        ];
+        for article in ["a minute", "an hour", "a month", "a year"] {
+            labels.push(format!("{article} ago"));
+            labels.push(format!("in {article}"));
+        }
+        for (unit, max) in [
+            ("minute", 44),
+            ("hour", 21),
+            ("day", 24),
+            ("month", 9),
+            ("year", 50),
+        ] {
+            for n in 1..=max {
+                let unit = if n == 1 {
+                    unit.to_string()
+                } else {
+                    format!("{unit}s")
+                };
+                labels.push(format!("{n} {unit} ago"));
+                labels.push(format!("in {n} {unit}"));
+            }
+        }
+        labels
+    }
+
+    fn fixed_clock() -> MockClock {
+        MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn test_parse_humanized_round_trips_every_label() {
+        let clock = fixed_clock();
+        for label in all_labels() {
+            let range = parse_humanized_with(&clock, &label).unwrap();
+            assert_eq!(humanize_with(&clock, range.instant().as_datetime()), label);
+            assert_eq!(humanize_with(&clock, range.earliest().as_datetime()), label);
+            assert_eq!(humanize_with(&clock, range.latest().as_datetime()), label);
+        }
+    }
+
+    #[test]
+    fn test_parse_humanized_bounds_are_tight() {
+        let clock = fixed_clock();
+        let second = Duration::seconds(1);
+        for label in all_labels() {
+            let range = parse_humanized_with(&clock, &label).unwrap();
+            let before = range.earliest().as_datetime() - second;
+            let after = range.latest().as_datetime() + second;
+            assert_ne!(humanize_with(&clock, before), label, "{label}: earliest");
+            assert_ne!(humanize_with(&clock, after), label, "{label}: latest");
+        }
+    }
+
+    #[test]
+    fn test_parse_humanized_instant_is_inside_bounds() {
+        let range = parse_humanized_with(&fixed_clock(), "in 3 days").unwrap();
+        assert!(range.earliest() <= range.instant() && range.instant() <= range.latest());
+        assert!(range.contains(&range.instant().as_datetime()));
+        assert!(!range.contains(&(range.latest().as_datetime() + Duration::seconds(1))));
+    }
+
+    #[test]
+    fn test_parse_humanized_just_now_spans_both_directions() {
+        let clock = fixed_clock();
+        let range = parse_humanized_with(&clock, "just now").unwrap();
+        assert_eq!(range.instant().as_datetime(), clock.now());
+        assert_eq!(
+            range.earliest().as_datetime(),
+            clock.now() - Duration::seconds(29)
+        );
+        assert_eq!(
+            range.latest().as_datetime(),
+            clock.now() + Duration::seconds(29)
+        );
+    }
+
+    #[test]
+    fn test_parse_humanized_ignores_case_and_whitespace() {
+        let clock = fixed_clock();
+        assert_eq!(
+            parse_humanized_with(&clock, "  3 Hours   AGO ").unwrap(),
+            parse_humanized_with(&clock, "3 hours ago").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_humanized_rejects_labels_humanize_never_emits() {
+        let clock = fixed_clock();
+        for (input, position, reason) in [
+            ("", 0, "expected a humanized time"),
+            (
+                "50 minutes ago",
+                0,
+                "humanize never uses this count for this unit",
+            ),
+            (
+                "in 0 days",
+                3,
+                "humanize never uses this count for this unit",
+            ),
+            (
+                "in 10 months",
+                3,
+                "humanize never uses this count for this unit",
+            ),
+            ("a day ago", 0, "humanize never writes 'a day'"),
+            (
+                "3 weeks ago",
+                2,
+                "expected minutes, hours, days, months or years",
+            ),
+            ("x hours ago", 0, "expected a number, 'a' or 'an'"),
+            (
+                "3 hour ago",
+                2,
+                "expected a plural unit after a count above 1",
+            ),
+            (
+                "in 2 day",
+                5,
+                "expected a plural unit after a count above 1",
+            ),
+            (
+                "1 hours ago",
+                2,
+                "expected a singular unit after 'a', 'an' or 1",
+            ),
+            (
+                "a minutes ago",
+                2,
+                "expected a singular unit after 'a', 'an' or 1",
+            ),
+            ("in an months", 3, "expected 'an hour' or 'a <unit>'"),
+            ("an minute ago", 0, "expected 'an hour' or 'a <unit>'"),
+            ("a hour ago", 0, "expected 'an hour' or 'a <unit>'"),
+            (
+                "3 hours later",
+                0,
+                "expected 'just now', 'yesterday', 'tomorrow', 'in N <unit>' or 'N <unit> ago'",
+            ),
+        ] {
+            assert_eq!(
+                parse_humanized_with(&clock, input).unwrap_err(),
+                PeriodError::Parse {
+                    format: FORMAT,
+                    position,
+                    reason
+                },
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_humanized_out_of_range_is_overflow() {
+        assert!(matches!(
+            parse_humanized_with(&fixed_clock(), "in 999999999 years").unwrap_err(),
+            PeriodError::Overflow { unit: "years", .. }
+        ));
+    }
 }
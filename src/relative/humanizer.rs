@@ -0,0 +1,1552 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use chrono::{DateTime, Datelike, Duration, Local, Months, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::clock::{Clock, SystemClock};
+use crate::error::PeriodError;
+use crate::locale::{English, Locale, Style, Tense, TimeUnit, write_pattern, write_units};
+use crate::now::now_with;
+use crate::relative::anchor::localize;
+use crate::relative::types::Relative;
+
+pub(crate) const MINUTE: i64 = TimeUnit::Minute.seconds();
+pub(crate) const HOUR: i64 = TimeUnit::Hour.seconds();
+pub(crate) const DAY: i64 = TimeUnit::Day.seconds();
+pub(crate) const MONTH: i64 = TimeUnit::Month.seconds();
+pub(crate) const YEAR: i64 = TimeUnit::Year.seconds();
+
+/// One bucket of a [`Humanizer`] ladder: how an offset below the bucket's
+/// threshold is phrased.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rung {
+    /// `"just now"`, in either direction.
+    JustNow,
+    /// A single unit without a number (`"a minute ago"`, `"in an hour"`).
+    Single(TimeUnit),
+    /// Whole units, rounded down (`"3 minutes ago"`, `"in 3 minutes"`).
+    Count(TimeUnit),
+    /// `"yesterday"` or `"tomorrow"`.
+    AdjacentDay,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Step {
+    rung: Rung,
+    below: i64,
+}
+
+const DEFAULT_LADDER: [Step; 10] = [
+    Step {
+        rung: Rung::JustNow,
+        below: 30,
+    },
+    Step {
+        rung: Rung::Single(TimeUnit::Minute),
+        below: 90,
+    },
+    Step {
+        rung: Rung::Count(TimeUnit::Minute),
+        below: 45 * MINUTE,
+    },
+    Step {
+        rung: Rung::Single(TimeUnit::Hour),
+        below: 90 * MINUTE,
+    },
+    Step {
+        rung: Rung::Count(TimeUnit::Hour),
+        below: 22 * HOUR,
+    },
+    Step {
+        rung: Rung::AdjacentDay,
+        below: 36 * HOUR,
+    },
+    Step {
+        rung: Rung::Count(TimeUnit::Day),
+        below: 25 * DAY,
+    },
+    Step {
+        rung: Rung::Single(TimeUnit::Month),
+        below: 45 * DAY,
+    },
+    Step {
+        rung: Rung::Count(TimeUnit::Month),
+        below: 10 * MONTH,
+    },
+    Step {
+        rung: Rung::Single(TimeUnit::Year),
+        below: 18 * MONTH,
+    },
+];
+
+/// Phrases offsets from now by walking a ladder of thresholds.
+///
+/// Each [`Rung`] of the ladder covers offsets from the previous rung's
+/// threshold up to (but excluding) its own; anything beyond the last
+/// threshold is counted in the top unit. The default is the table
+/// documented on [`humanize`](fn@crate::humanize), which is a thin wrapper over
+/// `Humanizer::default()`.
+///
+/// In [calendar mode](HumanizerBuilder::calendar), offsets that cross
+/// midnight are phrased by local calendar dates instead.
+///
+/// Customize it with [`Humanizer::builder`]:
+///
+/// ```
+/// # fn main() -> Result<(), period::PeriodError> {
+/// use chrono::{Duration, Local};
+/// use period::{Humanizer, Rung, TimeUnit};
+///
+/// let humanizer = Humanizer::builder()
+///     .threshold(Rung::JustNow, Duration::seconds(60))
+///     .threshold(Rung::Count(TimeUnit::Day), Duration::weeks(6))
+///     .build()?;
+///
+/// let now = Local::now();
+/// assert_eq!(humanizer.humanize(now - Duration::seconds(45)), "just now");
+/// assert_eq!(humanizer.humanize(now - Duration::days(40)), "40 days ago");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Humanizer {
+    ladder: Cow<'static, [Step]>,
+    top: TimeUnit,
+    calendar: bool,
+    calendar_months: bool,
+    style: Style,
+}
+
+impl Default for Humanizer {
+    fn default() -> Self {
+        Humanizer::DEFAULT
+    }
+}
+
+impl Humanizer {
+    pub(crate) const DEFAULT: Humanizer = Humanizer {
+        ladder: Cow::Borrowed(&DEFAULT_LADDER),
+        top: TimeUnit::Year,
+        calendar: false,
+        calendar_months: false,
+        style: Style::Long,
+    };
+
+    pub(crate) const CALENDAR: Humanizer = Humanizer {
+        calendar: true,
+        ..Humanizer::DEFAULT
+    };
+
+    pub(crate) const fn styled(style: Style) -> Humanizer {
+        Humanizer {
+            style,
+            ..Humanizer::DEFAULT
+        }
+    }
+
+    /// Starts from the default ladder, to be adjusted and then
+    /// [`build`](HumanizerBuilder::build)-validated.
+    #[inline]
+    pub fn builder() -> HumanizerBuilder {
+        HumanizerBuilder {
+            ladder: DEFAULT_LADDER.to_vec(),
+            top: TimeUnit::Year,
+            calendar: false,
+            calendar_months: false,
+            style: Style::Long,
+            error: None,
+        }
+    }
+
+    /// Phrases `datetime` relative to the current time.
+    #[inline]
+    #[must_use]
+    pub fn humanize(&self, datetime: DateTime<Local>) -> String {
+        self.display(datetime).to_string()
+    }
+
+    /// Like [`humanize`](Humanizer::humanize), but relative to the instant
+    /// reported by `clock`.
+    #[inline]
+    #[must_use]
+    pub fn humanize_with(
+        &self,
+        clock: &(impl Clock + ?Sized),
+        datetime: DateTime<Local>,
+    ) -> String {
+        self.display_with(clock, datetime).to_string()
+    }
+
+    /// Like [`humanize`](Humanizer::humanize), but in the language of
+    /// `locale`.
+    #[inline]
+    #[must_use]
+    pub fn humanize_with_locale(
+        &self,
+        locale: &(impl Locale + ?Sized),
+        datetime: DateTime<Local>,
+    ) -> String {
+        self.display_with_locale(locale, datetime).to_string()
+    }
+
+    /// Like [`humanize_with_locale`](Humanizer::humanize_with_locale), but
+    /// relative to the instant reported by `clock`.
+    #[inline]
+    #[must_use]
+    pub fn humanize_with_locale_with(
+        &self,
+        clock: &(impl Clock + ?Sized),
+        locale: &(impl Locale + ?Sized),
+        datetime: DateTime<Local>,
+    ) -> String {
+        self.display_with_locale_with(clock, locale, datetime)
+            .to_string()
+    }
+
+    /// Like [`humanize`](Humanizer::humanize), but returns a [`Humanized`]
+    /// that writes the phrase when displayed instead of allocating a
+    /// `String`.
+    #[inline]
+    pub fn display(&self, datetime: DateTime<Local>) -> Humanized<'_> {
+        self.display_with(&SystemClock, datetime)
+    }
+
+    /// Like [`display`](Humanizer::display), but relative to the instant
+    /// reported by `clock`.
+    #[inline]
+    pub fn display_with(
+        &self,
+        clock: &(impl Clock + ?Sized),
+        datetime: DateTime<Local>,
+    ) -> Humanized<'_> {
+        Humanized {
+            humanizer: self,
+            locale: &English,
+            datetime,
+            now: now_with(clock),
+        }
+    }
+
+    /// Like [`display`](Humanizer::display), but in the language of
+    /// `locale`.
+    #[inline]
+    pub fn display_with_locale<'a, L: Locale + ?Sized>(
+        &'a self,
+        locale: &'a L,
+        datetime: DateTime<Local>,
+    ) -> Humanized<'a, L> {
+        self.display_with_locale_with(&SystemClock, locale, datetime)
+    }
+
+    /// Like [`display_with_locale`](Humanizer::display_with_locale), but
+    /// relative to the instant reported by `clock`.
+    #[inline]
+    pub fn display_with_locale_with<'a, L: Locale + ?Sized>(
+        &'a self,
+        clock: &(impl Clock + ?Sized),
+        locale: &'a L,
+        datetime: DateTime<Local>,
+    ) -> Humanized<'a, L> {
+        Humanized {
+            humanizer: self,
+            locale,
+            datetime,
+            now: now_with(clock),
+        }
+    }
+
+    /// Like [`humanize`](Humanizer::humanize), but also returns when the
+    /// label will next change, so a live view can schedule one refresh
+    /// instead of re-rendering on a timer.
+    #[inline]
+    #[must_use]
+    pub fn humanize_live(&self, datetime: DateTime<Local>) -> LiveLabel {
+        self.humanize_live_with(&SystemClock, datetime)
+    }
+
+    /// Like [`humanize_live`](Humanizer::humanize_live), but relative to the
+    /// instant reported by `clock`.
+    #[must_use]
+    pub fn humanize_live_with(
+        &self,
+        clock: &(impl Clock + ?Sized),
+        datetime: DateTime<Local>,
+    ) -> LiveLabel {
+        let now = now_with(clock);
+        let label = self.format(&English, datetime, now);
+        let next_change = self
+            .next_change(&English, &label, datetime, now)
+            .map(Relative);
+        LiveLabel { label, next_change }
+    }
+
+    /// Phrases the length of `duration` on the same ladder, without past or
+    /// future framing: `"a few seconds"`, `"about a minute"`, `"3 hours"`.
+    ///
+    /// The sign of `duration` is ignored, and so is
+    /// [calendar mode](HumanizerBuilder::calendar), since a bare duration
+    /// has no dates to compare.
+    #[inline]
+    #[must_use]
+    pub fn humanize_duration(&self, duration: Duration) -> String {
+        self.humanize_duration_with_locale(&English, duration)
+    }
+
+    /// Like [`humanize_duration`](Humanizer::humanize_duration), but in the
+    /// language of `locale`.
+    #[inline]
+    #[must_use]
+    pub fn humanize_duration_with_locale(
+        &self,
+        locale: &(impl Locale + ?Sized),
+        duration: Duration,
+    ) -> String {
+        self.format_duration(locale, duration)
+    }
+
+    pub(crate) fn format_duration(
+        &self,
+        locale: &(impl Locale + ?Sized),
+        duration: Duration,
+    ) -> String {
+        let abs = duration.num_seconds().saturating_abs();
+        let mut out = String::new();
+        let (unit, n) = match self.rung(abs) {
+            Rung::JustNow => {
+                out.push_str(locale.few_seconds());
+                return out;
+            }
+            Rung::AdjacentDay if self.style == Style::Long => {
+                out.push_str(locale.about_one(TimeUnit::Day));
+                return out;
+            }
+            Rung::Single(unit) if self.style == Style::Long => {
+                out.push_str(locale.about_one(unit));
+                return out;
+            }
+            Rung::AdjacentDay => (TimeUnit::Day, 1),
+            Rung::Single(unit) => (unit, 1),
+            Rung::Count(unit) => (unit, (abs / unit.seconds()).unsigned_abs()),
+        };
+        let category = locale.plural_category(n);
+        let pattern = match self.style {
+            Style::Long => locale.duration_units(unit, category),
+            Style::Short => locale.short(unit, category),
+            Style::Narrow => locale.compact(unit),
+        };
+        write_pattern(locale, pattern, n, &mut out).expect("writing to a String cannot fail");
+        out
+    }
+
+    /// The rung covering an offset of `abs` seconds.
+    fn rung(&self, abs: i64) -> Rung {
+        self.bounds(abs).0
+    }
+
+    /// The rung covering an offset of `abs` seconds, with the range of
+    /// offsets it covers.
+    fn bounds(&self, abs: i64) -> (Rung, i64, i64) {
+        let mut lower = 0;
+        for step in self.ladder.iter() {
+            if abs < step.below {
+                return (step.rung, lower, step.below);
+            }
+            lower = step.below;
+        }
+        (Rung::Count(self.top), lower, i64::MAX)
+    }
+
+    /// The earliest instant after `now` at which `datetime` is no longer
+    /// phrased as `label`, or `None` if that is out of range.
+    fn next_change(
+        &self,
+        locale: &(impl Locale + ?Sized),
+        label: &str,
+        datetime: DateTime<Local>,
+        now: DateTime<Local>,
+    ) -> Option<DateTime<Local>> {
+        let mut at = now;
+        loop {
+            let secs = at.signed_duration_since(datetime).num_seconds();
+            let mut next = at_offset(datetime, self.next_breakpoint(secs)?)?;
+            if self.calendar {
+                // Calendar phrases only change when a date does.
+                let midnight = at.date_naive().succ_opt()?.and_time(NaiveTime::MIN);
+                next = next.min(localize(&Local, midnight)?);
+            }
+            if self.format(locale, datetime, next) != label {
+                if !self.calendar_months {
+                    return Some(next);
+                }
+                // Calendar months do not end on the breakpoints, which are
+                // in seconds, so narrow down on the change.
+                let (mut same, mut changed) = (at, next);
+                while changed - same > Duration::nanoseconds(1) {
+                    let mid = same + (changed - same) / 2;
+                    if self.format(locale, datetime, mid) == label {
+                        same = mid;
+                    } else {
+                        changed = mid;
+                    }
+                }
+                return Some(changed);
+            }
+            at = next;
+        }
+    }
+
+    /// The next offset in whole seconds after `secs` at which the ladder may
+    /// phrase it differently: where the rung or its count changes.
+    fn next_breakpoint(&self, secs: i64) -> Option<i64> {
+        let abs = secs.checked_abs()?;
+        let (rung, lower, below) = self.bounds(abs);
+        if secs >= 0 {
+            // Moving away from `datetime`: the count grows or the rung ends.
+            Some(match rung {
+                Rung::Count(unit) => {
+                    let next = (abs / unit.seconds() + 1).checked_mul(unit.seconds())?;
+                    next.min(below)
+                }
+                _ => below,
+            })
+        } else {
+            // Approaching it: the count shrinks or the rung ends, and past
+            // the first rung the offset crosses over into the past.
+            let floor = match rung {
+                Rung::Count(unit) => (abs / unit.seconds() * unit.seconds()).max(lower),
+                _ => lower,
+            };
+            Some((1 - floor).min(0))
+        }
+    }
+
+    pub(crate) fn format(
+        &self,
+        locale: &(impl Locale + ?Sized),
+        datetime: DateTime<Local>,
+        now: DateTime<Local>,
+    ) -> String {
+        Humanized {
+            humanizer: self,
+            locale,
+            datetime,
+            now,
+        }
+        .to_string()
+    }
+
+    /// Writes the phrase for `datetime` seen at `now`.
+    fn write(
+        &self,
+        locale: &(impl Locale + ?Sized),
+        datetime: DateTime<Local>,
+        now: DateTime<Local>,
+        out: &mut dyn fmt::Write,
+    ) -> fmt::Result {
+        let offset = self.offset(datetime, now);
+        let mut rung = self.rung(offset.ladder);
+        if self.calendar && rung != Rung::JustNow {
+            let (date, today) = (datetime.date_naive(), now.date_naive());
+            if date != today {
+                return write_calendar(locale, date, today, self.style, out);
+            }
+            if spans_days(rung) {
+                rung = Rung::Count(TimeUnit::Hour);
+            }
+        }
+        self.write_rung(locale, rung, offset, out)
+    }
+
+    /// Measures `datetime` from `now` for the ladder.
+    fn offset(&self, datetime: DateTime<Local>, now: DateTime<Local>) -> Offset {
+        let secs = now.signed_duration_since(datetime).num_seconds();
+        let tense = if secs >= 0 {
+            Tense::Past
+        } else {
+            Tense::Future
+        };
+        let abs = secs.saturating_abs();
+        let months = self
+            .calendar_months
+            .then(|| whole_months(now.naive_local(), datetime.naive_local(), tense))
+            .flatten();
+        let ladder = match months {
+            // Whole months count as 30 days each, and the rest never reaches
+            // another month, so month thresholds fall on calendar months.
+            Some((0, _)) => abs.min(MONTH - 1),
+            Some((months, rest)) => months.saturating_mul(MONTH) + rest.min(MONTH - 1),
+            None => abs,
+        };
+        Offset {
+            tense,
+            abs,
+            ladder,
+            months: months.map(|(months, _)| months),
+        }
+    }
+
+    /// Phrases a date `days` calendar days from today on the ladder, with
+    /// `"today"` for the same date.
+    pub(crate) fn format_days(&self, locale: &(impl Locale + ?Sized), days: i64) -> String {
+        let tense = if days < 0 { Tense::Past } else { Tense::Future };
+        let abs = days.saturating_abs().saturating_mul(DAY);
+        let offset = Offset {
+            tense,
+            abs,
+            ladder: abs,
+            months: None,
+        };
+        let mut out = String::new();
+        match self.rung(abs) {
+            _ if days == 0 => out.push_str(locale.today()),
+            Rung::JustNow => out.push_str(locale.today()),
+            rung => self
+                .write_rung(locale, rung, offset, &mut out)
+                .expect("writing to a String cannot fail"),
+        }
+        out
+    }
+
+    /// Writes the phrase for `rung` at `offset`.
+    fn write_rung(
+        &self,
+        locale: &(impl Locale + ?Sized),
+        rung: Rung,
+        offset: Offset,
+        out: &mut dyn fmt::Write,
+    ) -> fmt::Result {
+        let tense = offset.tense;
+        match rung {
+            Rung::JustNow => out.write_str(locale.just_now()),
+            Rung::AdjacentDay => out.write_str(match tense {
+                Tense::Past => locale.yesterday(),
+                Tense::Future => locale.tomorrow(),
+            }),
+            Rung::Single(unit) if self.style == Style::Long => {
+                out.write_str(locale.single_unit(unit, tense))
+            }
+            Rung::Single(unit) => write_units(locale, 1, unit, tense, self.style, out),
+            Rung::Count(unit) => {
+                write_units(locale, offset.count(unit), unit, tense, self.style, out)
+            }
+        }
+    }
+}
+
+/// An offset from now, measured for the ladder.
+#[derive(Debug, Clone, Copy)]
+struct Offset {
+    tense: Tense,
+    /// Whole seconds.
+    abs: i64,
+    /// The position on the ladder: `abs`, or in calendar-months mode whole
+    /// calendar months as 30 days each plus the rest.
+    ladder: i64,
+    /// Whole calendar months, in calendar-months mode.
+    months: Option<i64>,
+}
+
+impl Offset {
+    /// The number of whole `unit`s in the offset.
+    fn count(self, unit: TimeUnit) -> u64 {
+        match (unit, self.months) {
+            (TimeUnit::Month, Some(months)) => months.unsigned_abs(),
+            (TimeUnit::Year, Some(months)) => (months / 12).unsigned_abs(),
+            _ => (self.abs / unit.seconds()).unsigned_abs(),
+        }
+    }
+}
+
+/// Whole calendar months between the wall-clock times `now` and `datetime`
+/// in the direction of `tense`, and the seconds left over.
+///
+/// Months are counted from `now` the way [`months_ago`](crate::months_ago)
+/// and [`months_from_now`](crate::months_from_now) count them, so the
+/// result of either is exactly `n` months away.
+fn whole_months(now: NaiveDateTime, datetime: NaiveDateTime, tense: Tense) -> Option<(i64, i64)> {
+    let index = |d: NaiveDateTime| i64::from(d.year()) * 12 + i64::from(d.month0());
+    // The difference in month numbers overshoots by at most one.
+    let mut months = (index(now) - index(datetime)).abs();
+    loop {
+        let step = Months::new(u32::try_from(months).ok()?);
+        let rest = match tense {
+            Tense::Past => now
+                .checked_sub_months(step)?
+                .signed_duration_since(datetime),
+            Tense::Future => datetime.signed_duration_since(now.checked_add_months(step)?),
+        };
+        if rest >= Duration::zero() || months == 0 {
+            return Some((months, rest.num_seconds().max(0)));
+        }
+        months -= 1;
+    }
+}
+
+/// The instant at which `now - datetime`, truncated to whole seconds,
+/// first reaches `secs`.
+fn at_offset(datetime: DateTime<Local>, secs: i64) -> Option<DateTime<Local>> {
+    if secs > 0 {
+        datetime.checked_add_signed(Duration::seconds(secs))
+    } else {
+        // Negative offsets truncate toward zero, so -2.5 s reads as -2 s.
+        datetime.checked_add_signed(Duration::seconds(secs - 1) + Duration::nanoseconds(1))
+    }
+}
+
+/// A humanized label together with the instant it stops being accurate,
+/// as returned by [`Humanizer::humanize_live`].
+///
+/// ```
+/// use chrono::{Duration, Local};
+/// use period::humanize_live;
+///
+/// let posted = Local::now() - Duration::minutes(3);
+/// let live = humanize_live(posted);
+/// assert_eq!(live.label(), "3 minutes ago");
+/// // "4 minutes ago" takes over four minutes after posting.
+/// assert_eq!(
+///     live.next_change().unwrap().as_datetime(),
+///     posted + Duration::minutes(4)
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiveLabel {
+    label: String,
+    next_change: Option<Relative>,
+}
+
+impl LiveLabel {
+    /// The humanized label.
+    #[must_use]
+    #[inline]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The earliest instant at which the label will read differently, or
+    /// `None` if that lies beyond the representable range.
+    #[must_use]
+    #[inline]
+    pub fn next_change(&self) -> Option<Relative> {
+        self.next_change
+    }
+
+    /// Consumes the `LiveLabel`, returning the label.
+    #[must_use]
+    #[inline]
+    pub fn into_label(self) -> String {
+        self.label
+    }
+}
+
+/// The [`Humanizer::DEFAULT`] ladder, borrowed by the free functions that
+/// return a [`Humanized`].
+pub(crate) static DEFAULT_HUMANIZER: Humanizer = Humanizer::DEFAULT;
+
+/// A humanized phrase that is written straight into a formatter or
+/// [`fmt::Write`], without allocating.
+///
+/// Created by [`Humanizer::display`] and its variants, or by
+/// [`humanized`](crate::humanized). The current time is read when the
+/// adapter is created, so displaying it twice gives the same phrase.
+///
+/// ```
+/// use std::fmt::Write;
+///
+/// use chrono::{Duration, Local};
+/// use period::humanized;
+///
+/// let mut line = String::with_capacity(64);
+/// write!(line, "updated {}", humanized(Local::now() - Duration::minutes(3)))?;
+/// assert_eq!(line, "updated 3 minutes ago");
+/// # Ok::<(), std::fmt::Error>(())
+/// ```
+#[must_use]
+pub struct Humanized<'a, L: ?Sized = English> {
+    humanizer: &'a Humanizer,
+    locale: &'a L,
+    datetime: DateTime<Local>,
+    now: DateTime<Local>,
+}
+
+impl<'a> Humanized<'a> {
+    pub(crate) fn new(
+        humanizer: &'a Humanizer,
+        datetime: DateTime<Local>,
+        now: DateTime<Local>,
+    ) -> Self {
+        Humanized {
+            humanizer,
+            locale: &English,
+            datetime,
+            now,
+        }
+    }
+}
+
+impl<L: Locale + ?Sized> fmt::Display for Humanized<'_, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.humanizer
+            .write(self.locale, self.datetime, self.now, f)
+    }
+}
+
+impl<L: ?Sized> fmt::Debug for Humanized<'_, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Humanized")
+            .field("humanizer", self.humanizer)
+            .field("datetime", &self.datetime)
+            .field("now", &self.now)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<L: ?Sized> Clone for Humanized<'_, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: ?Sized> Copy for Humanized<'_, L> {}
+
+fn spans_days(rung: Rung) -> bool {
+    match rung {
+        Rung::JustNow => false,
+        Rung::AdjacentDay => true,
+        Rung::Single(unit) | Rung::Count(unit) => unit >= TimeUnit::Day,
+    }
+}
+
+/// Writes the calendar-mode phrase for `date` seen on a different day,
+/// `today`.
+fn write_calendar(
+    locale: &(impl Locale + ?Sized),
+    date: NaiveDate,
+    today: NaiveDate,
+    style: Style,
+    out: &mut dyn fmt::Write,
+) -> fmt::Result {
+    // Day numbers of each date's Monday; their difference is a multiple of 7.
+    let monday = |d: NaiveDate| {
+        i64::from(d.num_days_from_ce()) - i64::from(d.weekday().num_days_from_monday())
+    };
+    let month = |d: NaiveDate| i64::from(d.year()) * 12 + i64::from(d.month0());
+
+    let days = date.signed_duration_since(today).num_days();
+    let weeks = (monday(date) - monday(today)) / 7;
+    let months = month(date) - month(today);
+    let years = i64::from(date.year()) - i64::from(today.year());
+    let tense = if days < 0 { Tense::Past } else { Tense::Future };
+
+    match days.unsigned_abs() {
+        1 => out.write_str(match tense {
+            Tense::Past => locale.yesterday(),
+            Tense::Future => locale.tomorrow(),
+        }),
+        2..=6 => out.write_str(locale.weekday(date.weekday(), tense)),
+        _ if weeks.abs() == 1 => out.write_str(locale.adjacent(TimeUnit::Week, tense)),
+        _ if months == 0 => write_units(
+            locale,
+            weeks.unsigned_abs(),
+            TimeUnit::Week,
+            tense,
+            style,
+            out,
+        ),
+        _ if months.abs() == 1 => out.write_str(locale.adjacent(TimeUnit::Month, tense)),
+        _ if months.abs() <= 11 => write_units(
+            locale,
+            months.unsigned_abs(),
+            TimeUnit::Month,
+            tense,
+            style,
+            out,
+        ),
+        _ if years.abs() == 1 => out.write_str(locale.adjacent(TimeUnit::Year, tense)),
+        _ => write_units(
+            locale,
+            years.unsigned_abs(),
+            TimeUnit::Year,
+            tense,
+            style,
+            out,
+        ),
+    }
+}
+
+/// Adjusts a [`Humanizer`] ladder. Created by [`Humanizer::builder`].
+///
+/// Mistakes are recorded as they happen and reported by
+/// [`build`](HumanizerBuilder::build) as [`PeriodError::InvalidThreshold`].
+#[derive(Debug, Clone, PartialEq)]
+#[must_use]
+pub struct HumanizerBuilder {
+    ladder: Vec<Step>,
+    top: TimeUnit,
+    calendar: bool,
+    calendar_months: bool,
+    style: Style,
+    error: Option<PeriodError>,
+}
+
+impl HumanizerBuilder {
+    /// Moves the threshold of `rung`, which must already be on the ladder.
+    pub fn threshold(mut self, rung: Rung, below: Duration) -> Self {
+        let below = below.num_seconds();
+        match self.ladder.iter_mut().find(|step| step.rung == rung) {
+            Some(step) => step.below = below,
+            None => self.fail(below, "rung is not on the ladder"),
+        }
+        self
+    }
+
+    /// Removes every rung, to build a ladder from scratch with
+    /// [`rung`](HumanizerBuilder::rung).
+    pub fn clear(mut self) -> Self {
+        self.ladder.clear();
+        self
+    }
+
+    /// Adds `rung` to the top of the ladder, covering offsets from the
+    /// previous threshold up to `below`.
+    pub fn rung(mut self, rung: Rung, below: Duration) -> Self {
+        self.ladder.push(Step {
+            rung,
+            below: below.num_seconds(),
+        });
+        self
+    }
+
+    /// Sets the unit counted beyond the last threshold. Defaults to years.
+    pub fn top(mut self, unit: TimeUnit) -> Self {
+        self.top = unit;
+        self
+    }
+
+    /// Switches calendar mode on or off. Off by default.
+    ///
+    /// In calendar mode, an offset that the ladder would not call
+    /// `"just now"` is compared by local calendar dates. On the same date the
+    /// ladder applies, counting hours at most, so 00:30 seen at 23:00 is
+    /// `"22 hours ago"`. Across midnight the phrase follows calendar
+    /// boundaries, with weeks starting on Monday:
+    ///
+    /// | Dates apart                      | Past                | Future              |
+    /// |----------------------------------|---------------------|---------------------|
+    /// | 1 day                            | `"yesterday"`       | `"tomorrow"`        |
+    /// | 2–6 days                         | `"last Tuesday"`    | `"next Tuesday"`    |
+    /// | adjacent week                    | `"last week"`       | `"next week"`       |
+    /// | same month                       | `"N weeks ago"`     | `"in N weeks"`      |
+    /// | adjacent month                   | `"last month"`      | `"next month"`      |
+    /// | up to 11 months                  | `"N months ago"`    | `"in N months"`     |
+    /// | adjacent year                    | `"last year"`       | `"next year"`       |
+    /// | further                          | `"N years ago"`     | `"in N years"`      |
+    ///
+    /// Counts are differences of calendar weeks, months and years, so
+    /// December 20 is `"last month"` on January 8.
+    pub fn calendar(mut self, on: bool) -> Self {
+        self.calendar = on;
+        self
+    }
+
+    /// Measures months and years by calendar arithmetic instead of as 30
+    /// and 365 days. Off by default.
+    ///
+    /// Whole months are counted the way [`months_ago`](crate::months_ago)
+    /// counts them, so `months_ago(n)` is always `n` months ago, and a year
+    /// is twelve of those months. Thresholds from a month up are read in the
+    /// same months: the default `"a year ago"` from 10 months starts at ten
+    /// calendar months. Shorter thresholds and counts are unaffected.
+    pub fn calendar_months(mut self, on: bool) -> Self {
+        self.calendar_months = on;
+        self
+    }
+
+    /// Sets how long the words are: `"3 minutes ago"`, `"3 min. ago"` or
+    /// `"3m ago"`. Defaults to [`Style::Long`].
+    ///
+    /// Outside the long style, rungs that name a single unit are counted as
+    /// one (`"1 hr. ago"`), while `"just now"`, `"yesterday"` and the other
+    /// calendar phrases keep their words.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Validates the ladder.
+    ///
+    /// # Errors
+    /// Returns [`PeriodError::InvalidThreshold`] if a threshold is not
+    /// positive or not above the one before it, if a rung appears twice, if
+    /// a counted rung (or the top unit) would start below one of its units
+    /// and so read `"0 minutes ago"`, or if
+    /// [`threshold`](HumanizerBuilder::threshold) named a rung that is not on
+    /// the ladder.
+    pub fn build(self) -> Result<Humanizer, PeriodError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let mut lower = 0;
+        for (i, step) in self.ladder.iter().enumerate() {
+            if step.below <= lower {
+                let reason = if i == 0 {
+                    "must be positive"
+                } else {
+                    "must be above the previous threshold"
+                };
+                return Err(invalid(step.below, reason));
+            }
+            if self.ladder[..i].iter().any(|s| s.rung == step.rung) {
+                return Err(invalid(step.below, "rung appears twice"));
+            }
+            if let Rung::Count(unit) = step.rung
+                && lower < unit.seconds()
+            {
+                return Err(invalid(lower, "counted rung starts below one unit"));
+            }
+            lower = step.below;
+        }
+        if lower < self.top.seconds() {
+            return Err(invalid(lower, "top unit starts below one unit"));
+        }
+        Ok(Humanizer {
+            ladder: Cow::Owned(self.ladder),
+            top: self.top,
+            calendar: self.calendar,
+            calendar_months: self.calendar_months,
+            style: self.style,
+        })
+    }
+
+    fn fail(&mut self, seconds: i64, reason: &'static str) {
+        if self.error.is_none() {
+            self.error = Some(invalid(seconds, reason));
+        }
+    }
+}
+
+fn invalid(seconds: i64, reason: &'static str) -> PeriodError {
+    PeriodError::InvalidThreshold { seconds, reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn now() -> DateTime<Local> {
+        Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0)
+            .unwrap()
+            .with_timezone(&Local)
+    }
+
+    fn ago(humanizer: &Humanizer, secs: i64) -> String {
+        humanizer.format(&English, now() - Duration::seconds(secs), now())
+    }
+
+    #[test]
+    fn test_default_matches_builder_default() {
+        assert_eq!(Humanizer::builder().build().unwrap(), Humanizer::default());
+    }
+
+    #[test]
+    fn test_extended_just_now() {
+        let h = Humanizer::builder()
+            .threshold(Rung::JustNow, Duration::seconds(60))
+            .build()
+            .unwrap();
+        assert_eq!(ago(&h, 59), "just now");
+        assert_eq!(ago(&h, 60), "a minute ago");
+        assert_eq!(ago(&h, 90), "1 minute ago");
+    }
+
+    #[test]
+    fn test_days_up_to_six_weeks() {
+        let h = Humanizer::builder()
+            .threshold(Rung::Count(TimeUnit::Day), Duration::weeks(6))
+            .build()
+            .unwrap();
+        assert_eq!(ago(&h, 41 * DAY), "41 days ago");
+        assert_eq!(ago(&h, 42 * DAY), "a month ago");
+        assert_eq!(ago(&h, 45 * DAY), "1 month ago");
+    }
+
+    #[test]
+    fn test_custom_ladder_and_top() {
+        let h = Humanizer::builder()
+            .clear()
+            .rung(Rung::JustNow, Duration::seconds(10))
+            .rung(Rung::Count(TimeUnit::Second), Duration::minutes(1))
+            .rung(Rung::Count(TimeUnit::Minute), Duration::hours(1))
+            .top(TimeUnit::Hour)
+            .build()
+            .unwrap();
+        assert_eq!(ago(&h, 5), "just now");
+        assert_eq!(ago(&h, 42), "42 seconds ago");
+        assert_eq!(ago(&h, 59 * MINUTE), "59 minutes ago");
+        assert_eq!(ago(&h, 30 * DAY), "720 hours ago");
+    }
+
+    #[test]
+    fn test_future_uses_same_ladder() {
+        let h = Humanizer::builder()
+            .threshold(Rung::JustNow, Duration::seconds(60))
+            .build()
+            .unwrap();
+        let at = |secs| h.format(&English, now() + Duration::seconds(secs), now());
+        assert_eq!(at(45), "just now");
+        assert_eq!(at(30 * HOUR), "tomorrow");
+    }
+
+    #[test]
+    fn test_single_rung_then_top_unit() {
+        let h = Humanizer::builder()
+            .clear()
+            .rung(Rung::JustNow, Duration::seconds(1))
+            .top(TimeUnit::Second)
+            .build()
+            .unwrap();
+        assert_eq!(ago(&h, 0), "just now");
+        assert_eq!(ago(&h, 1), "1 second ago");
+        assert_eq!(ago(&h, 2 * MINUTE), "120 seconds ago");
+    }
+
+    #[test]
+    fn test_empty_ladder_rejected() {
+        let err = Humanizer::builder()
+            .clear()
+            .top(TimeUnit::Second)
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            PeriodError::InvalidThreshold {
+                seconds: 0,
+                reason: "top unit starts below one unit",
+            }
+        );
+    }
+
+    #[test]
+    fn test_locale() {
+        let h = Humanizer::builder()
+            .threshold(Rung::Count(TimeUnit::Day), Duration::weeks(6))
+            .build()
+            .unwrap();
+        let then = now() - Duration::days(40);
+        assert_eq!(
+            h.format(&crate::locale::German, then, now()),
+            "vor 40 Tagen"
+        );
+    }
+
+    #[test]
+    fn test_duration_uses_ladder() {
+        let h = Humanizer::builder()
+            .threshold(Rung::JustNow, Duration::seconds(60))
+            .top(TimeUnit::Month)
+            .build()
+            .unwrap();
+        assert_eq!(h.humanize_duration(Duration::seconds(45)), "a few seconds");
+        assert_eq!(h.humanize_duration(Duration::seconds(75)), "about a minute");
+        assert_eq!(h.humanize_duration(Duration::hours(30)), "about a day");
+        assert_eq!(h.humanize_duration(Duration::days(-3)), "3 days");
+        assert_eq!(h.humanize_duration(Duration::days(800)), "26 months");
+        assert_eq!(
+            h.humanize_duration_with_locale(&crate::locale::Polish, Duration::minutes(5)),
+            "5 minut"
+        );
+    }
+
+    #[test]
+    fn test_duration_ignores_calendar_mode() {
+        assert_eq!(
+            Humanizer::CALENDAR.humanize_duration(Duration::hours(30)),
+            "about a day"
+        );
+    }
+
+    #[test]
+    fn test_styles_in_other_locales() {
+        let then = now() - Duration::minutes(5);
+        let short = Humanizer::builder().style(Style::Short).build().unwrap();
+        let narrow = Humanizer::builder().style(Style::Narrow).build().unwrap();
+        assert_eq!(
+            short.format(&crate::locale::German, then, now()),
+            "vor 5 Min."
+        );
+        assert_eq!(
+            narrow.format(&crate::locale::Russian, then, now()),
+            "5 мин назад"
+        );
+        assert_eq!(
+            narrow.format(&crate::locale::Japanese, then, now()),
+            "5分前"
+        );
+        assert_eq!(
+            narrow.format(&crate::locale::French, now() + Duration::hours(3), now()),
+            "dans 3 h"
+        );
+        assert_eq!(
+            short.humanize_duration_with_locale(&crate::locale::Polish, Duration::days(3)),
+            "3 d."
+        );
+    }
+
+    #[test]
+    fn test_display_matches_format() {
+        let h = Humanizer::builder()
+            .calendar(true)
+            .style(Style::Short)
+            .build()
+            .unwrap();
+        for secs in [0, 45, 3 * 60, 5 * HOUR, 2 * DAY, 40 * DAY, 3 * YEAR] {
+            let then = now() - Duration::seconds(secs);
+            let humanized = Humanized {
+                humanizer: &h,
+                locale: &crate::locale::Polish,
+                datetime: then,
+                now: now(),
+            };
+            assert_eq!(
+                humanized.to_string(),
+                h.format(&crate::locale::Polish, then, now())
+            );
+        }
+    }
+
+    #[test]
+    fn test_display_reads_clock_once() {
+        let clock = crate::clock::MockClock::new(&now().to_utc());
+        let humanized = Humanizer::DEFAULT.display_with(&clock, now() - Duration::minutes(3));
+        clock.advance(Duration::hours(2));
+        assert_eq!(humanized.to_string(), "3 minutes ago");
+        assert_eq!(format!("[{humanized}]"), "[3 minutes ago]");
+    }
+
+    #[test]
+    fn test_display_with_locale_reads_clock_once() {
+        let clock = crate::clock::MockClock::new(&now().to_utc());
+        let humanized = Humanizer::DEFAULT.display_with_locale_with(
+            &clock,
+            &crate::locale::French,
+            now() - Duration::minutes(3),
+        );
+        clock.advance(Duration::hours(2));
+        assert_eq!(humanized.to_string(), "il y a 3 minutes");
+    }
+
+    fn live(humanizer: &Humanizer, datetime: DateTime<Local>) -> LiveLabel {
+        let clock = crate::clock::MockClock::new(&now().to_utc());
+        humanizer.humanize_live_with(&clock, datetime)
+    }
+
+    #[test]
+    fn test_live_next_change() {
+        let h = Humanizer::DEFAULT;
+        let ms = Duration::milliseconds;
+        for (offset, label, change) in [
+            (Duration::seconds(-10), "just now", Duration::seconds(30)),
+            (Duration::minutes(-3), "3 minutes ago", Duration::minutes(4)),
+            (Duration::hours(-24), "yesterday", Duration::hours(36)),
+            (
+                Duration::days(-3 * 365),
+                "3 years ago",
+                Duration::days(4 * 365),
+            ),
+            // Approaching an instant, the count drops as soon as the offset
+            // is below a whole unit.
+            (Duration::seconds(10), "just now", Duration::seconds(30)),
+            (
+                Duration::minutes(5) + ms(500),
+                "in 5 minutes",
+                -Duration::minutes(5) + Duration::nanoseconds(1),
+            ),
+            (
+                Duration::hours(30),
+                "tomorrow",
+                -Duration::hours(22) + Duration::nanoseconds(1),
+            ),
+        ] {
+            let then = now() + offset;
+            let live = live(&h, then);
+            assert_eq!(live.label(), label, "{offset}");
+            assert_eq!(
+                live.next_change().unwrap().as_datetime(),
+                then + change,
+                "{offset}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_live_next_change_is_exact() {
+        let calendar = Humanizer::builder().calendar(true).build().unwrap();
+        for h in [&Humanizer::DEFAULT, &calendar] {
+            for secs in [
+                0,
+                29,
+                30,
+                89,
+                90,
+                2_699,
+                2_700,
+                5_399,
+                79_199,
+                129_599,
+                2_159_999,
+                3_887_999,
+                25_919_999,
+                46_655_999,
+                400_000_000,
+            ] {
+                for then in [
+                    now() - Duration::seconds(secs),
+                    now() + Duration::seconds(secs),
+                ] {
+                    let label = h.format(&English, then, now());
+                    let next = live(h, then).next_change().unwrap().as_datetime();
+                    assert!(next > now(), "{secs}");
+                    assert_ne!(h.format(&English, then, next), label, "{secs}");
+                    assert_eq!(
+                        h.format(&English, then, next - Duration::nanoseconds(1)),
+                        label,
+                        "{secs}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_live_calendar_changes_at_midnight() {
+        let h = Humanizer::builder().calendar(true).build().unwrap();
+        let now = local(2026, 3, 25, 12, 0);
+        let clock = crate::clock::MockClock::new(&now.to_utc());
+        let live = h.humanize_live_with(&clock, local(2026, 3, 23, 18, 0));
+        assert_eq!(live.label(), "last Monday");
+        assert_eq!(
+            live.next_change().unwrap().as_datetime(),
+            local(2026, 3, 30, 0, 0)
+        );
+        assert_eq!(live.into_label(), "last Monday");
+    }
+
+    fn calendar_months() -> Humanizer {
+        Humanizer::builder().calendar_months(true).build().unwrap()
+    }
+
+    #[test]
+    fn test_calendar_months_match_months_ago() {
+        use crate::relative::functions::{
+            months_ago_with, months_from_now_with, years_ago_with, years_from_now_with,
+        };
+
+        let h = calendar_months();
+        for (y, m, d) in [(2026, 3, 31), (2026, 1, 31), (2028, 2, 29), (2026, 12, 31)] {
+            let clock = crate::clock::MockClock::new(&local(y, m, d, 12, 0).to_utc());
+            for n in 2..=9 {
+                let past = months_ago_with(&clock, n).unwrap().as_datetime();
+                assert_eq!(h.humanize_with(&clock, past), format!("{n} months ago"));
+                let future = months_from_now_with(&clock, n).unwrap().as_datetime();
+                assert_eq!(h.humanize_with(&clock, future), format!("in {n} months"));
+            }
+            for n in [2, 3, 4, 100] {
+                let past = years_ago_with(&clock, n).unwrap().as_datetime();
+                assert_eq!(h.humanize_with(&clock, past), format!("{n} years ago"));
+                let future = years_from_now_with(&clock, n).unwrap().as_datetime();
+                assert_eq!(h.humanize_with(&clock, future), format!("in {n} years"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_calendar_months_differ_from_thirty_days() {
+        // March 31 back to January 31 is two calendar months but only 59 days.
+        let now = local(2026, 3, 31, 12, 0);
+        let then = local(2026, 1, 31, 12, 0);
+        assert_eq!(
+            Humanizer::DEFAULT.format(&English, then, now),
+            "1 month ago"
+        );
+        assert_eq!(
+            calendar_months().format(&English, then, now),
+            "2 months ago"
+        );
+        // One minute short of two calendar months is still one.
+        let later = local(2026, 1, 31, 12, 1);
+        assert_eq!(
+            calendar_months().format(&English, later, now),
+            "1 month ago"
+        );
+    }
+
+    #[test]
+    fn test_calendar_months_thresholds() {
+        let h = calendar_months();
+        let now = local(2026, 12, 31, 12, 0);
+        // March 1 is 305 days back, past the 300-day approximation of ten
+        // months but only nine calendar months.
+        let march = local(2026, 3, 1, 12, 0);
+        assert_eq!(
+            Humanizer::DEFAULT.format(&English, march, now),
+            "a year ago"
+        );
+        assert_eq!(h.format(&English, march, now), "9 months ago");
+        assert_eq!(
+            h.format(&English, local(2026, 2, 28, 12, 0), now),
+            "a year ago"
+        );
+        // Under a month, the ladder reads seconds as before.
+        assert_eq!(
+            h.format(&English, local(2026, 12, 1, 12, 0), now),
+            "a month ago"
+        );
+        assert_eq!(
+            h.format(&English, local(2026, 12, 10, 12, 0), now),
+            "21 days ago"
+        );
+    }
+
+    #[test]
+    fn test_calendar_months_live_next_change() {
+        let h = calendar_months();
+        let now = local(2026, 3, 31, 12, 0);
+        let clock = crate::clock::MockClock::new(&now.to_utc());
+        let then = local(2026, 1, 31, 12, 0);
+        let live = h.humanize_live_with(&clock, then);
+        assert_eq!(live.label(), "2 months ago");
+        // Three months before any time on April 30 is still January 30, so
+        // "3 months ago" starts at midnight on May 1.
+        assert_eq!(
+            live.next_change().unwrap().as_datetime(),
+            local(2026, 5, 1, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_humanize_with_clock() {
+        use crate::clock::MockClock;
+
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let h = Humanizer::default();
+        assert_eq!(
+            h.humanize_with(&clock, now() - Duration::hours(3)),
+            "3 hours ago"
+        );
+    }
+
+    #[test]
+    fn test_non_positive_threshold_rejected() {
+        let err = Humanizer::builder()
+            .threshold(Rung::JustNow, Duration::zero())
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            PeriodError::InvalidThreshold {
+                seconds: 0,
+                reason: "must be positive",
+            }
+        );
+    }
+
+    #[test]
+    fn test_out_of_order_threshold_rejected() {
+        let err = Humanizer::builder()
+            .threshold(Rung::JustNow, Duration::seconds(120))
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            PeriodError::InvalidThreshold {
+                seconds: 90,
+                reason: "must be above the previous threshold",
+            }
+        );
+    }
+
+    #[test]
+    fn test_duplicate_rung_rejected() {
+        let err = Humanizer::builder()
+            .rung(Rung::JustNow, Duration::days(1000))
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            PeriodError::InvalidThreshold {
+                reason: "rung appears twice",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_count_below_one_unit_rejected() {
+        let err = Humanizer::builder()
+            .clear()
+            .rung(Rung::JustNow, Duration::seconds(30))
+            .rung(Rung::Count(TimeUnit::Minute), Duration::hours(1))
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            PeriodError::InvalidThreshold {
+                seconds: 30,
+                reason: "counted rung starts below one unit",
+            }
+        );
+    }
+
+    #[test]
+    fn test_top_below_one_unit_rejected() {
+        let err = Humanizer::builder()
+            .threshold(Rung::Single(TimeUnit::Year), Duration::days(300))
+            .threshold(Rung::Count(TimeUnit::Month), Duration::days(200))
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            PeriodError::InvalidThreshold {
+                seconds: 300 * DAY,
+                reason: "top unit starts below one unit",
+            }
+        );
+    }
+
+    #[test]
+    fn test_missing_rung_rejected() {
+        let err = Humanizer::builder()
+            .threshold(Rung::Count(TimeUnit::Week), Duration::weeks(8))
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid humanize threshold of 4838400s: rung is not on the ladder"
+        );
+    }
+
+    fn local(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Local> {
+        use chrono::TimeZone;
+        Local
+            .from_local_datetime(
+                &NaiveDate::from_ymd_opt(y, m, d)
+                    .unwrap()
+                    .and_hms_opt(h, min, 0)
+                    .unwrap(),
+            )
+            .earliest()
+            .unwrap()
+    }
+
+    fn calendar(datetime: DateTime<Local>, now: DateTime<Local>) -> String {
+        Humanizer::CALENDAR.format(&English, datetime, now)
+    }
+
+    #[test]
+    fn test_calendar_same_day_counts_hours() {
+        let now = local(2026, 2, 25, 23, 0);
+        assert_eq!(calendar(local(2026, 2, 25, 0, 30), now), "22 hours ago");
+        assert_eq!(calendar(local(2026, 2, 25, 21, 0), now), "2 hours ago");
+        assert_eq!(calendar(local(2026, 2, 25, 22, 50), now), "10 minutes ago");
+        let morning = local(2026, 2, 25, 1, 0);
+        assert_eq!(calendar(local(2026, 2, 25, 23, 30), morning), "in 22 hours");
+    }
+
+    #[test]
+    fn test_calendar_just_now_across_midnight() {
+        let now = local(2026, 2, 25, 0, 0);
+        let then = now - Duration::seconds(10);
+        assert_eq!(calendar(then, now), "just now");
+        assert_eq!(calendar(now - Duration::minutes(5), now), "yesterday");
+    }
+
+    #[test]
+    fn test_calendar_past() {
+        let now = local(2026, 2, 25, 23, 0);
+        let cases = [
+            ((2026, 2, 24), "yesterday"),
+            ((2026, 2, 23), "last Monday"),
+            ((2026, 2, 19), "last Thursday"),
+            ((2026, 2, 18), "last week"),
+            ((2026, 2, 16), "last week"),
+            ((2026, 2, 15), "2 weeks ago"),
+            ((2026, 2, 2), "3 weeks ago"),
+            ((2026, 1, 31), "last month"),
+            ((2026, 1, 1), "last month"),
+            ((2025, 12, 31), "2 months ago"),
+            ((2025, 3, 1), "11 months ago"),
+            ((2025, 2, 28), "last year"),
+            ((2025, 1, 1), "last year"),
+            ((2024, 12, 31), "2 years ago"),
+        ];
+        for ((y, m, d), expected) in cases {
+            assert_eq!(
+                calendar(local(y, m, d, 12, 0), now),
+                expected,
+                "{y}-{m}-{d}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_calendar_future() {
+        let now = local(2026, 2, 25, 1, 0);
+        let cases = [
+            ((2026, 2, 26), "tomorrow"),
+            ((2026, 2, 28), "next Saturday"),
+            ((2026, 3, 3), "next Tuesday"),
+            ((2026, 3, 4), "next week"),
+            ((2026, 3, 10), "next month"),
+            ((2026, 4, 1), "in 2 months"),
+            ((2027, 1, 31), "in 11 months"),
+            ((2027, 2, 1), "next year"),
+            ((2028, 1, 1), "in 2 years"),
+        ];
+        for ((y, m, d), expected) in cases {
+            assert_eq!(
+                calendar(local(y, m, d, 12, 0), now),
+                expected,
+                "{y}-{m}-{d}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_calendar_weeks_within_month() {
+        let now = local(2026, 3, 30, 12, 0);
+        assert_eq!(calendar(local(2026, 3, 16, 12, 0), now), "2 weeks ago");
+        let start = local(2026, 3, 2, 12, 0);
+        assert_eq!(calendar(local(2026, 3, 30, 12, 0), start), "in 4 weeks");
+    }
+
+    #[test]
+    fn test_calendar_mode_with_style() {
+        let h = Humanizer::builder()
+            .calendar(true)
+            .style(Style::Narrow)
+            .build()
+            .unwrap();
+        let now = local(2026, 3, 25, 12, 0);
+        let format = |then| h.format(&English, then, now);
+        assert_eq!(format(local(2026, 3, 25, 9, 0)), "3h ago");
+        assert_eq!(format(local(2026, 3, 24, 9, 0)), "yesterday");
+        assert_eq!(format(local(2026, 3, 16, 12, 0)), "last week");
+        assert_eq!(format(local(2026, 3, 2, 12, 0)), "3w ago");
+        assert_eq!(format(local(2025, 10, 25, 12, 0)), "5mo ago");
+        assert_eq!(format(local(2021, 3, 25, 12, 0)), "5y ago");
+    }
+
+    #[test]
+    fn test_calendar_mode_via_builder() {
+        let h = Humanizer::builder().calendar(true).build().unwrap();
+        assert_eq!(h, Humanizer::CALENDAR);
+        let now = local(2026, 2, 25, 23, 0);
+        let then = local(2026, 2, 23, 8, 0);
+        assert_eq!(
+            h.format(&crate::locale::German, then, now),
+            "letzten Montag"
+        );
+        assert_eq!(Humanizer::DEFAULT.format(&English, then, now), "2 days ago");
+    }
+}
@@ -1,11 +1,37 @@
+pub mod anchor;
+pub mod builder;
 pub mod functions;
 pub mod humanize;
+pub mod humanizer;
+pub mod parse;
+pub mod precise;
 pub mod types;
 
+pub use anchor::Anchor;
+pub use builder::{RelativeBuilder, ago, from_now};
 pub use functions::{
-    days_ago, days_from_now, hours_ago, hours_from_now, minutes_ago, minutes_from_now, months_ago,
-    months_from_now, seconds_ago, seconds_from_now, tomorrow, weeks_ago, weeks_from_now, years_ago,
-    years_from_now, yesterday,
+    days_after, days_ago, days_ago_in, days_ago_with, days_before, days_from_now, days_from_now_in,
+    days_from_now_with, hours_after, hours_ago, hours_ago_in, hours_ago_with, hours_before,
+    hours_from_now, hours_from_now_in, hours_from_now_with, minutes_after, minutes_ago,
+    minutes_ago_in, minutes_ago_with, minutes_before, minutes_from_now, minutes_from_now_in,
+    minutes_from_now_with, months_after, months_ago, months_ago_in, months_ago_with, months_before,
+    months_from_now, months_from_now_in, months_from_now_with, seconds_after, seconds_ago,
+    seconds_ago_in, seconds_ago_with, seconds_before, seconds_from_now, seconds_from_now_in,
+    seconds_from_now_with, tomorrow, tomorrow_in, tomorrow_with, weeks_after, weeks_ago,
+    weeks_ago_in, weeks_ago_with, weeks_before, weeks_from_now, weeks_from_now_in,
+    weeks_from_now_with, years_after, years_ago, years_ago_in, years_ago_with, years_before,
+    years_from_now, years_from_now_in, years_from_now_with, yesterday, yesterday_in,
+    yesterday_with,
 };
-pub use humanize::humanize;
+pub use humanize::{
+    Humanize, HumanizedRange, humanize, humanize_calendar, humanize_calendar_with,
+    humanize_duration, humanize_live, humanize_live_with, humanize_precise, humanize_precise_with,
+    humanize_styled, humanize_styled_with, humanize_timestamp, humanize_timestamp_with,
+    humanize_with, humanize_with_locale, humanize_with_locale_with, humanized, humanized_with,
+    parse_humanized, parse_humanized_with, write_humanized, write_humanized_with,
+    write_humanized_with_locale, write_humanized_with_locale_with,
+};
+pub use humanizer::{Humanized, Humanizer, HumanizerBuilder, LiveLabel, Rung};
+pub use parse::{parse, parse_in, parse_with};
+pub use precise::{Conjunction, Precise, Rounding};
 pub use types::Relative;
@@ -0,0 +1,534 @@
+use chrono::{DateTime, Datelike, Duration, NaiveTime, TimeZone, Weekday};
+
+use crate::clock::{Clock, SystemClock};
+use crate::error::PeriodError;
+use crate::now::{now_in, now_with};
+use crate::period::Period;
+use crate::relative::anchor::localize;
+use crate::relative::types::Relative;
+
+const FORMAT: &str = "relative date";
+
+/// Parses a relative date phrase such as `"3 days ago"`, `"in 2 weeks"` or
+/// `"tomorrow at 5pm"`, measured from the current local date-time.
+///
+/// Understood phrases (case-insensitive, words separated by whitespace):
+///
+/// | Phrase                           | Meaning                                   |
+/// |----------------------------------|-------------------------------------------|
+/// | `N <unit> ago`                   | [`days_ago`](crate::days_ago) and friends |
+/// | `N <unit> from now`, `in N <unit>` | [`days_from_now`](crate::days_from_now) and friends |
+/// | `now`, `today`                   | the current date-time                     |
+/// | `yesterday`, `tomorrow`          | the previous / next calendar day          |
+/// | `last <weekday>`, `next <weekday>` | the closest such day before / after today, 1 to 7 days away |
+///
+/// `N` is a non-negative integer or `a` / `an`, and `<unit>` is one of
+/// `second`, `minute`, `hour`, `day`, `week`, `month`, `year` (singular or
+/// plural). Weekdays are full names or three-letter abbreviations.
+///
+/// Any phrase may be followed by `at <time>`, where the time is `HH:MM` on a
+/// 24-hour clock, `H[:MM]am` / `H[:MM]pm` (with or without a space), `noon`
+/// or `midnight`. The time replaces the wall-clock time of day; a bare
+/// `at <time>` means today. Without it, the current time of day is kept.
+///
+/// Unit offsets use exactly the same arithmetic as the unit functions, so
+/// `parse("1 day ago")` equals `days_ago(1)`. `yesterday`, `tomorrow` and the
+/// weekday forms step whole calendar days on the wall clock instead, so they
+/// always land on the expected date across a DST change.
+///
+/// # Errors
+/// Returns [`PeriodError::Parse`] with the byte offset of the first word that
+/// could not be understood, and [`PeriodError::Overflow`] if the result is out
+/// of range.
+///
+/// # Example
+///
+/// ```rust
+/// use period::{days_ago, parse};
+///
+/// # fn main() -> Result<(), period::PeriodError> {
+/// assert_eq!(parse("3 days ago")?.as_date(), days_ago(3)?.as_date());
+/// let meeting = parse("next friday at 9:30am")?;
+/// # let _ = meeting;
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn parse(input: &str) -> Result<Relative, PeriodError> {
+    parse_with(&SystemClock, input)
+}
+
+/// Like [`parse`], but measured from the instant reported by `clock`.
+///
+/// # Errors
+/// Same as [`parse`].
+#[inline]
+pub fn parse_with(clock: &(impl Clock + ?Sized), input: &str) -> Result<Relative, PeriodError> {
+    parse_from(now_with(clock), input)
+}
+
+/// Like [`parse`], but read in the timezone `tz` instead of [`chrono::Local`].
+///
+/// Calendar days, weekdays and `at <time>` are all taken from the wall clock
+/// in `tz`.
+///
+/// # Errors
+/// Same as [`parse`].
+#[inline]
+pub fn parse_in<Tz: TimeZone>(tz: &Tz, input: &str) -> Result<Relative<Tz>, PeriodError> {
+    parse_from(now_in(tz), input)
+}
+
+/// A word of the input and its byte offset.
+pub(super) type Token<'a> = (usize, &'a str);
+
+fn parse_from<Tz: TimeZone>(now: DateTime<Tz>, input: &str) -> Result<Relative<Tz>, PeriodError> {
+    let tokens = tokenize(input);
+    let (date, rest) = parse_phrase(now, &tokens, input.len())?;
+    match rest {
+        [] => Ok(date),
+        [(at, word), time @ ..] if word.eq_ignore_ascii_case("at") => {
+            let time = parse_time(time, at + word.len())?;
+            at_time(date, time).ok_or_else(|| error(*at, "the date is out of range"))
+        }
+        [(pos, _), ..] => Err(error(*pos, "expected 'at' or the end of the phrase")),
+    }
+}
+
+/// Parses the date part of the phrase, returning the result and the tokens
+/// that follow it.
+fn parse_phrase<'t, 'a, Tz: TimeZone>(
+    now: DateTime<Tz>,
+    tokens: &'t [Token<'a>],
+    end: usize,
+) -> Result<(Relative<Tz>, &'t [Token<'a>]), PeriodError> {
+    let Some(&(pos, first)) = tokens.first() else {
+        return Err(error(end, "expected a relative date"));
+    };
+    let word = first.to_ascii_lowercase();
+    match word.as_str() {
+        "now" | "today" => Ok((Relative(now), &tokens[1..])),
+        "at" => Ok((Relative(now), tokens)),
+        "yesterday" => Ok((calendar_days(&now, -1, pos)?, &tokens[1..])),
+        "tomorrow" => Ok((calendar_days(&now, 1, pos)?, &tokens[1..])),
+        "last" | "next" => {
+            let Some(&(day_pos, day)) = tokens.get(1) else {
+                return Err(error(end, "expected a weekday"));
+            };
+            let target = weekday(day).ok_or_else(|| error(day_pos, "expected a weekday"))?;
+            let current = now.weekday();
+            let days = if word == "next" {
+                weekdays_between(current, target)
+            } else {
+                -weekdays_between(target, current)
+            };
+            Ok((calendar_days(&now, days, pos)?, &tokens[2..]))
+        }
+        "in" => {
+            let (period, rest) = parse_amount(&tokens[1..], end)?;
+            Ok((period.add_to(now)?, rest))
+        }
+        _ => {
+            let (period, rest) = parse_amount(tokens, end)?;
+            match rest {
+                [(_, ago), rest @ ..] if ago.eq_ignore_ascii_case("ago") => {
+                    Ok((period.sub_from(now)?, rest))
+                }
+                [(_, from), (_, now_word), rest @ ..]
+                    if from.eq_ignore_ascii_case("from")
+                        && now_word.eq_ignore_ascii_case("now") =>
+                {
+                    Ok((period.add_to(now)?, rest))
+                }
+                [(pos, _), ..] => Err(error(*pos, "expected 'ago' or 'from now'")),
+                [] => Err(error(end, "expected 'ago' or 'from now'")),
+            }
+        }
+    }
+}
+
+/// Parses `N <unit>` into a single-component [`Period`].
+fn parse_amount<'t, 'a>(
+    tokens: &'t [Token<'a>],
+    end: usize,
+) -> Result<(Period, &'t [Token<'a>]), PeriodError> {
+    let [(num_pos, num), rest @ ..] = tokens else {
+        return Err(error(end, "expected a number"));
+    };
+    let amount = if num.eq_ignore_ascii_case("a") || num.eq_ignore_ascii_case("an") {
+        1
+    } else if num.bytes().all(|b| b.is_ascii_digit()) {
+        num.parse::<i64>()
+            .map_err(|_| error(*num_pos, "number is too large"))?
+    } else {
+        return Err(error(*num_pos, "expected a number"));
+    };
+    let [(unit_pos, unit), rest @ ..] = rest else {
+        return Err(error(end, "expected a unit"));
+    };
+    let unit = unit.to_ascii_lowercase();
+    let period = match unit.strip_suffix('s').unwrap_or(&unit) {
+        "second" => Period::seconds(amount),
+        "minute" => Period::minutes(amount),
+        "hour" => Period::hours(amount),
+        "day" => Period::days(amount),
+        "week" => Period::weeks(amount),
+        "month" => Period::months(amount),
+        "year" => Period::years(amount),
+        _ => return Err(error(*unit_pos, "expected a unit")),
+    };
+    Ok((period, rest))
+}
+
+/// Parses the tokens after `at` as a time of day; `end` is where a missing
+/// time would have started.
+fn parse_time(tokens: &[Token<'_>], end: usize) -> Result<NaiveTime, PeriodError> {
+    let [(pos, word), rest @ ..] = tokens else {
+        return Err(error(end, "expected a time"));
+    };
+    let pos = *pos;
+    let named = if word.eq_ignore_ascii_case("noon") {
+        NaiveTime::from_hms_opt(12, 0, 0)
+    } else if word.eq_ignore_ascii_case("midnight") {
+        Some(NaiveTime::MIN)
+    } else {
+        None
+    };
+    if let Some(time) = named {
+        return match rest {
+            [] => Ok(time),
+            [(extra, _), ..] => Err(error(*extra, "expected the end of the phrase")),
+        };
+    }
+
+    let split = word
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(word.len());
+    let (clock, suffix) = word.split_at(split);
+    let (meridiem, meridiem_pos, rest) = match (suffix, rest) {
+        ("", [(next_pos, next), rest @ ..]) => (*next, *next_pos, rest),
+        _ => (suffix, pos + split, rest),
+    };
+    let pm = if meridiem.eq_ignore_ascii_case("am") {
+        Some(false)
+    } else if meridiem.eq_ignore_ascii_case("pm") {
+        Some(true)
+    } else if meridiem.is_empty() {
+        None
+    } else {
+        return Err(error(meridiem_pos, "expected 'am' or 'pm'"));
+    };
+    if let [(extra, _), ..] = rest {
+        return Err(error(*extra, "expected the end of the phrase"));
+    }
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => (hour, minute),
+        Some((hour, _)) => return Err(error(pos + hour.len() + 1, "expected two-digit minutes")),
+        None if pm.is_some() => (clock, "00"),
+        None => return Err(error(pos, "expected HH:MM, or 'am' or 'pm'")),
+    };
+    let hour = parse_clock_number(hour).ok_or_else(|| error(pos, "expected an hour"))?;
+    let minute = parse_clock_number(minute)
+        .filter(|&m| m < 60)
+        .ok_or_else(|| error(pos + clock.len() - 2, "expected minutes from 00 to 59"))?;
+    let hour = match pm {
+        Some(pm) if (1..=12).contains(&hour) => hour % 12 + if pm { 12 } else { 0 },
+        Some(_) => return Err(error(pos, "expected an hour from 1 to 12")),
+        None if hour < 24 => hour,
+        None => return Err(error(pos, "expected an hour from 0 to 23")),
+    };
+    NaiveTime::from_hms_opt(hour, minute, 0).ok_or_else(|| error(pos, "expected a time"))
+}
+
+fn parse_clock_number(digits: &str) -> Option<u32> {
+    if digits.is_empty() || digits.len() > 2 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn weekday(word: &str) -> Option<Weekday> {
+    const NAMES: [(&str, Weekday); 7] = [
+        ("monday", Weekday::Mon),
+        ("tuesday", Weekday::Tue),
+        ("wednesday", Weekday::Wed),
+        ("thursday", Weekday::Thu),
+        ("friday", Weekday::Fri),
+        ("saturday", Weekday::Sat),
+        ("sunday", Weekday::Sun),
+    ];
+    let word = word.to_ascii_lowercase();
+    NAMES
+        .iter()
+        .find(|(name, _)| *name == word || name[..3] == word)
+        .map(|&(_, day)| day)
+}
+
+/// Days from `from` forward to the next `to`, in `1..=7`.
+fn weekdays_between(from: Weekday, to: Weekday) -> i64 {
+    let days = i64::from(to.days_since(from));
+    if days == 0 { 7 } else { days }
+}
+
+/// Moves `now` by `days` calendar days, keeping its wall-clock time.
+fn calendar_days<Tz: TimeZone>(
+    now: &DateTime<Tz>,
+    days: i64,
+    pos: usize,
+) -> Result<Relative<Tz>, PeriodError> {
+    now.naive_local()
+        .checked_add_signed(Duration::days(days))
+        .and_then(|wall| localize(&now.timezone(), wall))
+        .map(Relative)
+        .ok_or_else(|| error(pos, "the date is out of range"))
+}
+
+/// Replaces the wall-clock time of `date` with `time`.
+fn at_time<Tz: TimeZone>(date: Relative<Tz>, time: NaiveTime) -> Option<Relative<Tz>> {
+    let date = date.as_datetime();
+    let wall = date.date_naive().and_time(time);
+    localize(&date.timezone(), wall).map(Relative)
+}
+
+pub(super) fn tokenize(input: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in input.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(s)) => {
+                tokens.push((s, &input[s..i]));
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        tokens.push((s, &input[s..]));
+    }
+    tokens
+}
+
+fn error(position: usize, reason: &'static str) -> PeriodError {
+    PeriodError::Parse {
+        format: FORMAT,
+        position,
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use crate::relative::functions::{
+        days_ago_with, hours_from_now_with, months_from_now_with, weeks_from_now_with,
+        years_ago_with,
+    };
+    use chrono::{FixedOffset, NaiveDate, Utc};
+
+    /// Sunday 2026-02-22 12:00 UTC.
+    fn clock() -> MockClock {
+        MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap())
+    }
+
+    fn utc(input: &str) -> DateTime<Utc> {
+        parse_from(clock().now(), input).unwrap().as_datetime()
+    }
+
+    fn parse_err(input: &str) -> (usize, &'static str) {
+        match parse_from(clock().now(), input).unwrap_err() {
+            PeriodError::Parse {
+                position, reason, ..
+            } => (position, reason),
+            other => panic!("unexpected error {other:?}"),
+        }
+    }
+
+    fn at(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    // -- unit offsets ---------------------------------------------------------
+
+    #[test]
+    fn test_parse_matches_unit_functions() {
+        let clock = clock();
+        assert_eq!(
+            parse_with(&clock, "3 days ago").unwrap(),
+            days_ago_with(&clock, 3).unwrap()
+        );
+        assert_eq!(
+            parse_with(&clock, "2 months from now").unwrap(),
+            months_from_now_with(&clock, 2).unwrap()
+        );
+        assert_eq!(
+            parse_with(&clock, "in 2 weeks").unwrap(),
+            weeks_from_now_with(&clock, 2).unwrap()
+        );
+        assert_eq!(
+            parse_with(&clock, "an hour from now").unwrap(),
+            hours_from_now_with(&clock, 1).unwrap()
+        );
+        assert_eq!(
+            parse_with(&clock, "a year ago").unwrap(),
+            years_ago_with(&clock, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_every_unit() {
+        assert_eq!(
+            utc("30 seconds ago"),
+            at(2026, 2, 22, 11, 59) + Duration::seconds(30)
+        );
+        assert_eq!(utc("in 5 minutes"), at(2026, 2, 22, 12, 5));
+        assert_eq!(utc("1 hour ago"), at(2026, 2, 22, 11, 0));
+        assert_eq!(utc("in 1 day"), at(2026, 2, 23, 12, 0));
+        assert_eq!(utc("1 week ago"), at(2026, 2, 15, 12, 0));
+        assert_eq!(utc("in 1 month"), at(2026, 3, 22, 12, 0));
+        assert_eq!(utc("2 years ago"), at(2024, 2, 22, 12, 0));
+    }
+
+    #[test]
+    fn test_parse_is_case_and_whitespace_insensitive() {
+        assert_eq!(utc("  In   3\tDAYS "), at(2026, 2, 25, 12, 0));
+        assert_eq!(utc("3 Days Ago"), at(2026, 2, 19, 12, 0));
+    }
+
+    #[test]
+    fn test_parse_zero_is_now() {
+        assert_eq!(utc("0 seconds ago"), clock().now());
+    }
+
+    // -- named days -----------------------------------------------------------
+
+    #[test]
+    fn test_parse_now_today_yesterday_tomorrow() {
+        assert_eq!(utc("now"), clock().now());
+        assert_eq!(utc("today"), clock().now());
+        assert_eq!(utc("yesterday"), at(2026, 2, 21, 12, 0));
+        assert_eq!(utc("tomorrow"), at(2026, 2, 23, 12, 0));
+    }
+
+    #[test]
+    fn test_parse_next_and_last_weekday() {
+        // 2026-02-22 is a Sunday.
+        assert_eq!(utc("next monday"), at(2026, 2, 23, 12, 0));
+        assert_eq!(utc("next sunday"), at(2026, 3, 1, 12, 0));
+        assert_eq!(utc("last saturday"), at(2026, 2, 21, 12, 0));
+        assert_eq!(utc("last sunday"), at(2026, 2, 15, 12, 0));
+        assert_eq!(utc("next Fri"), at(2026, 2, 27, 12, 0));
+    }
+
+    // -- at <time> ------------------------------------------------------------
+
+    #[test]
+    fn test_parse_at_time_forms() {
+        assert_eq!(utc("tomorrow at 5pm"), at(2026, 2, 23, 17, 0));
+        assert_eq!(utc("tomorrow at 5 PM"), at(2026, 2, 23, 17, 0));
+        assert_eq!(utc("yesterday at 9:30am"), at(2026, 2, 21, 9, 30));
+        assert_eq!(utc("next monday at 17:45"), at(2026, 2, 23, 17, 45));
+        assert_eq!(utc("3 days ago at 08:00"), at(2026, 2, 19, 8, 0));
+        assert_eq!(utc("at noon"), at(2026, 2, 22, 12, 0));
+        assert_eq!(utc("tomorrow at midnight"), at(2026, 2, 23, 0, 0));
+    }
+
+    #[test]
+    fn test_parse_twelve_am_and_pm() {
+        assert_eq!(utc("today at 12am"), at(2026, 2, 22, 0, 0));
+        assert_eq!(utc("today at 12:15pm"), at(2026, 2, 22, 12, 15));
+    }
+
+    #[test]
+    fn test_parse_in_uses_zone_wall_clock() {
+        // 12:00 UTC is 21:00 in UTC+9, so "tomorrow" there is Feb 23 local.
+        let tz = FixedOffset::east_opt(9 * 3600).unwrap();
+        let now = clock().now().with_timezone(&tz);
+        let r = parse_from(now, "tomorrow at 8am").unwrap();
+        assert_eq!(r.as_date(), NaiveDate::from_ymd_opt(2026, 2, 23).unwrap());
+        assert_eq!(r.as_time(), NaiveTime::from_hms_opt(8, 0, 0).unwrap());
+        assert_eq!(r.timezone(), tz);
+    }
+
+    #[test]
+    fn test_parse_local_is_close_to_now() {
+        let before = chrono::Local::now();
+        let result = parse("now").unwrap().as_datetime();
+        assert!(result >= before && result <= chrono::Local::now());
+    }
+
+    // -- errors ---------------------------------------------------------------
+
+    #[test]
+    fn test_parse_errors_report_position() {
+        assert_eq!(parse_err(""), (0, "expected a relative date"));
+        assert_eq!(parse_err("3 days"), (6, "expected 'ago' or 'from now'"));
+        assert_eq!(
+            parse_err("3 days later"),
+            (7, "expected 'ago' or 'from now'")
+        );
+        assert_eq!(parse_err("3 fortnights ago"), (2, "expected a unit"));
+        assert_eq!(parse_err("in"), (2, "expected a number"));
+        assert_eq!(parse_err("in x days"), (3, "expected a number"));
+        assert_eq!(parse_err("next"), (4, "expected a weekday"));
+        assert_eq!(parse_err("next week"), (5, "expected a weekday"));
+        assert_eq!(
+            parse_err("tomorrow please"),
+            (9, "expected 'at' or the end of the phrase")
+        );
+        assert_eq!(parse_err("tomorrow at"), (11, "expected a time"));
+        assert_eq!(
+            parse_err("tomorrow at 5"),
+            (12, "expected HH:MM, or 'am' or 'pm'")
+        );
+        assert_eq!(
+            parse_err("tomorrow at 13pm"),
+            (12, "expected an hour from 1 to 12")
+        );
+        assert_eq!(
+            parse_err("tomorrow at 24:00"),
+            (12, "expected an hour from 0 to 23")
+        );
+        assert_eq!(
+            parse_err("tomorrow at 5:7"),
+            (14, "expected two-digit minutes")
+        );
+        assert_eq!(
+            parse_err("tomorrow at 5:75pm"),
+            (14, "expected minutes from 00 to 59")
+        );
+        assert_eq!(parse_err("tomorrow at 5xm"), (13, "expected 'am' or 'pm'"));
+        assert_eq!(
+            parse_err("tomorrow at 5pm sharp"),
+            (16, "expected the end of the phrase")
+        );
+    }
+
+    #[test]
+    fn test_parse_huge_number_is_an_error() {
+        assert_eq!(
+            parse_err("99999999999999999999 days ago"),
+            (0, "number is too large")
+        );
+    }
+
+    #[test]
+    fn test_parse_out_of_range_is_overflow() {
+        assert!(matches!(
+            parse_from(clock().now(), "9223372036854775807 days ago").unwrap_err(),
+            PeriodError::Overflow { unit: "days", .. }
+        ));
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        assert_eq!(
+            parse_with(&clock(), "3 fortnights ago")
+                .unwrap_err()
+                .to_string(),
+            "invalid relative date at position 2: expected a unit"
+        );
+    }
+}
@@ -0,0 +1,446 @@
+use std::fmt;
+
+use chrono::{DateTime, Local};
+
+use crate::clock::{Clock, SystemClock};
+use crate::locale::{English, Locale, Tense, TimeUnit, write_pattern};
+use crate::now::now_with;
+
+/// How [`Precise`] rounds the smallest unit it shows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Drop the remainder: 2 h 5 min 59 s is `"2 hours, 5 minutes"`.
+    #[default]
+    Floor,
+    /// Round half up: 2 h 5 min 30 s is `"2 hours, 6 minutes"`.
+    Round,
+    /// Round any remainder up: 2 h 5 min 1 s is `"2 hours, 6 minutes"`.
+    Ceil,
+}
+
+/// How [`Precise`] joins its units.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Conjunction {
+    /// `"1 day, 2 hours, 5 minutes"`.
+    #[default]
+    Comma,
+    /// `"1 day, 2 hours and 5 minutes"`.
+    And,
+    /// `"1d 2h 5m"`.
+    Compact,
+}
+
+/// Units [`Precise`] splits an offset into, largest first. Months are 30
+/// days and years 365 days, as in [`humanize`](fn@crate::humanize).
+const UNITS: [TimeUnit; 7] = [
+    TimeUnit::Year,
+    TimeUnit::Month,
+    TimeUnit::Week,
+    TimeUnit::Day,
+    TimeUnit::Hour,
+    TimeUnit::Minute,
+    TimeUnit::Second,
+];
+
+/// Phrases offsets from now in several units, such as
+/// `"2 hours, 5 minutes ago"` or `"in 1d 3h"`.
+///
+/// Starting from the largest non-zero unit, up to
+/// [`max_units`](Precise::max_units) consecutive units are shown, the last
+/// one rounded by [`rounding`](Precise::rounding) and none smaller than
+/// [`smallest_unit`](Precise::smallest_unit). Units that come out as zero are
+/// left out, and an offset that rounds to zero is `"just now"`. The past and
+/// future framing is the same as [`humanize`](fn@crate::humanize).
+///
+/// ```
+/// use chrono::{Duration, Local};
+/// use period::{Conjunction, Precise, Rounding, TimeUnit};
+///
+/// let then = Local::now() - Duration::minutes(125) - Duration::seconds(40);
+/// assert_eq!(Precise::new().humanize(then), "2 hours, 5 minutes ago");
+///
+/// let precise = Precise::new()
+///     .max_units(3)
+///     .smallest_unit(TimeUnit::Minute)
+///     .rounding(Rounding::Round)
+///     .conjunction(Conjunction::And);
+/// assert_eq!(precise.humanize(then), "2 hours and 6 minutes ago");
+/// assert_eq!(
+///     precise.conjunction(Conjunction::Compact).humanize(then),
+///     "2h 6m ago"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct Precise {
+    max_units: usize,
+    smallest: TimeUnit,
+    rounding: Rounding,
+    conjunction: Conjunction,
+}
+
+impl Default for Precise {
+    fn default() -> Self {
+        Precise::new()
+    }
+}
+
+impl Precise {
+    /// At most two units, down to seconds, floored and joined by commas.
+    #[inline]
+    pub const fn new() -> Self {
+        Precise {
+            max_units: 2,
+            smallest: TimeUnit::Second,
+            rounding: Rounding::Floor,
+            conjunction: Conjunction::Comma,
+        }
+    }
+
+    /// Shows at most `n` consecutive units. Zero is treated as one.
+    #[inline]
+    pub const fn max_units(self, n: usize) -> Self {
+        Precise {
+            max_units: if n == 0 { 1 } else { n },
+            ..self
+        }
+    }
+
+    /// Shows no unit smaller than `unit`.
+    #[inline]
+    pub const fn smallest_unit(self, unit: TimeUnit) -> Self {
+        Precise {
+            smallest: unit,
+            ..self
+        }
+    }
+
+    /// Sets how the last unit shown is rounded.
+    #[inline]
+    pub const fn rounding(self, rounding: Rounding) -> Self {
+        Precise { rounding, ..self }
+    }
+
+    /// Sets how the units are joined.
+    #[inline]
+    pub const fn conjunction(self, conjunction: Conjunction) -> Self {
+        Precise {
+            conjunction,
+            ..self
+        }
+    }
+
+    /// Phrases `datetime` relative to the current time.
+    #[inline]
+    #[must_use]
+    pub fn humanize(&self, datetime: DateTime<Local>) -> String {
+        self.humanize_with(&SystemClock, datetime)
+    }
+
+    /// Like [`humanize`](Precise::humanize), but relative to the instant
+    /// reported by `clock`.
+    #[inline]
+    #[must_use]
+    pub fn humanize_with(
+        &self,
+        clock: &(impl Clock + ?Sized),
+        datetime: DateTime<Local>,
+    ) -> String {
+        self.format(&English, datetime, now_with(clock))
+    }
+
+    /// Like [`humanize`](Precise::humanize), but in the language of `locale`.
+    #[inline]
+    #[must_use]
+    pub fn humanize_with_locale(
+        &self,
+        locale: &(impl Locale + ?Sized),
+        datetime: DateTime<Local>,
+    ) -> String {
+        self.format(locale, datetime, now_with(&SystemClock))
+    }
+
+    pub(crate) fn format(
+        &self,
+        locale: &(impl Locale + ?Sized),
+        datetime: DateTime<Local>,
+        now: DateTime<Local>,
+    ) -> String {
+        let secs = now.signed_duration_since(datetime).num_seconds();
+        let tense = if secs >= 0 {
+            Tense::Past
+        } else {
+            Tense::Future
+        };
+        let mut out = String::new();
+        self.write(locale, secs.unsigned_abs(), tense, &mut out)
+            .expect("writing to a String cannot fail");
+        out
+    }
+
+    fn write(
+        &self,
+        locale: &(impl Locale + ?Sized),
+        abs: u64,
+        tense: Tense,
+        out: &mut dyn fmt::Write,
+    ) -> fmt::Result {
+        let amounts = self.split(abs);
+        let count = amounts.iter().filter(|&&n| n > 0).count();
+        if count == 0 {
+            return out.write_str(locale.just_now());
+        }
+
+        let (prefix, suffix) = locale.relative(tense).split_once("{0}").unwrap_or(("", ""));
+        let [separator, last_separator] = locale.list_separators();
+        out.write_str(prefix)?;
+        let items = UNITS.iter().zip(amounts).filter(|&(_, n)| n > 0);
+        for (i, (&unit, n)) in items.enumerate() {
+            if i > 0 {
+                out.write_str(match self.conjunction {
+                    Conjunction::And if i + 1 == count => last_separator,
+                    Conjunction::Comma | Conjunction::And => separator,
+                    Conjunction::Compact => " ",
+                })?;
+            }
+            let pattern = match self.conjunction {
+                Conjunction::Compact => locale.compact(unit),
+                _ => locale.counted(unit, tense, locale.plural_category(n)),
+            };
+            write_pattern(locale, pattern, n, out)?;
+        }
+        out.write_str(suffix)
+    }
+
+    /// Splits `abs` seconds into amounts of each of [`UNITS`].
+    fn split(&self, abs: u64) -> [u64; 7] {
+        let size = |i: usize| UNITS[i].seconds().unsigned_abs();
+        let smallest = UNITS
+            .iter()
+            .rposition(|&unit| unit >= self.smallest)
+            .unwrap_or(0);
+        let first = |value: u64| {
+            (0..smallest)
+                .find(|&i| value >= size(i))
+                .unwrap_or(smallest)
+        };
+
+        // Floor every unit but the last, which gets the remainder rounded.
+        // Rounding up can carry into a unit larger than the window, which
+        // moves the window; start over from there.
+        let mut start = first(abs);
+        loop {
+            let last = start.saturating_add(self.max_units - 1).min(smallest);
+            let mut rest = abs;
+            let mut total = 0u64;
+            for i in start..last {
+                total += rest / size(i) * size(i);
+                rest %= size(i);
+            }
+            total = total.saturating_add(round(rest, size(last), self.rounding));
+            let carried = first(total);
+            if carried < start {
+                start = carried;
+                continue;
+            }
+            let mut amounts = [0; 7];
+            for (i, amount) in amounts.iter_mut().enumerate().take(last + 1).skip(start) {
+                *amount = total / size(i);
+                total %= size(i);
+            }
+            return amounts;
+        }
+    }
+}
+
+fn round(value: u64, step: u64, rounding: Rounding) -> u64 {
+    let floor = value - value % step;
+    let remainder = value % step;
+    let up = match rounding {
+        Rounding::Floor => false,
+        Rounding::Round => remainder >= step - remainder,
+        Rounding::Ceil => remainder > 0,
+    };
+    if up {
+        floor.saturating_add(step)
+    } else {
+        floor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn now() -> DateTime<Local> {
+        Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0)
+            .unwrap()
+            .with_timezone(&Local)
+    }
+
+    fn ago(precise: Precise, secs: i64) -> String {
+        precise.format(&English, now() - Duration::seconds(secs), now())
+    }
+
+    const H: i64 = 3_600;
+    const M: i64 = 60;
+
+    #[test]
+    fn test_default_two_units_floored() {
+        assert_eq!(
+            ago(Precise::new(), 2 * H + 5 * M + 59),
+            "2 hours, 5 minutes ago"
+        );
+        assert_eq!(ago(Precise::new(), 45), "45 seconds ago");
+        assert_eq!(ago(Precise::new(), 61), "1 minute, 1 second ago");
+    }
+
+    #[test]
+    fn test_future() {
+        let then = now() + Duration::seconds(2 * H + 5 * M);
+        assert_eq!(
+            Precise::new().format(&English, then, now()),
+            "in 2 hours, 5 minutes"
+        );
+    }
+
+    #[test]
+    fn test_zero_units_are_skipped() {
+        assert_eq!(ago(Precise::new(), 2 * H + 5), "2 hours ago");
+        assert_eq!(
+            ago(Precise::new().max_units(3), 2 * H + 5),
+            "2 hours, 5 seconds ago"
+        );
+    }
+
+    #[test]
+    fn test_zero_is_just_now() {
+        assert_eq!(ago(Precise::new(), 0), "just now");
+        assert_eq!(
+            ago(Precise::new().smallest_unit(TimeUnit::Minute), 59),
+            "just now"
+        );
+    }
+
+    #[test]
+    fn test_max_units() {
+        let secs = 400 * 86_400 + 3 * H + 5 * M + 7;
+        assert_eq!(ago(Precise::new().max_units(1), secs), "1 year ago");
+        assert_eq!(
+            ago(Precise::new().max_units(7), secs),
+            "1 year, 1 month, 5 days, 3 hours, 5 minutes, 7 seconds ago"
+        );
+        assert_eq!(ago(Precise::new().max_units(0), secs), "1 year ago");
+        assert_eq!(
+            ago(Precise::new().max_units(usize::MAX), 61),
+            "1 minute, 1 second ago"
+        );
+    }
+
+    #[test]
+    fn test_weeks() {
+        assert_eq!(ago(Precise::new(), 10 * 86_400), "1 week, 3 days ago");
+        assert_eq!(ago(Precise::new(), 29 * 86_400), "4 weeks, 1 day ago");
+        assert_eq!(ago(Precise::new(), 30 * 86_400), "1 month ago");
+        assert_eq!(ago(Precise::new(), 36 * 86_400), "1 month ago");
+        assert_eq!(ago(Precise::new(), 37 * 86_400), "1 month, 1 week ago");
+    }
+
+    #[test]
+    fn test_smallest_unit() {
+        let p = Precise::new().smallest_unit(TimeUnit::Hour).max_units(3);
+        assert_eq!(ago(p, 2 * 86_400 + 5 * H + 59 * M), "2 days, 5 hours ago");
+        assert_eq!(ago(p, 30 * M), "just now");
+    }
+
+    #[test]
+    fn test_rounding() {
+        let floor = Precise::new();
+        let round = floor.rounding(Rounding::Round);
+        let ceil = floor.rounding(Rounding::Ceil);
+        let secs = 2 * H + 5 * M + 30;
+        assert_eq!(ago(floor, secs), "2 hours, 5 minutes ago");
+        assert_eq!(ago(round, secs), "2 hours, 6 minutes ago");
+        assert_eq!(ago(round, secs - 1), "2 hours, 5 minutes ago");
+        assert_eq!(ago(ceil, 2 * H + 5 * M + 1), "2 hours, 6 minutes ago");
+        assert_eq!(ago(ceil, 2 * H + 5 * M), "2 hours, 5 minutes ago");
+    }
+
+    #[test]
+    fn test_rounding_carries_into_larger_unit() {
+        let round = Precise::new().rounding(Rounding::Round);
+        assert_eq!(
+            ago(round.smallest_unit(TimeUnit::Minute), H - 20),
+            "1 hour ago"
+        );
+        assert_eq!(ago(round, 23 * H + 59 * M + 50), "1 day ago");
+        assert_eq!(ago(round.max_units(1), 89 * M), "1 hour ago");
+        assert_eq!(ago(round.max_units(1), 90 * M), "2 hours ago");
+    }
+
+    #[test]
+    fn test_conjunctions() {
+        let p = Precise::new().max_units(3);
+        let secs = 86_400 + 2 * H + 5 * M;
+        assert_eq!(ago(p, secs), "1 day, 2 hours, 5 minutes ago");
+        assert_eq!(
+            ago(p.conjunction(Conjunction::And), secs),
+            "1 day, 2 hours and 5 minutes ago"
+        );
+        assert_eq!(
+            ago(p.conjunction(Conjunction::And), 2 * H + 5 * M),
+            "2 hours and 5 minutes ago"
+        );
+        assert_eq!(
+            ago(p.conjunction(Conjunction::Compact), secs),
+            "1d 2h 5m ago"
+        );
+        let then = now() + Duration::seconds(secs);
+        assert_eq!(
+            p.conjunction(Conjunction::Compact)
+                .format(&English, then, now()),
+            "in 1d 2h 5m"
+        );
+    }
+
+    #[test]
+    fn test_locales() {
+        use crate::locale::{Arabic, German, Japanese, Polish, Russian};
+
+        let p = Precise::new().conjunction(Conjunction::And);
+        let then = now() - Duration::seconds(2 * 86_400 + 5 * H);
+        assert_eq!(p.format(&German, then, now()), "vor 2 Tagen und 5 Stunden");
+        assert_eq!(p.format(&Polish, then, now()), "2 dni i 5 godzin temu");
+        assert_eq!(p.format(&Russian, then, now()), "2 дня и 5 часов назад");
+        assert_eq!(p.format(&Arabic, then, now()), "قبل يومين و٥ ساعات");
+        assert_eq!(p.format(&Japanese, then, now()), "2 日 5 時間前");
+        let soon = now() + Duration::seconds(86_400 + 21 * H);
+        assert_eq!(p.format(&Russian, soon, now()), "через 1 день и 21 час");
+        assert_eq!(
+            p.conjunction(Conjunction::Compact)
+                .format(&German, soon, now()),
+            "in 1 T. 21 Std."
+        );
+    }
+
+    #[test]
+    fn test_humanize_with_clock() {
+        use crate::clock::MockClock;
+
+        let clock = MockClock::new(&Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap());
+        let then = now() - Duration::seconds(3 * H + 20 * M);
+        assert_eq!(
+            Precise::new().humanize_with(&clock, then),
+            "3 hours, 20 minutes ago"
+        );
+    }
+
+    #[test]
+    fn test_extreme_offset() {
+        let p = Precise::new().rounding(Rounding::Ceil).max_units(7);
+        let mut out = String::new();
+        p.write(&English, u64::MAX, Tense::Past, &mut out).unwrap();
+        assert!(out.ends_with("ago"));
+    }
+}
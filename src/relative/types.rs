@@ -1,10 +1,20 @@
-use chrono::{DateTime, Local, NaiveDate, NaiveTime};
+use std::cmp::Ordering;
+use std::fmt;
+
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone};
+
+use crate::error::PeriodError;
 
 /// A resolved point in time returned by every relative-date function.
 ///
 /// Call `.as_datetime()`, `.as_date()`, or `.as_time()` to extract the
 /// representation you need, or rely on the `From` / `Into` conversions.
 ///
+/// `Relative` is generic over the [`TimeZone`] its date and time are read in.
+/// The plain relative functions produce `Relative<Local>`; the `_in`
+/// variants produce a `Relative` in the requested zone, and
+/// [`with_timezone`](Relative::with_timezone) converts between zones.
+///
 /// # Example
 ///
 /// ```rust
@@ -13,17 +23,18 @@ use chrono::{DateTime, Local, NaiveDate, NaiveTime};
 /// let date     = r.as_date();     // NaiveDate       - just the calendar day
 /// let datetime = r.as_datetime(); // DateTime<Local> - full timestamp
 /// let time     = r.as_time();     // NaiveTime       - just the clock reading
+///
+/// let utc = r.with_timezone(&chrono::Utc); // Relative<Utc> - same instant
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Relative(pub(super) DateTime<Local>);
+pub struct Relative<Tz: TimeZone = Local>(pub(super) DateTime<Tz>);
 
-impl Relative {
-    /// The full local date-time.
+impl<Tz: TimeZone> Relative<Tz> {
+    /// The full date-time in this value's timezone.
     #[inline]
     #[must_use]
-    pub fn as_datetime(self) -> DateTime<Local> {
+    pub fn as_datetime(self) -> DateTime<Tz> {
         self.0
     }
 
@@ -40,22 +51,170 @@ impl Relative {
     pub fn as_time(self) -> NaiveTime {
         self.0.time()
     }
+
+    /// The same instant, with its date and time read in `tz`.
+    #[inline]
+    #[must_use]
+    pub fn with_timezone<Tz2: TimeZone>(&self, tz: &Tz2) -> Relative<Tz2> {
+        Relative(self.0.with_timezone(tz))
+    }
+
+    /// The timezone this value's date and time are read in.
+    #[inline]
+    #[must_use]
+    pub fn timezone(&self) -> Tz {
+        self.0.timezone()
+    }
+
+    /// Whole seconds since the Unix epoch, rounded towards the past
+    /// (`-0.5 s` is `-1`).
+    #[inline]
+    #[must_use]
+    pub fn to_unix_seconds(&self) -> i64 {
+        self.0.timestamp()
+    }
+
+    /// Whole milliseconds since the Unix epoch, rounded towards the past.
+    #[inline]
+    #[must_use]
+    pub fn to_unix_millis(&self) -> i64 {
+        self.0.timestamp_millis()
+    }
+
+    /// Whole microseconds since the Unix epoch, rounded towards the past.
+    #[inline]
+    #[must_use]
+    pub fn to_unix_micros(&self) -> i64 {
+        self.0.timestamp_micros()
+    }
+
+    /// Nanoseconds since the Unix epoch.
+    ///
+    /// # Errors
+    /// Returns [`PeriodError::Overflow`] if the instant is outside the range
+    /// an `i64` of nanoseconds can hold (roughly the years 1677 to 2262). The
+    /// error's `value` is the timestamp in seconds, which is negative for an
+    /// instant before 1677.
+    #[inline]
+    pub fn to_unix_nanos(&self) -> Result<i64, PeriodError> {
+        self.0.timestamp_nanos_opt().ok_or(PeriodError::Overflow {
+            unit: "seconds",
+            value: self.0.timestamp(),
+        })
+    }
+}
+
+impl Relative {
+    /// The local date-time `seconds` seconds after the Unix epoch; negative
+    /// values are before 1970.
+    ///
+    /// # Errors
+    /// Returns [`PeriodError::Overflow`] if the timestamp is out of range.
+    #[inline]
+    pub fn from_unix_seconds(seconds: i64) -> Result<Relative, PeriodError> {
+        DateTime::from_timestamp(seconds, 0)
+            .map(|dt| Relative(dt.with_timezone(&Local)))
+            .ok_or(PeriodError::Overflow {
+                unit: "seconds",
+                value: seconds,
+            })
+    }
+
+    /// The local date-time `millis` milliseconds after the Unix epoch;
+    /// negative values are before 1970.
+    ///
+    /// # Errors
+    /// Returns [`PeriodError::Overflow`] if the timestamp is out of range.
+    #[inline]
+    pub fn from_unix_millis(millis: i64) -> Result<Relative, PeriodError> {
+        DateTime::from_timestamp_millis(millis)
+            .map(|dt| Relative(dt.with_timezone(&Local)))
+            .ok_or(PeriodError::Overflow {
+                unit: "milliseconds",
+                value: millis,
+            })
+    }
+
+    /// The local date-time `micros` microseconds after the Unix epoch;
+    /// negative values are before 1970.
+    ///
+    /// # Errors
+    /// Returns [`PeriodError::Overflow`] if the timestamp is out of range.
+    #[inline]
+    pub fn from_unix_micros(micros: i64) -> Result<Relative, PeriodError> {
+        DateTime::from_timestamp_micros(micros)
+            .map(|dt| Relative(dt.with_timezone(&Local)))
+            .ok_or(PeriodError::Overflow {
+                unit: "microseconds",
+                value: micros,
+            })
+    }
+
+    /// The local date-time `nanos` nanoseconds after the Unix epoch;
+    /// negative values are before 1970.
+    ///
+    /// Every `i64` is in range, so unlike the other constructors this cannot
+    /// fail.
+    #[inline]
+    #[must_use]
+    pub fn from_unix_nanos(nanos: i64) -> Relative {
+        Relative(DateTime::from_timestamp_nanos(nanos).with_timezone(&Local))
+    }
+}
+
+// The trait impls below are written by hand rather than derived: a derive
+// would require `Tz` itself to implement each trait, which `Local` does not
+// for `PartialEq`, and `Copy` additionally depends on `Tz::Offset`.
+
+impl<Tz: TimeZone> fmt::Debug for Relative<Tz> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Relative").field(&self.0).finish()
+    }
+}
+
+#[allow(clippy::expl_impl_clone_on_copy)]
+impl<Tz: TimeZone> Clone for Relative<Tz> {
+    fn clone(&self) -> Self {
+        Relative(self.0.clone())
+    }
+}
+
+impl<Tz: TimeZone> Copy for Relative<Tz> where DateTime<Tz>: Copy {}
+
+impl<Tz: TimeZone, Tz2: TimeZone> PartialEq<Relative<Tz2>> for Relative<Tz> {
+    fn eq(&self, other: &Relative<Tz2>) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<Tz: TimeZone> Eq for Relative<Tz> {}
+
+impl<Tz: TimeZone> PartialOrd for Relative<Tz> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
-impl From<Relative> for DateTime<Local> {
-    fn from(r: Relative) -> Self {
+impl<Tz: TimeZone> Ord for Relative<Tz> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<Tz: TimeZone> From<Relative<Tz>> for DateTime<Tz> {
+    fn from(r: Relative<Tz>) -> Self {
         r.0
     }
 }
 
-impl From<Relative> for NaiveDate {
-    fn from(r: Relative) -> Self {
+impl<Tz: TimeZone> From<Relative<Tz>> for NaiveDate {
+    fn from(r: Relative<Tz>) -> Self {
         r.0.date_naive()
     }
 }
 
-impl From<Relative> for NaiveTime {
-    fn from(r: Relative) -> Self {
+impl<Tz: TimeZone> From<Relative<Tz>> for NaiveTime {
+    fn from(r: Relative<Tz>) -> Self {
         r.0.time()
     }
 }
@@ -64,7 +223,7 @@ impl From<Relative> for NaiveTime {
 mod tests {
     use super::*;
     use crate::relative::functions::{days_ago, hours_ago, minutes_ago, seconds_ago};
-    use chrono::Duration;
+    use chrono::{Duration, FixedOffset, Utc};
 
     #[test]
     fn test_relative_as_date_matches_naive_date() {
@@ -139,6 +298,136 @@ mod tests {
         assert_eq!(r.as_time(), r.as_datetime().time());
     }
 
+    #[test]
+    fn test_relative_with_timezone_keeps_instant() {
+        let r = hours_ago(3).unwrap();
+        let utc = r.with_timezone(&Utc);
+        assert_eq!(utc, r);
+        assert_eq!(utc.as_datetime(), r.as_datetime().to_utc());
+        assert_eq!(utc.timezone(), Utc);
+    }
+
+    #[test]
+    fn test_relative_with_timezone_changes_wall_clock() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 22, 0, 0).unwrap();
+        let r = crate::relative::functions::hours_before(anchor, 0).unwrap();
+        let ist = FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+        let shifted = r.with_timezone(&ist);
+        assert_eq!(
+            shifted.as_date(),
+            NaiveDate::from_ymd_opt(2026, 2, 23).unwrap()
+        );
+        assert_eq!(
+            shifted.as_time(),
+            NaiveTime::from_hms_opt(3, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_relative_utc_into_datetime_utc() {
+        let anchor = Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap();
+        let r = crate::relative::functions::days_before(anchor, 1).unwrap();
+        let dt: DateTime<Utc> = r.into();
+        assert_eq!(dt, anchor - Duration::days(1));
+    }
+
+    // -- Unix timestamps ------------------------------------------------------
+
+    #[test]
+    fn test_from_unix_seconds_round_trips() {
+        let r = Relative::from_unix_seconds(1_771_761_600).unwrap();
+        assert_eq!(
+            r.with_timezone(&Utc).as_datetime(),
+            Utc.with_ymd_and_hms(2026, 2, 22, 12, 0, 0).unwrap()
+        );
+        assert_eq!(r.to_unix_seconds(), 1_771_761_600);
+    }
+
+    #[test]
+    fn test_from_unix_each_precision_agrees() {
+        let expected = Relative::from_unix_seconds(1_771_761_600).unwrap();
+        assert_eq!(
+            Relative::from_unix_millis(1_771_761_600_000).unwrap(),
+            expected
+        );
+        assert_eq!(
+            Relative::from_unix_micros(1_771_761_600_000_000).unwrap(),
+            expected
+        );
+        assert_eq!(
+            Relative::from_unix_nanos(1_771_761_600_000_000_000),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_to_unix_each_precision() {
+        let r = Relative::from_unix_nanos(1_500_000_001);
+        assert_eq!(r.to_unix_seconds(), 1);
+        assert_eq!(r.to_unix_millis(), 1_500);
+        assert_eq!(r.to_unix_micros(), 1_500_000);
+        assert_eq!(r.to_unix_nanos().unwrap(), 1_500_000_001);
+    }
+
+    #[test]
+    fn test_negative_timestamps_are_before_1970() {
+        let r = Relative::from_unix_millis(-1).unwrap().with_timezone(&Utc);
+        assert_eq!(
+            r.as_datetime(),
+            Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap() + Duration::milliseconds(999)
+        );
+        // Conversions round towards the past, not towards zero.
+        assert_eq!(r.to_unix_seconds(), -1);
+        assert_eq!(r.to_unix_millis(), -1);
+        assert_eq!(r.to_unix_micros(), -1_000);
+        assert_eq!(r.to_unix_nanos().unwrap(), -1_000_000);
+    }
+
+    #[test]
+    fn test_negative_timestamps_round_trip() {
+        for seconds in [-1, -86_400, -2_208_988_800] {
+            let r = Relative::from_unix_seconds(seconds).unwrap();
+            assert_eq!(r.to_unix_seconds(), seconds);
+        }
+        let r = Relative::from_unix_micros(-1_234_567).unwrap();
+        assert_eq!(r.to_unix_micros(), -1_234_567);
+    }
+
+    #[test]
+    fn test_from_unix_out_of_range_is_overflow() {
+        assert_eq!(
+            Relative::from_unix_seconds(i64::MAX).unwrap_err(),
+            crate::PeriodError::Overflow {
+                unit: "seconds",
+                value: i64::MAX
+            }
+        );
+        assert!(Relative::from_unix_seconds(i64::MIN).is_err());
+        assert!(Relative::from_unix_millis(i64::MAX).is_err());
+        assert!(Relative::from_unix_micros(i64::MIN).is_err());
+    }
+
+    #[test]
+    fn test_to_unix_nanos_out_of_range_is_overflow() {
+        let r = Relative::from_unix_seconds(10_000_000_000).unwrap();
+        assert_eq!(
+            r.to_unix_nanos().unwrap_err(),
+            crate::PeriodError::Overflow {
+                unit: "seconds",
+                value: 10_000_000_000
+            }
+        );
+        assert_eq!(
+            r.to_unix_nanos().unwrap_err().to_string(),
+            "seconds value 10000000000 is too large"
+        );
+        let r = Relative::from_unix_seconds(-10_000_000_000).unwrap();
+        assert_eq!(
+            r.to_unix_nanos().unwrap_err().to_string(),
+            "seconds value -10000000000 is too small"
+        );
+    }
+
     #[test]
     fn test_relative_ordering_same_instant_is_equal() {
         let r = seconds_ago(0).unwrap();